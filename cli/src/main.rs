@@ -0,0 +1,1019 @@
+//! Command line tool for operating Cetra chambers.
+
+mod output;
+
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, Context, Result};
+use cetra_chamber::state;
+use cetra_chamber_client::{farm, lookup_table::LookupTable, protocol, ChamberClient};
+use clap::{Args, Parser, Subcommand};
+use solana_client::{rpc_client::RpcClient, rpc_request::RpcRequest};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    message::VersionedMessage,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+    system_instruction,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[clap(name = "cetra-cli", version, about = "Operate Cetra chambers")]
+struct Cli {
+    /// Solana json rpc url.
+    #[clap(long, default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Signer keypair path.
+    #[clap(long, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+
+    /// Print output as json.
+    #[clap(long)]
+    json: bool,
+
+    /// Print instructions and account lists without sending transaction.
+    #[clap(long)]
+    dry_run: bool,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct ChamberArgs {
+    /// Tulip leveraged farm name, e.g. `ray-sol-usdc`.
+    #[clap(long)]
+    farm: String,
+
+    /// Chamber nonce.
+    #[clap(long, default_value_t = 0)]
+    nonce: u8,
+
+    /// Json file with lending reserve and vault accounts.
+    #[clap(long)]
+    market_accounts: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Initialize chamber for named farm.
+    Init {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        #[clap(long)]
+        fee_manager: Pubkey,
+
+        #[clap(long, default_value_t = 3)]
+        leverage: u64,
+
+        #[clap(long)]
+        is_base_volatile: bool,
+    },
+
+    /// List chambers of all registry pages.
+    List,
+
+    /// Update registered chamber status, after it was paused, resumed or closed.
+    SyncRegistry {
+        /// Registered chamber address.
+        #[clap(long)]
+        chamber: Pubkey,
+    },
+
+    /// Show decoded chamber and user account state.
+    Show {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        /// Show `UserAccount` of this user.
+        #[clap(long)]
+        user: Option<Pubkey>,
+    },
+
+    /// Deposit signer funds into chamber.
+    Deposit {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        #[clap(long, default_value_t = 0)]
+        base_amount: u64,
+
+        #[clap(long, default_value_t = 0)]
+        quote_amount: u64,
+
+        #[clap(long, default_value_t = 0)]
+        native_amount: u64,
+    },
+
+    /// Withdraw signer funds from chamber.
+    Withdraw {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        #[clap(long)]
+        shares_amount: u64,
+
+        #[clap(long)]
+        output_mint: Option<Pubkey>,
+
+        #[clap(long, default_value_t = 0)]
+        min_base_out: u64,
+
+        #[clap(long, default_value_t = 0)]
+        min_quote_out: u64,
+
+        #[clap(long)]
+        unwrap_native: bool,
+    },
+
+    /// Rebalance chamber position.
+    Rebalance {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        /// Obligation, which receives rebalanced position, active obligation by default.
+        #[clap(long)]
+        target_obligation: Option<u8>,
+
+        /// Part of active obligation position, moved into target obligation.
+        #[clap(long, default_value_t = 100)]
+        withdraw_percent: u8,
+    },
+
+    /// Partially repay chamber debt, when obligation LTV is past safety margin.
+    Deleverage {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        /// Deleveraged obligation, active obligation by default.
+        #[clap(long)]
+        obligation: Option<u8>,
+
+        #[clap(long, default_value_t = 0)]
+        min_coin_swap: u64,
+
+        #[clap(long, default_value_t = 0)]
+        min_pc_swap: u64,
+
+        /// Part of obligation position, unwound by guardian regardless of its health,
+        /// by default part, which brings obligation to target LTV.
+        #[clap(long, default_value_t = 0)]
+        withdraw_percent: u8,
+    },
+
+    /// Borrow up to target leverage, when position is under-levered.
+    Releverage {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+    },
+
+    /// Pause or resume chamber deposits and rebalances.
+    Pause {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        /// Resume paused chamber, signer is chamber owner.
+        #[clap(long)]
+        resume: bool,
+    },
+
+    /// Propose timelocked leverage, performance fee or delay change.
+    ProposeConfig {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        #[clap(long)]
+        leverage: Option<u64>,
+
+        #[clap(long)]
+        performance_fee_bps: Option<u64>,
+
+        /// Timelock delay in seconds, applied with this change.
+        #[clap(long)]
+        delay: Option<i64>,
+
+        /// Unix timestamp, defaults to current cluster time plus timelock delay.
+        #[clap(long)]
+        effective_timestamp: Option<i64>,
+    },
+
+    /// Execute pending config change past its effective timestamp.
+    ExecuteConfig {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+    },
+
+    /// Cancel pending config change, signer is chamber owner or guardian.
+    CancelConfig {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+    },
+
+    /// Initialize protocol config, program upgrade authority signer becomes protocol admin.
+    ProtocolInit,
+
+    /// Show decoded protocol config.
+    ProtocolShow,
+
+    /// Update protocol admin, fee and allowed farm and oracle programs.
+    ProtocolUpdate {
+        #[clap(long)]
+        admin: Option<Pubkey>,
+
+        #[clap(long)]
+        protocol_fee_bps: Option<u64>,
+
+        /// Allowed farm program, replaces current list, when provided.
+        #[clap(long)]
+        farm_program: Vec<Pubkey>,
+
+        /// Allowed oracle program, replaces current list, when provided.
+        #[clap(long)]
+        oracle_program: Vec<Pubkey>,
+    },
+
+    /// Pause or resume chamber creation, deposits and rebalances protocol wide.
+    ProtocolPause {
+        /// Resume paused protocol.
+        #[clap(long)]
+        resume: bool,
+    },
+
+    /// Allow, or disallow, chamber creation over named farm.
+    AllowFarm {
+        /// Tulip leveraged farm name, e.g. `ray-sol-usdc`.
+        #[clap(long)]
+        farm: String,
+
+        /// Remove farm from allowed farms.
+        #[clap(long)]
+        remove: bool,
+    },
+
+    /// Allow, or disallow, `Pyth` product and price accounts as mint oracle.
+    AllowOracle {
+        #[clap(long)]
+        mint: Pubkey,
+
+        /// `Pyth` product account, required to allow oracle.
+        #[clap(long)]
+        product: Option<Pubkey>,
+
+        /// `Pyth` price account, required to allow oracle.
+        #[clap(long)]
+        price: Option<Pubkey>,
+
+        /// Remove mint oracle from allowed oracles.
+        #[clap(long)]
+        remove: bool,
+    },
+
+    /// Upgrade chamber, or signer user account, to current account layout.
+    Migrate {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        /// Migrate signer user account instead of chamber.
+        #[clap(long)]
+        user_account: bool,
+    },
+
+    /// Create chamber lookup table, if missing, and extend it with static market accounts.
+    LookupTable {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+    },
+
+    /// Update chamber limits, access, risk and roles config.
+    UpdateConfig {
+        #[clap(flatten)]
+        chamber: ChamberArgs,
+
+        #[clap(long)]
+        tvl_cap: Option<u64>,
+
+        #[clap(long)]
+        max_user_deposit: Option<u64>,
+
+        #[clap(long)]
+        min_deposit: Option<u64>,
+
+        #[clap(long)]
+        is_allowlist_enabled: Option<bool>,
+
+        #[clap(long)]
+        gatekeeper: Option<Pubkey>,
+
+        #[clap(long)]
+        liquidation_threshold_bps: Option<u64>,
+
+        #[clap(long)]
+        safety_margin_bps: Option<u64>,
+
+        #[clap(long)]
+        target_ltv_bps: Option<u64>,
+
+        #[clap(long)]
+        rebalance_drift_bps: Option<u64>,
+
+        #[clap(long)]
+        max_deleverage_slippage_bps: Option<u64>,
+
+        #[clap(long)]
+        min_safety_margin_bps: Option<u64>,
+
+        #[clap(long)]
+        max_safety_margin_bps: Option<u64>,
+
+        #[clap(long)]
+        min_target_ltv_bps: Option<u64>,
+
+        #[clap(long)]
+        max_target_ltv_bps: Option<u64>,
+
+        #[clap(long)]
+        max_deleverage_slippage_bound_bps: Option<u64>,
+
+        #[clap(long)]
+        min_rebalance_drift_bps: Option<u64>,
+
+        #[clap(long)]
+        max_rebalance_drift_bps: Option<u64>,
+
+        #[clap(long)]
+        strategist: Option<Pubkey>,
+
+        #[clap(long)]
+        guardian: Option<Pubkey>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let signer = load_keypair(&cli.keypair)?;
+
+    match &cli.command {
+        Command::Init {
+            chamber,
+            fee_manager,
+            leverage,
+            is_base_volatile,
+        } => {
+            let shares_mint = Keypair::new();
+            let client = build_client(chamber, &shares_mint.pubkey())?;
+
+            let protocol_config = fetch_account::<state::ProtocolConfig>(
+                &rpc,
+                &cetra_chamber::utils::derive_protocol_config_address().0,
+            )?;
+
+            let rent = rpc.get_minimum_balance_for_rent_exemption(anchor_spl::token::Mint::LEN)?;
+
+            let mut ixs = vec![];
+
+            // Chamber is listed in new registry page, when all existing pages are full
+            let registry_page = if protocol_config.is_registry_full() {
+                ixs.push(protocol::create_chamber_registry_page(
+                    &signer.pubkey(),
+                    protocol_config.registry_pages,
+                ));
+
+                protocol_config.registry_pages
+            } else {
+                protocol_config.registry_pages - 1
+            };
+
+            ixs.extend([
+                system_instruction::create_account(
+                    &signer.pubkey(),
+                    &shares_mint.pubkey(),
+                    rent,
+                    anchor_spl::token::Mint::LEN as u64,
+                    &anchor_spl::token::ID,
+                ),
+                client.initialize_chamber(
+                    &signer.pubkey(),
+                    &signer.pubkey(),
+                    fee_manager,
+                    *leverage,
+                    *is_base_volatile,
+                    registry_page,
+                ),
+            ]);
+
+            execute(&cli, &rpc, &ixs, &[&signer, &shares_mint], None)
+        }
+        Command::List => {
+            let registry = fetch_chamber_registry(&rpc)?;
+
+            let value = serde_json::Value::Array(
+                registry
+                    .iter()
+                    .flat_map(|page| page.entries.iter())
+                    .map(output::chamber_registry_entry_to_json)
+                    .collect(),
+            );
+
+            output::print_value(&value, cli.json);
+
+            Ok(())
+        }
+        Command::SyncRegistry { chamber } => {
+            let registry = fetch_chamber_registry(&rpc)?;
+
+            let page = registry
+                .iter()
+                .find(|page| page.entries.iter().any(|entry| entry.chamber == *chamber))
+                .ok_or_else(|| anyhow!("Chamber is not registered: {}", chamber))?;
+
+            let ixs = vec![protocol::sync_chamber_registry(page.index, chamber)];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::Show { chamber, user } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let chamber_state = fetch_account::<state::Chamber>(&rpc, &client.chamber)?;
+
+            let mut value = serde_json::json!({
+                "chamber": client.chamber.to_string(),
+                "state": output::chamber_to_json(&chamber_state),
+            });
+
+            if let Some(user) = user {
+                let user_account = client.user_account_address(user);
+                let user_account_state = fetch_account::<state::UserAccount>(&rpc, &user_account)?;
+
+                value["user_account"] = serde_json::json!({
+                    "address": user_account.to_string(),
+                    "state": output::user_account_to_json(&user_account_state),
+                });
+            }
+
+            output::print_value(&value, cli.json);
+
+            Ok(())
+        }
+        Command::Deposit {
+            chamber,
+            base_amount,
+            quote_amount,
+            native_amount,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let mut ixs = vec![];
+
+            let user_account = client.user_account_address(&signer.pubkey());
+            if rpc.get_account(&user_account).is_err() {
+                ixs.push(client.create_user_account(&signer.pubkey()));
+            }
+
+            ixs.push(client.deposit_chamber(
+                &signer.pubkey(),
+                *base_amount,
+                *quote_amount,
+                *native_amount,
+            ));
+
+            let lookup_table = fetch_lookup_table(&rpc, &client)?;
+            execute(&cli, &rpc, &ixs, &[&signer], lookup_table.as_ref())
+        }
+        Command::Withdraw {
+            chamber,
+            shares_amount,
+            output_mint,
+            min_base_out,
+            min_quote_out,
+            unwrap_native,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let ixs = vec![client.withdraw_chamber(
+                &signer.pubkey(),
+                *shares_amount,
+                *output_mint,
+                *min_base_out,
+                *min_quote_out,
+                *unwrap_native,
+            )];
+
+            let lookup_table = fetch_lookup_table(&rpc, &client)?;
+            execute(&cli, &rpc, &ixs, &[&signer], lookup_table.as_ref())
+        }
+        Command::Rebalance {
+            chamber,
+            target_obligation,
+            withdraw_percent,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let target_obligation = target_obligation.unwrap_or(client.active_obligation);
+
+            let ixs = vec![client.rebalance_chamber(
+                &signer.pubkey(),
+                target_obligation,
+                *withdraw_percent,
+            )];
+
+            let lookup_table = fetch_lookup_table(&rpc, &client)?;
+            execute(&cli, &rpc, &ixs, &[&signer], lookup_table.as_ref())
+        }
+        Command::Deleverage {
+            chamber,
+            obligation,
+            min_coin_swap,
+            min_pc_swap,
+            withdraw_percent,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let obligation = obligation.unwrap_or(client.active_obligation);
+
+            let ixs = vec![client.deleverage_chamber(
+                &signer.pubkey(),
+                obligation,
+                *min_coin_swap,
+                *min_pc_swap,
+                *withdraw_percent,
+            )];
+
+            let lookup_table = fetch_lookup_table(&rpc, &client)?;
+            execute(&cli, &rpc, &ixs, &[&signer], lookup_table.as_ref())
+        }
+        Command::Releverage { chamber } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let ixs = vec![client.releverage_chamber(&signer.pubkey())];
+
+            let lookup_table = fetch_lookup_table(&rpc, &client)?;
+            execute(&cli, &rpc, &ixs, &[&signer], lookup_table.as_ref())
+        }
+        Command::Pause { chamber, resume } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let ixs = vec![client.set_chamber_pause(&signer.pubkey(), !*resume)];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::ProposeConfig {
+            chamber,
+            leverage,
+            performance_fee_bps,
+            delay,
+            effective_timestamp,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let chamber_state = fetch_account::<state::Chamber>(&rpc, &client.chamber)?;
+
+            let effective_timestamp = match effective_timestamp {
+                Some(effective_timestamp) => *effective_timestamp,
+                None => rpc.get_block_time(rpc.get_slot()?)? + chamber_state.timelock.delay,
+            };
+
+            let ixs = vec![client.propose_config_change(
+                &signer.pubkey(),
+                leverage.unwrap_or(chamber_state.strategy.leverage),
+                performance_fee_bps.unwrap_or(chamber_state.harvest.performance_fee_bps),
+                delay.unwrap_or(chamber_state.timelock.delay),
+                effective_timestamp,
+            )];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::ExecuteConfig { chamber } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let ixs = vec![client.execute_config_change(&signer.pubkey())];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::CancelConfig { chamber } => {
+            let client = fetch_client(&rpc, chamber)?;
+
+            let ixs = vec![client.cancel_config_change(&signer.pubkey())];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::ProtocolInit => {
+            let ixs = vec![protocol::initialize_protocol_config(&signer.pubkey())];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::ProtocolShow => {
+            let protocol_config = fetch_account::<state::ProtocolConfig>(
+                &rpc,
+                &cetra_chamber::utils::derive_protocol_config_address().0,
+            )?;
+
+            output::print_value(&output::protocol_config_to_json(&protocol_config), cli.json);
+
+            Ok(())
+        }
+        Command::ProtocolUpdate {
+            admin,
+            protocol_fee_bps,
+            farm_program,
+            oracle_program,
+        } => {
+            let protocol_config = fetch_account::<state::ProtocolConfig>(
+                &rpc,
+                &cetra_chamber::utils::derive_protocol_config_address().0,
+            )?;
+
+            let farm_programs = if farm_program.is_empty() {
+                get_allowed_programs(&protocol_config.farm_programs)
+            } else {
+                farm_program.clone()
+            };
+            let oracle_programs = if oracle_program.is_empty() {
+                get_allowed_programs(&protocol_config.oracle_programs)
+            } else {
+                oracle_program.clone()
+            };
+
+            let ixs = vec![protocol::update_protocol_config(
+                &signer.pubkey(),
+                &admin.unwrap_or(protocol_config.admin),
+                protocol_fee_bps.unwrap_or(protocol_config.protocol_fee_bps),
+                farm_programs,
+                oracle_programs,
+            )];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::ProtocolPause { resume } => {
+            let ixs = vec![protocol::set_protocol_pause(&signer.pubkey(), !*resume)];
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::AllowFarm { farm, remove } => {
+            let (farm_config, _) = farm::get_farm(farm)?;
+
+            let ixs = if *remove {
+                vec![protocol::remove_farm_entry(
+                    &signer.pubkey(),
+                    &farm_config.account,
+                )]
+            } else {
+                vec![protocol::add_farm_entry(
+                    &signer.pubkey(),
+                    &farm_config.account,
+                    &farm::FARM_PROGRAM,
+                )]
+            };
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::AllowOracle {
+            mint,
+            product,
+            price,
+            remove,
+        } => {
+            let ixs = if *remove {
+                vec![protocol::remove_oracle_entry(&signer.pubkey(), mint)]
+            } else {
+                let product = product.ok_or_else(|| anyhow!("Product is required"))?;
+                let price = price.ok_or_else(|| anyhow!("Price is required"))?;
+
+                vec![protocol::add_oracle_entry(
+                    &signer.pubkey(),
+                    mint,
+                    &product,
+                    &price,
+                )]
+            };
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::Migrate {
+            chamber,
+            user_account,
+        } => {
+            // Outdated `Chamber` layout is not decoded, so shares mint is not fetched
+            let client = build_client(chamber, &Pubkey::default())?;
+
+            let ixs = if *user_account {
+                vec![client.migrate_user_account(&signer.pubkey())]
+            } else {
+                vec![client.migrate_chamber(&signer.pubkey())]
+            };
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+        Command::LookupTable { chamber } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let chamber_state = fetch_account::<state::Chamber>(&rpc, &client.chamber)?;
+
+            let (mut ixs, lookup_table) = if chamber_state.config.has_lookup_table() {
+                let lookup_table = fetch_lookup_table(&rpc, &client)?
+                    .ok_or_else(|| anyhow!("Lookup table is not found"))?;
+
+                (vec![], lookup_table)
+            } else {
+                let (ix, address) = client.create_chamber_lookup_table(
+                    &signer.pubkey(),
+                    &signer.pubkey(),
+                    rpc.get_slot()?,
+                );
+
+                (
+                    vec![ix],
+                    LookupTable {
+                        address,
+                        addresses: vec![],
+                    },
+                )
+            };
+
+            let addresses = lookup_table.get_missing_addresses(&client.lookup_table_addresses());
+            let extend_ixs = client.extend_chamber_lookup_table(
+                &signer.pubkey(),
+                &signer.pubkey(),
+                &lookup_table.address,
+                &addresses,
+            );
+
+            // Each extension is sent separately to fit legacy transaction size
+            let mut extend_ixs = extend_ixs.into_iter();
+            ixs.extend(extend_ixs.next());
+            if ixs.is_empty() {
+                return Err(anyhow!("Lookup table is up to date"));
+            }
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)?;
+            for ix in extend_ixs {
+                execute(&cli, &rpc, &[ix], &[&signer], None)?;
+            }
+
+            Ok(())
+        }
+        Command::UpdateConfig {
+            chamber,
+            tvl_cap,
+            max_user_deposit,
+            min_deposit,
+            is_allowlist_enabled,
+            gatekeeper,
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_deleverage_slippage_bps,
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_deleverage_slippage_bound_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+            strategist,
+            guardian,
+        } => {
+            let client = fetch_client(&rpc, chamber)?;
+            let chamber_state = fetch_account::<state::Chamber>(&rpc, &client.chamber)?;
+
+            let mut ixs = vec![];
+
+            if tvl_cap.is_some() || max_user_deposit.is_some() || min_deposit.is_some() {
+                ixs.push(client.update_chamber_limits(
+                    &signer.pubkey(),
+                    tvl_cap.unwrap_or(chamber_state.limits.tvl_cap),
+                    max_user_deposit.unwrap_or(chamber_state.limits.max_user_deposit),
+                    min_deposit.unwrap_or(chamber_state.limits.min_deposit),
+                ));
+            }
+
+            if is_allowlist_enabled.is_some() || gatekeeper.is_some() {
+                ixs.push(client.update_chamber_access(
+                    &signer.pubkey(),
+                    is_allowlist_enabled.unwrap_or(chamber_state.access.is_allowlist_enabled),
+                    &gatekeeper.unwrap_or(chamber_state.access.gatekeeper),
+                ));
+            }
+
+            // Bounds are updated first, so widened bounds admit updated risk config
+            if min_safety_margin_bps.is_some()
+                || max_safety_margin_bps.is_some()
+                || min_target_ltv_bps.is_some()
+                || max_target_ltv_bps.is_some()
+                || max_deleverage_slippage_bound_bps.is_some()
+                || min_rebalance_drift_bps.is_some()
+                || max_rebalance_drift_bps.is_some()
+            {
+                ixs.push(
+                    client.update_chamber_risk_bounds(
+                        &signer.pubkey(),
+                        min_safety_margin_bps
+                            .unwrap_or(chamber_state.risk_bounds.min_safety_margin_bps),
+                        max_safety_margin_bps
+                            .unwrap_or(chamber_state.risk_bounds.max_safety_margin_bps),
+                        min_target_ltv_bps.unwrap_or(chamber_state.risk_bounds.min_target_ltv_bps),
+                        max_target_ltv_bps.unwrap_or(chamber_state.risk_bounds.max_target_ltv_bps),
+                        max_deleverage_slippage_bound_bps
+                            .unwrap_or(chamber_state.risk_bounds.max_slippage_bps),
+                        min_rebalance_drift_bps
+                            .unwrap_or(chamber_state.risk_bounds.min_rebalance_drift_bps),
+                        max_rebalance_drift_bps
+                            .unwrap_or(chamber_state.risk_bounds.max_rebalance_drift_bps),
+                    ),
+                );
+            }
+
+            if liquidation_threshold_bps.is_some()
+                || safety_margin_bps.is_some()
+                || target_ltv_bps.is_some()
+                || rebalance_drift_bps.is_some()
+                || max_deleverage_slippage_bps.is_some()
+            {
+                ixs.push(
+                    client.update_chamber_risk(
+                        &signer.pubkey(),
+                        liquidation_threshold_bps
+                            .unwrap_or(chamber_state.risk.liquidation_threshold_bps),
+                        safety_margin_bps.unwrap_or(chamber_state.risk.safety_margin_bps),
+                        target_ltv_bps.unwrap_or(chamber_state.risk.target_ltv_bps),
+                        rebalance_drift_bps.unwrap_or(chamber_state.risk.rebalance_drift_bps),
+                        max_deleverage_slippage_bps.unwrap_or(chamber_state.risk.max_slippage_bps),
+                    ),
+                );
+            }
+
+            if strategist.is_some() || guardian.is_some() {
+                ixs.push(client.update_chamber_roles(
+                    &signer.pubkey(),
+                    &strategist.unwrap_or(chamber_state.roles.strategist),
+                    &guardian.unwrap_or(chamber_state.roles.guardian),
+                ));
+            }
+
+            if ixs.is_empty() {
+                return Err(anyhow!("Nothing to update"));
+            }
+
+            execute(&cli, &rpc, &ixs, &[&signer], None)
+        }
+    }
+}
+
+fn load_keypair(path: &str) -> Result<Keypair> {
+    let path = match path.strip_prefix("~/") {
+        Some(path) => {
+            let home = std::env::var("HOME").context("HOME is not set")?;
+            PathBuf::from(home).join(path)
+        }
+        None => PathBuf::from(path),
+    };
+
+    read_keypair_file(&path).map_err(|err| anyhow!("Unable to read keypair: {}", err))
+}
+
+fn build_client(args: &ChamberArgs, shares_mint: &Pubkey) -> Result<ChamberClient> {
+    let (farm_config, farm_type) = farm::get_farm(&args.farm)?;
+    let market = farm::load_market_accounts(args.market_accounts.as_deref())?;
+
+    Ok(ChamberClient::new(
+        &farm_config,
+        farm_type,
+        market,
+        args.nonce,
+        shares_mint,
+    ))
+}
+
+/// Build client for existing chamber, using its on-chain shares mint.
+fn fetch_client(rpc: &RpcClient, args: &ChamberArgs) -> Result<ChamberClient> {
+    let client = build_client(args, &Pubkey::default())?;
+    let chamber = fetch_account::<state::Chamber>(rpc, &client.chamber)?;
+
+    Ok(build_client(args, &chamber.config.shares_mint)?
+        .with_active_obligation(chamber.active_obligation))
+}
+
+/// Allowed programs of `ProtocolConfig`, without unused slots.
+fn get_allowed_programs(programs: &[Pubkey]) -> Vec<Pubkey> {
+    programs
+        .iter()
+        .filter(|program| **program != Pubkey::default())
+        .copied()
+        .collect()
+}
+
+/// Fetch all `ChamberRegistry` pages in index order.
+fn fetch_chamber_registry(rpc: &RpcClient) -> Result<Vec<state::ChamberRegistry>> {
+    let protocol_config = fetch_account::<state::ProtocolConfig>(
+        rpc,
+        &cetra_chamber::utils::derive_protocol_config_address().0,
+    )?;
+
+    (0..protocol_config.registry_pages)
+        .map(|index| {
+            fetch_account::<state::ChamberRegistry>(
+                rpc,
+                &cetra_chamber::utils::derive_chamber_registry_address(index).0,
+            )
+        })
+        .collect()
+}
+
+fn fetch_account<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    let account = rpc
+        .get_account(address)
+        .with_context(|| format!("Unable to fetch account: {}", address))?;
+
+    T::try_deserialize(&mut account.data.as_slice())
+        .map_err(|err| anyhow!("Unable to decode account {}: {}", address, err))
+}
+
+/// Fetch `Chamber` lookup table, if it was created.
+fn fetch_lookup_table(rpc: &RpcClient, client: &ChamberClient) -> Result<Option<LookupTable>> {
+    let chamber = fetch_account::<state::Chamber>(rpc, &client.chamber)?;
+    if !chamber.config.has_lookup_table() {
+        return Ok(None);
+    }
+
+    let account = rpc
+        .get_account(&chamber.config.lookup_table)
+        .with_context(|| format!("Unable to fetch account: {}", chamber.config.lookup_table))?;
+
+    LookupTable::deserialize(&chamber.config.lookup_table, &account.data).map(Some)
+}
+
+/// Send `ixs` as v0 transaction with `lookup_table`, or as legacy transaction otherwise.
+fn execute(
+    cli: &Cli,
+    rpc: &RpcClient,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+    lookup_table: Option<&LookupTable>,
+) -> Result<()> {
+    if cli.dry_run {
+        output::print_instructions(ixs, cli.json);
+
+        return Ok(());
+    }
+
+    let blockhash = rpc.get_latest_blockhash()?;
+
+    if let Some(lookup_table) = lookup_table {
+        let message = cetra_chamber_client::lookup_table::compile_v0_message(
+            &signers[0].pubkey(),
+            ixs,
+            lookup_table,
+            blockhash,
+        )?;
+        let message_data = bincode::serialize(&message)?;
+
+        let signer_keys = match &message {
+            VersionedMessage::V0(message) => {
+                &message.account_keys[..usize::from(message.header.num_required_signatures)]
+            }
+            VersionedMessage::Legacy(message) => {
+                &message.account_keys[..usize::from(message.header.num_required_signatures)]
+            }
+        };
+
+        let signatures = signer_keys
+            .iter()
+            .map(|key| {
+                signers
+                    .iter()
+                    .find(|signer| signer.pubkey() == *key)
+                    .map(|signer| signer.sign_message(&message_data))
+                    .ok_or_else(|| anyhow!("Missing signer: {}", key))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let tx = VersionedTransaction {
+            signatures,
+            message,
+        };
+
+        let signature: String = rpc.send(
+            RpcRequest::SendTransaction,
+            serde_json::json!([
+                base64::encode(bincode::serialize(&tx)?),
+                { "encoding": "base64" },
+            ]),
+        )?;
+        let signature = signature.parse()?;
+        rpc.poll_for_signature(&signature)?;
+
+        output::print_signature(&signature, cli.json);
+
+        return Ok(());
+    }
+
+    let tx =
+        Transaction::new_signed_with_payer(ixs, Some(&signers[0].pubkey()), signers, blockhash);
+
+    let signature = rpc.send_and_confirm_transaction(&tx)?;
+    output::print_signature(&signature, cli.json);
+
+    Ok(())
+}
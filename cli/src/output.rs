@@ -0,0 +1,178 @@
+//! Human readable and json output of instructions and decoded accounts.
+
+use cetra_chamber::state;
+use serde_json::{json, Value};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
+
+pub fn instruction_to_json(ix: &Instruction) -> Value {
+    json!({
+        "program_id": ix.program_id.to_string(),
+        "accounts": ix.accounts.iter().map(|meta| json!({
+            "pubkey": meta.pubkey.to_string(),
+            "is_signer": meta.is_signer,
+            "is_writable": meta.is_writable,
+        })).collect::<Vec<_>>(),
+        "data": base64::encode(&ix.data),
+    })
+}
+
+pub fn chamber_to_json(chamber: &state::Chamber) -> Value {
+    json!({
+        "strategy": {
+            "market": format!("{:?}", chamber.strategy.market),
+            "farm": chamber.strategy.farm.to_string(),
+            "farm_program": chamber.strategy.farm_program.to_string(),
+            "leverage": chamber.strategy.leverage,
+            "is_base_volatile": chamber.strategy.is_base_volatile,
+        },
+        "vault": {
+            "base": chamber.vault.base.to_string(),
+            "quote": chamber.vault.quote.to_string(),
+            "base_mint": chamber.vault.base_mint.to_string(),
+            "quote_mint": chamber.vault.quote_mint.to_string(),
+            "base_oracle": chamber.vault.base_oracle.to_string(),
+            "quote_oracle": chamber.vault.quote_oracle.to_string(),
+            "base_decimals": chamber.vault.base_decimals,
+            "quote_decimals": chamber.vault.quote_decimals,
+            "base_amount": chamber.vault.base_amount.to_string(),
+            "quote_amount": chamber.vault.quote_amount.to_string(),
+        },
+        "config": {
+            "authority": chamber.config.authority.to_string(),
+            "owner": chamber.config.owner.to_string(),
+            "fee_manager": chamber.config.fee_manager.to_string(),
+            "shares_mint": chamber.config.shares_mint.to_string(),
+            "nonce": chamber.config.nonce,
+            "lookup_table": chamber.config.lookup_table.to_string(),
+        },
+        "limits": {
+            "tvl_cap": chamber.limits.tvl_cap,
+            "max_user_deposit": chamber.limits.max_user_deposit,
+            "min_deposit": chamber.limits.min_deposit,
+        },
+        "access": {
+            "is_allowlist_enabled": chamber.access.is_allowlist_enabled,
+            "gatekeeper": chamber.access.gatekeeper.to_string(),
+            "is_paused": chamber.access.is_paused,
+        },
+        "harvest": {
+            "reward_mint": chamber.harvest.reward_mint.to_string(),
+            "swap_amm": chamber.harvest.swap_amm.to_string(),
+            "reward_farm": chamber.harvest.reward_farm.to_string(),
+            "is_reward_to_base": chamber.harvest.is_reward_to_base,
+            "performance_fee_bps": chamber.harvest.performance_fee_bps,
+            "max_slippage_bps": chamber.harvest.max_slippage_bps,
+        },
+        "risk": {
+            "liquidation_threshold_bps": chamber.risk.liquidation_threshold_bps,
+            "safety_margin_bps": chamber.risk.safety_margin_bps,
+            "target_ltv_bps": chamber.risk.target_ltv_bps,
+            "rebalance_drift_bps": chamber.risk.rebalance_drift_bps,
+            "max_slippage_bps": chamber.risk.max_slippage_bps,
+        },
+        "risk_bounds": {
+            "min_safety_margin_bps": chamber.risk_bounds.min_safety_margin_bps,
+            "max_safety_margin_bps": chamber.risk_bounds.max_safety_margin_bps,
+            "min_target_ltv_bps": chamber.risk_bounds.min_target_ltv_bps,
+            "max_target_ltv_bps": chamber.risk_bounds.max_target_ltv_bps,
+            "max_slippage_bps": chamber.risk_bounds.max_slippage_bps,
+            "min_rebalance_drift_bps": chamber.risk_bounds.min_rebalance_drift_bps,
+            "max_rebalance_drift_bps": chamber.risk_bounds.max_rebalance_drift_bps,
+        },
+        "roles": {
+            "strategist": chamber.roles.strategist.to_string(),
+            "guardian": chamber.roles.guardian.to_string(),
+        },
+        "timelock": {
+            "delay": chamber.timelock.delay,
+            "pending_leverage": chamber.timelock.pending.leverage,
+            "pending_performance_fee_bps": chamber.timelock.pending.performance_fee_bps,
+            "pending_delay": chamber.timelock.pending.delay,
+            "pending_effective_timestamp": chamber.timelock.pending.effective_timestamp,
+        },
+        "debt": {
+            "base_principal": chamber.debt.base_principal,
+            "quote_principal": chamber.debt.quote_principal,
+            "base_interest": chamber.debt.base_interest,
+            "quote_interest": chamber.debt.quote_interest,
+        },
+        "version": chamber.version,
+        "active_obligation": chamber.active_obligation,
+        "obligations": chamber
+            .obligations
+            .iter()
+            .map(|obligation| json!({
+                "lp_amount": obligation.lp_amount,
+                "base_debt": obligation.base_debt,
+                "quote_debt": obligation.quote_debt,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+pub fn user_account_to_json(user_account: &state::UserAccount) -> Value {
+    json!({
+        "chamber": user_account.chamber.to_string(),
+        "user": user_account.user.to_string(),
+        "shares": user_account.shares.to_string(),
+        "status": format!("{:?}", user_account.status),
+        "locked_base_amount": user_account.locked_base_amount,
+        "locked_quote_amount": user_account.locked_quote_amount,
+        "locked_shares_amount": user_account.locked_shares_amount,
+        "version": user_account.version,
+    })
+}
+
+pub fn protocol_config_to_json(protocol_config: &state::ProtocolConfig) -> Value {
+    json!({
+        "admin": protocol_config.admin.to_string(),
+        "protocol_fee_bps": protocol_config.protocol_fee_bps,
+        "farm_programs": allowed_programs_to_json(&protocol_config.farm_programs),
+        "oracle_programs": allowed_programs_to_json(&protocol_config.oracle_programs),
+        "is_paused": protocol_config.is_paused,
+        "registry_pages": protocol_config.registry_pages,
+        "chambers_count": protocol_config.chambers_count,
+    })
+}
+
+pub fn chamber_registry_entry_to_json(entry: &state::ChamberRegistryEntry) -> Value {
+    json!({
+        "chamber": entry.chamber.to_string(),
+        "market": format!("{:?}", entry.market),
+        "farm": entry.farm.to_string(),
+        "base_mint": entry.base_mint.to_string(),
+        "quote_mint": entry.quote_mint.to_string(),
+        "nonce": entry.nonce,
+        "status": format!("{:?}", entry.status),
+    })
+}
+
+fn allowed_programs_to_json(programs: &[Pubkey]) -> Vec<String> {
+    programs
+        .iter()
+        .filter(|program| **program != Pubkey::default())
+        .map(Pubkey::to_string)
+        .collect()
+}
+
+pub fn print_value(value: &Value, is_json: bool) {
+    if is_json {
+        println!("{}", value);
+    } else {
+        println!("{:#}", value);
+    }
+}
+
+pub fn print_instructions(ixs: &[Instruction], is_json: bool) {
+    let value = Value::Array(ixs.iter().map(instruction_to_json).collect());
+
+    print_value(&value, is_json);
+}
+
+pub fn print_signature(signature: &Signature, is_json: bool) {
+    if is_json {
+        println!("{}", json!({ "signature": signature.to_string() }));
+    } else {
+        println!("Signature: {}", signature);
+    }
+}
@@ -0,0 +1,1030 @@
+use crate::tulip::{RaydiumStakeAccounts, RaydiumSwapAccounts, TulipAccounts, TulipMarketAccounts};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program, sysvar},
+    InstructionData,
+};
+use anchor_spl::{associated_token, token};
+use cetra_chamber::{accounts, instruction, state, utils};
+use tulipv2_sdk_common::config::levfarm::LevFarmConfig;
+use tulipv2_sdk_levfarm::accounts::Farms;
+
+/// Build `cetra_chamber` instructions for single `Tulip` leveraged farm `Chamber`.
+#[derive(Debug, Clone)]
+pub struct ChamberClient {
+    pub chamber: Pubkey,
+    pub chamber_bump: u8,
+    pub chamber_nonce: u8,
+
+    pub authority: Pubkey,
+    pub authority_bump: u8,
+
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub shares_mint: Pubkey,
+
+    pub base_token: Pubkey,
+    pub quote_token: Pubkey,
+
+    pub base_oracle: Pubkey,
+    pub quote_oracle: Pubkey,
+
+    pub tulip: TulipAccounts,
+
+    /// Obligation, used by deposit, withdraw and harvest instructions,
+    /// should match `state::Chamber::active_obligation`.
+    pub active_obligation: u8,
+
+    /// Gatekeeper, which signs user instructions of allowlist enabled `Chamber`,
+    /// user allowlist entry is used otherwise.
+    pub gatekeeper: Option<Pubkey>,
+}
+
+impl ChamberClient {
+    pub fn new(
+        farm_config: &LevFarmConfig,
+        farm_type: Farms,
+        market: TulipMarketAccounts,
+        chamber_nonce: u8,
+        shares_mint: &Pubkey,
+    ) -> Self {
+        let base_mint = farm_config.base_token_mint;
+        let quote_mint = farm_config.quote_token_mint;
+
+        let (chamber, chamber_bump) = utils::derive_chamber_address(
+            &farm_config.account,
+            &base_mint,
+            &quote_mint,
+            chamber_nonce,
+        );
+        let (authority, authority_bump) = utils::derive_chamber_authority_address(&chamber);
+
+        let base_token = associated_token::get_associated_token_address(&authority, &base_mint);
+        let quote_token = associated_token::get_associated_token_address(&authority, &quote_mint);
+
+        ChamberClient {
+            chamber,
+            chamber_bump,
+            chamber_nonce,
+            authority,
+            authority_bump,
+            base_mint,
+            quote_mint,
+            shares_mint: *shares_mint,
+            base_token,
+            quote_token,
+            base_oracle: farm_config.coin_price_account,
+            quote_oracle: farm_config.pc_price_account,
+            tulip: TulipAccounts::new(farm_config, farm_type, market, &authority),
+            active_obligation: 0,
+            gatekeeper: None,
+        }
+    }
+
+    /// Use `obligation_index` obligation accounts for position instructions.
+    pub fn with_active_obligation(mut self, obligation_index: u8) -> Self {
+        self.active_obligation = obligation_index;
+        self
+    }
+
+    /// Permit user instructions with `gatekeeper` signature instead of allowlist entry.
+    pub fn with_gatekeeper(mut self, gatekeeper: &Pubkey) -> Self {
+        self.gatekeeper = Some(*gatekeeper);
+        self
+    }
+
+    /// `Tulip` accounts of active obligation.
+    fn active_tulip(&self) -> TulipAccounts {
+        self.tulip.get_obligation(self.active_obligation)
+    }
+
+    /// Build `initialize_chamber` instruction, listing `Chamber` in last `registry_page`.
+    pub fn initialize_chamber(
+        &self,
+        payer: &Pubkey,
+        owner: &Pubkey,
+        fee_manager: &Pubkey,
+        leverage: u64,
+        is_base_volatile: bool,
+        registry_page: u32,
+    ) -> Instruction {
+        let accounts = accounts::InitializeChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            chamber_registry: utils::derive_chamber_registry_address(registry_page).0,
+            farm_entry: utils::derive_farm_entry_address(&self.tulip.leveraged_farm).0,
+            farm: self.tulip.leveraged_farm,
+            base_token: self.base_token,
+            quote_token: self.quote_token,
+            base_mint: self.base_mint,
+            quote_mint: self.quote_mint,
+            shares_mint: self.shares_mint,
+            base_oracle: self.base_oracle,
+            quote_oracle: self.quote_oracle,
+            base_oracle_entry: utils::derive_oracle_entry_address(&self.base_mint).0,
+            quote_oracle_entry: utils::derive_oracle_entry_address(&self.quote_mint).0,
+            authority: self.authority,
+            fee_manager: *fee_manager,
+            farm_program: tulipv2_sdk_levfarm::ID,
+            payer: *payer,
+            owner: *owner,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::id(),
+        };
+
+        let data = instruction::InitializeChamber {
+            market: state::ChamberMarket::Tulip,
+            leverage,
+            is_base_volatile,
+            chamber_nonce: self.chamber_nonce,
+            authority_bump: self.authority_bump,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip.initialize_chamber_accounts(),
+            data.data(),
+        )
+    }
+
+    pub fn create_user_account(&self, user: &Pubkey) -> Instruction {
+        let accounts = accounts::CreateUserAccount {
+            chamber: self.chamber,
+            user_account: self.user_account_address(user),
+            user_shares: self.user_shares_address(user),
+            shares_mint: self.shares_mint,
+            user: *user,
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.permit_account_metas(vec![AccountMeta::new_readonly(
+                self.permit_address(user),
+                false,
+            )]),
+            instruction::CreateUserAccount {}.data(),
+        )
+    }
+
+    pub fn close_user_account(&self, user: &Pubkey, close_shares: bool) -> Instruction {
+        let accounts = accounts::CloseUserAccount {
+            chamber: self.chamber,
+            user_account: self.user_account_address(user),
+            user_shares: self.user_shares_address(user),
+            user: *user,
+            token_program: token::ID,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::CloseUserAccount { close_shares }.data(),
+        )
+    }
+
+    pub fn begin_deposit_chamber(
+        &self,
+        user: &Pubkey,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> Instruction {
+        let accounts = accounts::BeginDepositChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            user_account: self.user_account_address(user),
+            user_shares: self.user_shares_address(user),
+            user_base_token: self.user_token_address(user, &self.base_mint),
+            user_quote_token: self.user_token_address(user, &self.quote_mint),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            permit: self.permit_address(user),
+            user: *user,
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        let data = instruction::BeginDepositChamber {
+            base_amount,
+            quote_amount,
+        };
+
+        build_instruction(
+            self.permit_account_metas(accounts.to_account_metas(None)),
+            self.active_tulip().begin_deposit_chamber_accounts(),
+            data.data(),
+        )
+    }
+
+    pub fn process_deposit_chamber(&self, user: &Pubkey) -> Instruction {
+        let accounts = accounts::ProcessDepositChamber {
+            chamber: self.chamber,
+            user_account: self.user_account_address(user),
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            user: *user,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip().process_deposit_chamber_accounts(),
+            instruction::ProcessDepositChamber {}.data(),
+        )
+    }
+
+    pub fn end_deposit_chamber(&self, user: &Pubkey) -> Instruction {
+        let accounts = accounts::EndDepositChamber {
+            chamber: self.chamber,
+            user_account: self.user_account_address(user),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            user_shares: self.user_shares_address(user),
+            user: *user,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip().end_deposit_chamber_accounts(),
+            instruction::EndDepositChamber {}.data(),
+        )
+    }
+
+    /// Build `cancel_deposit_chamber` instruction, which refunds pending `user` deposit,
+    /// not yet moved into position.
+    pub fn cancel_deposit_chamber(&self, signer: &Pubkey, user: &Pubkey) -> Instruction {
+        let accounts = accounts::CancelDepositChamber {
+            chamber: self.chamber,
+            user_account: self.user_account_address(user),
+            user_base_token: self.user_token_address(user, &self.base_mint),
+            user_quote_token: self.user_token_address(user, &self.quote_mint),
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_authority: self.authority,
+            signer: *signer,
+            token_program: token::ID,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::CancelDepositChamber {}.data(),
+        )
+    }
+
+    pub fn deposit_chamber(
+        &self,
+        user: &Pubkey,
+        base_amount: u64,
+        quote_amount: u64,
+        native_amount: u64,
+    ) -> Instruction {
+        let user_account = self.user_account_address(user);
+
+        let accounts = accounts::DepositChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            user_account,
+            user_shares: self.user_shares_address(user),
+            user_base_token: self.user_token_address(user, &self.base_mint),
+            user_quote_token: self.user_token_address(user, &self.quote_mint),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: utils::derive_native_token_address(&user_account).0,
+            native_mint: token::spl_token::native_mint::ID,
+            permit: self.permit_address(user),
+            user: *user,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        let data = instruction::DepositChamber {
+            base_amount,
+            quote_amount,
+            native_amount,
+        };
+
+        build_instruction(
+            self.permit_account_metas(accounts.to_account_metas(None)),
+            self.active_tulip().deposit_chamber_accounts(),
+            data.data(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn withdraw_chamber(
+        &self,
+        user: &Pubkey,
+        shares_amount: u64,
+        output_mint: Option<Pubkey>,
+        min_base_out: u64,
+        min_quote_out: u64,
+        unwrap_native: bool,
+    ) -> Instruction {
+        let user_account = self.user_account_address(user);
+
+        let accounts = accounts::WithdrawChamber {
+            chamber: self.chamber,
+            user_account,
+            user_shares: self.user_shares_address(user),
+            user_base_token: self.user_token_address(user, &self.base_mint),
+            user_quote_token: self.user_token_address(user, &self.quote_mint),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: utils::derive_native_token_address(&user_account).0,
+            native_mint: token::spl_token::native_mint::ID,
+            user: *user,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        let data = instruction::WithdrawChamber {
+            shares_amount,
+            output_mint,
+            min_base_out,
+            min_quote_out,
+            unwrap_native,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip().withdraw_chamber_accounts(),
+            data.data(),
+        )
+    }
+
+    /// Build `rebalance_chamber` instruction, moving `withdraw_percent` of active
+    /// obligation position into `target_obligation`.
+    pub fn rebalance_chamber(
+        &self,
+        strategist: &Pubkey,
+        target_obligation: u8,
+        withdraw_percent: u8,
+    ) -> Instruction {
+        let accounts = accounts::RebalanceChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            strategist: *strategist,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip()
+                .rebalance_chamber_accounts(&self.tulip.get_obligation(target_obligation)),
+            instruction::RebalanceChamber {
+                target_obligation,
+                withdraw_percent,
+            }
+            .data(),
+        )
+    }
+
+    /// Build `harvest_chamber` instruction, collecting `reward_mint` rewards from `reward_farm`
+    /// and swapping them via `route`, priced by `reward_oracle`.
+    ///
+    /// Performance fee is split between `fee_manager_token` and protocol admin `protocol_fee_token`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn harvest_chamber(
+        &self,
+        cranker: &Pubkey,
+        reward_mint: &Pubkey,
+        reward_oracle: &Pubkey,
+        fee_manager_token: &Pubkey,
+        protocol_fee_token: &Pubkey,
+        reward_farm: &RaydiumStakeAccounts,
+        route: &RaydiumSwapAccounts,
+        min_out: u64,
+    ) -> Instruction {
+        let accounts = accounts::HarvestChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            chamber_reward_token: associated_token::get_associated_token_address(
+                &self.authority,
+                reward_mint,
+            ),
+            reward_mint: *reward_mint,
+            reward_oracle_entry: utils::derive_oracle_entry_address(reward_mint).0,
+            reward_oracle: *reward_oracle,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            fee_manager_token: *fee_manager_token,
+            protocol_fee_token: *protocol_fee_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            swap_program: self.tulip.liquidity_program_id,
+            cranker: *cranker,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::ID,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip()
+                .harvest_chamber_accounts(route, reward_farm),
+            instruction::HarvestChamber { min_out }.data(),
+        )
+    }
+
+    /// Build `deleverage_chamber` instruction, which partially repays `obligation_index` obligation debt.
+    ///
+    /// Zero `withdraw_percent` brings obligation to target LTV, otherwise guardian forces it.
+    pub fn deleverage_chamber(
+        &self,
+        cranker: &Pubkey,
+        obligation_index: u8,
+        min_coin_swap: u64,
+        min_pc_swap: u64,
+        withdraw_percent: u8,
+    ) -> Instruction {
+        let accounts = accounts::DeleverageChamber {
+            chamber: self.chamber,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            cranker: *cranker,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        let data = instruction::DeleverageChamber {
+            obligation_index,
+            min_coin_swap,
+            min_pc_swap,
+            withdraw_percent,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip
+                .get_obligation(obligation_index)
+                .deleverage_chamber_accounts(),
+            data.data(),
+        )
+    }
+
+    /// Build `releverage_chamber` instruction, which borrows active obligation up to target leverage.
+    pub fn releverage_chamber(&self, strategist: &Pubkey) -> Instruction {
+        let accounts = accounts::ReleverageChamber {
+            chamber: self.chamber,
+            protocol_config: utils::derive_protocol_config_address().0,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            strategist: *strategist,
+            clock_sysvar: sysvar::clock::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.active_tulip().releverage_chamber_accounts(),
+            instruction::ReleverageChamber {}.data(),
+        )
+    }
+
+    /// Build `close_chamber` instruction, which sweeps leftover tokens into `owner` atas.
+    pub fn close_chamber(&self, owner: &Pubkey) -> Instruction {
+        let accounts = accounts::CloseChamber {
+            chamber: self.chamber,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_shares_mint: self.shares_mint,
+            owner_base_token: self.user_token_address(owner, &self.base_mint),
+            owner_quote_token: self.user_token_address(owner, &self.quote_mint),
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            owner: *owner,
+            token_program: token::ID,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip.close_chamber_accounts(),
+            instruction::CloseChamber {}.data(),
+        )
+    }
+
+    /// Build `get_chamber_nav` view instruction, which returns `views::ChamberNav`.
+    pub fn get_chamber_nav(&self) -> Instruction {
+        let accounts = accounts::GetChamberNav {
+            chamber: self.chamber,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip.view_chamber_accounts(),
+            instruction::GetChamberNav {}.data(),
+        )
+    }
+
+    /// Build `get_share_price` view instruction, which returns `views::SharePrice`.
+    pub fn get_share_price(&self) -> Instruction {
+        let accounts = accounts::GetSharePrice {
+            chamber: self.chamber,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip.view_chamber_accounts(),
+            instruction::GetSharePrice {}.data(),
+        )
+    }
+
+    /// Build `get_user_position` view instruction, which returns `user` `views::UserPosition`.
+    pub fn get_user_position(&self, user: &Pubkey) -> Instruction {
+        let accounts = accounts::GetUserPosition {
+            chamber: self.chamber,
+            chamber_base_token: self.base_token,
+            chamber_quote_token: self.quote_token,
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            user_account: self.user_account_address(user),
+            user_shares: self.user_shares_address(user),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            self.tulip.view_chamber_accounts(),
+            instruction::GetUserPosition {}.data(),
+        )
+    }
+
+    /// Build `migrate_chamber` instruction, which upgrades `Chamber` to current layout.
+    pub fn migrate_chamber(&self, owner: &Pubkey) -> Instruction {
+        let accounts = accounts::MigrateChamber {
+            chamber: self.chamber,
+            owner: *owner,
+            rent_sysvar: sysvar::rent::id(),
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::MigrateChamber {}.data(),
+        )
+    }
+
+    /// Build `migrate_user_account` instruction, which upgrades `user` `UserAccount` to current layout.
+    pub fn migrate_user_account(&self, user: &Pubkey) -> Instruction {
+        let accounts = accounts::MigrateUserAccount {
+            user_account: self.user_account_address(user),
+            user: *user,
+            rent_sysvar: sysvar::rent::id(),
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::MigrateUserAccount {}.data(),
+        )
+    }
+
+    pub fn update_chamber_limits(
+        &self,
+        owner: &Pubkey,
+        tvl_cap: u64,
+        max_user_deposit: u64,
+        min_deposit: u64,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberLimits {
+            chamber: self.chamber,
+            owner: *owner,
+        };
+
+        let data = instruction::UpdateChamberLimits {
+            tvl_cap,
+            max_user_deposit,
+            min_deposit,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    pub fn update_chamber_access(
+        &self,
+        owner: &Pubkey,
+        is_allowlist_enabled: bool,
+        gatekeeper: &Pubkey,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberAccess {
+            chamber: self.chamber,
+            owner: *owner,
+        };
+
+        let data = instruction::UpdateChamberAccess {
+            is_allowlist_enabled,
+            gatekeeper: *gatekeeper,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_chamber_harvest(
+        &self,
+        owner: &Pubkey,
+        reward_mint: &Pubkey,
+        swap_amm: &Pubkey,
+        reward_farm: &Pubkey,
+        is_reward_to_base: bool,
+        max_slippage_bps: u64,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberHarvest {
+            chamber: self.chamber,
+            owner: *owner,
+        };
+
+        let data = instruction::UpdateChamberHarvest {
+            reward_mint: *reward_mint,
+            swap_amm: *swap_amm,
+            reward_farm: *reward_farm,
+            is_reward_to_base,
+            max_slippage_bps,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    pub fn update_chamber_risk(
+        &self,
+        signer: &Pubkey,
+        liquidation_threshold_bps: u64,
+        safety_margin_bps: u64,
+        target_ltv_bps: u64,
+        rebalance_drift_bps: u64,
+        max_slippage_bps: u64,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberRisk {
+            chamber: self.chamber,
+            signer: *signer,
+        };
+
+        let data = instruction::UpdateChamberRisk {
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_slippage_bps,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    pub fn update_chamber_risk_bounds(
+        &self,
+        owner: &Pubkey,
+        min_safety_margin_bps: u64,
+        max_safety_margin_bps: u64,
+        min_target_ltv_bps: u64,
+        max_target_ltv_bps: u64,
+        max_slippage_bps: u64,
+        min_rebalance_drift_bps: u64,
+        max_rebalance_drift_bps: u64,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberRiskBounds {
+            chamber: self.chamber,
+            owner: *owner,
+        };
+
+        let data = instruction::UpdateChamberRiskBounds {
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_slippage_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    pub fn update_chamber_roles(
+        &self,
+        owner: &Pubkey,
+        strategist: &Pubkey,
+        guardian: &Pubkey,
+    ) -> Instruction {
+        let accounts = accounts::UpdateChamberRoles {
+            chamber: self.chamber,
+            owner: *owner,
+        };
+
+        let data = instruction::UpdateChamberRoles {
+            strategist: *strategist,
+            guardian: *guardian,
+        };
+
+        build_instruction(accounts.to_account_metas(None), vec![], data.data())
+    }
+
+    pub fn add_allowlist_entry(&self, owner: &Pubkey, user: &Pubkey) -> Instruction {
+        let accounts = accounts::AddAllowlistEntry {
+            chamber: self.chamber,
+            allowlist_entry: utils::derive_allowlist_entry_address(&self.chamber, user).0,
+            owner: *owner,
+            system_program: system_program::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::AddAllowlistEntry { user: *user }.data(),
+        )
+    }
+
+    pub fn remove_allowlist_entry(&self, owner: &Pubkey, user: &Pubkey) -> Instruction {
+        let accounts = accounts::RemoveAllowlistEntry {
+            chamber: self.chamber,
+            allowlist_entry: utils::derive_allowlist_entry_address(&self.chamber, user).0,
+            owner: *owner,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::RemoveAllowlistEntry {}.data(),
+        )
+    }
+
+    pub fn set_chamber_pause(&self, signer: &Pubkey, is_paused: bool) -> Instruction {
+        let accounts = accounts::SetChamberPause {
+            chamber: self.chamber,
+            signer: *signer,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::SetChamberPause { is_paused }.data(),
+        )
+    }
+
+    pub fn propose_config_change(
+        &self,
+        owner: &Pubkey,
+        leverage: u64,
+        performance_fee_bps: u64,
+        delay: i64,
+        effective_timestamp: i64,
+    ) -> Instruction {
+        let accounts = accounts::ProposeConfigChange {
+            chamber: self.chamber,
+            owner: *owner,
+            clock_sysvar: sysvar::clock::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::ProposeConfigChange {
+                leverage,
+                performance_fee_bps,
+                delay,
+                effective_timestamp,
+            }
+            .data(),
+        )
+    }
+
+    pub fn execute_config_change(&self, cranker: &Pubkey) -> Instruction {
+        let accounts = accounts::ExecuteConfigChange {
+            chamber: self.chamber,
+            cranker: *cranker,
+            clock_sysvar: sysvar::clock::id(),
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::ExecuteConfigChange {}.data(),
+        )
+    }
+
+    pub fn cancel_config_change(&self, signer: &Pubkey) -> Instruction {
+        let accounts = accounts::CancelConfigChange {
+            chamber: self.chamber,
+            signer: *signer,
+        };
+
+        build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::CancelConfigChange {}.data(),
+        )
+    }
+
+    /// Build `create_chamber_lookup_table` instruction, returns it with lookup table address.
+    pub fn create_chamber_lookup_table(
+        &self,
+        payer: &Pubkey,
+        owner: &Pubkey,
+        recent_slot: u64,
+    ) -> (Instruction, Pubkey) {
+        let (lookup_table, lookup_table_bump) =
+            utils::derive_lookup_table_address(&self.authority, recent_slot);
+
+        let accounts = accounts::CreateChamberLookupTable {
+            chamber: self.chamber,
+            lookup_table,
+            chamber_authority: self.authority,
+            payer: *payer,
+            owner: *owner,
+            address_lookup_table_program: utils::address_lookup_table_program::id(),
+            system_program: system_program::id(),
+        };
+
+        let ix = build_instruction(
+            accounts.to_account_metas(None),
+            vec![],
+            instruction::CreateChamberLookupTable {
+                recent_slot,
+                lookup_table_bump,
+            }
+            .data(),
+        );
+
+        (ix, lookup_table)
+    }
+
+    /// Build `extend_chamber_lookup_table` instructions, split by max addresses per instruction.
+    pub fn extend_chamber_lookup_table(
+        &self,
+        payer: &Pubkey,
+        owner: &Pubkey,
+        lookup_table: &Pubkey,
+        addresses: &[Pubkey],
+    ) -> Vec<Instruction> {
+        addresses
+            .chunks(utils::MAX_LOOKUP_TABLE_EXTEND_ADDRESSES)
+            .map(|addresses| {
+                let accounts = accounts::ExtendChamberLookupTable {
+                    chamber: self.chamber,
+                    lookup_table: *lookup_table,
+                    chamber_authority: self.authority,
+                    payer: *payer,
+                    owner: *owner,
+                    address_lookup_table_program: utils::address_lookup_table_program::id(),
+                    system_program: system_program::id(),
+                };
+
+                build_instruction(
+                    accounts.to_account_metas(None),
+                    vec![],
+                    instruction::ExtendChamberLookupTable {
+                        addresses: addresses.to_vec(),
+                    }
+                    .data(),
+                )
+            })
+            .collect()
+    }
+
+    /// Static `Chamber` and market accounts, which should be stored in `Chamber` lookup table.
+    pub fn lookup_table_addresses(&self) -> Vec<Pubkey> {
+        let mut addresses = vec![
+            cetra_chamber::id(),
+            self.chamber,
+            self.authority,
+            self.base_mint,
+            self.quote_mint,
+            self.shares_mint,
+            self.base_token,
+            self.quote_token,
+            self.base_oracle,
+            self.quote_oracle,
+            token::ID,
+            associated_token::ID,
+            system_program::id(),
+            sysvar::rent::id(),
+            sysvar::clock::id(),
+        ];
+
+        for address in self.tulip.lookup_table_addresses() {
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+
+        addresses
+    }
+
+    pub fn user_account_address(&self, user: &Pubkey) -> Pubkey {
+        utils::derive_user_account_address(&self.chamber, user).0
+    }
+
+    /// Gatekeeper or `user` allowlist entry, which permits `user` to use `Chamber`.
+    pub fn permit_address(&self, user: &Pubkey) -> Pubkey {
+        self.gatekeeper
+            .unwrap_or_else(|| utils::derive_allowlist_entry_address(&self.chamber, user).0)
+    }
+
+    /// Mark gatekeeper permit in `accounts` as signer.
+    fn permit_account_metas(&self, mut accounts: Vec<AccountMeta>) -> Vec<AccountMeta> {
+        if let Some(gatekeeper) = self.gatekeeper {
+            for account in accounts.iter_mut() {
+                if account.pubkey == gatekeeper {
+                    account.is_signer = true;
+                }
+            }
+        }
+
+        accounts
+    }
+
+    pub fn user_shares_address(&self, user: &Pubkey) -> Pubkey {
+        self.user_token_address(user, &self.shares_mint)
+    }
+
+    pub fn user_token_address(&self, user: &Pubkey, mint: &Pubkey) -> Pubkey {
+        associated_token::get_associated_token_address(user, mint)
+    }
+}
+
+pub(crate) fn build_instruction(
+    mut accounts: Vec<AccountMeta>,
+    remaining_accounts: Vec<AccountMeta>,
+    data: Vec<u8>,
+) -> Instruction {
+    accounts.extend(remaining_accounts);
+
+    Instruction {
+        program_id: cetra_chamber::id(),
+        accounts,
+        data,
+    }
+}
@@ -0,0 +1,101 @@
+//! Named `Tulip` leveraged farm configs and market accounts loading.
+
+use crate::tulip::TulipMarketAccounts;
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::{fs, path::Path, str::FromStr};
+use tulipv2_sdk_common::config::levfarm::{self, LevFarmConfig};
+use tulipv2_sdk_levfarm::accounts::Farms;
+
+/// Program, owning named `Tulip` leveraged farms.
+pub const FARM_PROGRAM: Pubkey = tulipv2_sdk_levfarm::ID;
+
+/// Get `Tulip` leveraged farm config and type by farm name.
+pub fn get_farm(name: &str) -> Result<(LevFarmConfig, Farms)> {
+    match name {
+        "ray-sol-usdc" => Ok((
+            levfarm::ray_solusdc::get_lev_farm_config(),
+            Farms::SolUsdcRayVault,
+        )),
+        _ => Err(anyhow!("Unknown farm: {}", name)),
+    }
+}
+
+/// `TulipMarketAccounts` json representation, with base58 encoded keys.
+#[derive(Debug, Deserialize)]
+struct MarketAccountsFile {
+    coin_reserve_account: String,
+    pc_reserve_account: String,
+    coin_reserve_liquidity_token_account: String,
+    pc_reserve_liquidity_token_account: String,
+    borrow_authorizer: String,
+    vault: String,
+    vault_pda_account: String,
+    vault_info_account: String,
+    vault_signer: String,
+    lp_token_account: String,
+    user_balance_account: String,
+    user_balance_metadata: String,
+    user_info_account: String,
+    /// Second obligation vault accounts, absent for single obligation markets.
+    #[serde(default)]
+    user_balance_account_1: Option<String>,
+    #[serde(default)]
+    user_balance_metadata_1: Option<String>,
+    #[serde(default)]
+    user_info_account_1: Option<String>,
+    stake_program_id: String,
+    pool_id: String,
+    pool_authority: String,
+    pool_lp_token_account: String,
+    user_reward_a_token_account: String,
+    pool_reward_a_token_account: String,
+    user_reward_b_token_account: String,
+    pool_reward_b_token_account: String,
+}
+
+/// Load market accounts from json file, or use empty accounts if no file provided.
+pub fn load_market_accounts(path: Option<&Path>) -> Result<TulipMarketAccounts> {
+    let Some(path) = path else {
+        return Ok(TulipMarketAccounts::default());
+    };
+
+    let data = fs::read_to_string(path)
+        .with_context(|| format!("Unable to read market accounts: {}", path.display()))?;
+    let file: MarketAccountsFile = serde_json::from_str(&data)?;
+
+    let parse = |key: &str| {
+        Pubkey::from_str(key).map_err(|_| anyhow!("Invalid market account key: {}", key))
+    };
+    let parse_optional = |key: &Option<String>| -> Result<Pubkey> {
+        Ok(key.as_deref().map(parse).transpose()?.unwrap_or_default())
+    };
+
+    Ok(TulipMarketAccounts {
+        coin_reserve_account: parse(&file.coin_reserve_account)?,
+        pc_reserve_account: parse(&file.pc_reserve_account)?,
+        coin_reserve_liquidity_token_account: parse(&file.coin_reserve_liquidity_token_account)?,
+        pc_reserve_liquidity_token_account: parse(&file.pc_reserve_liquidity_token_account)?,
+        borrow_authorizer: parse(&file.borrow_authorizer)?,
+        vault: parse(&file.vault)?,
+        vault_pda_account: parse(&file.vault_pda_account)?,
+        vault_info_account: parse(&file.vault_info_account)?,
+        vault_signer: parse(&file.vault_signer)?,
+        lp_token_account: parse(&file.lp_token_account)?,
+        user_balance_account: parse(&file.user_balance_account)?,
+        user_balance_metadata: parse(&file.user_balance_metadata)?,
+        user_info_account: parse(&file.user_info_account)?,
+        user_balance_account_1: parse_optional(&file.user_balance_account_1)?,
+        user_balance_metadata_1: parse_optional(&file.user_balance_metadata_1)?,
+        user_info_account_1: parse_optional(&file.user_info_account_1)?,
+        stake_program_id: parse(&file.stake_program_id)?,
+        pool_id: parse(&file.pool_id)?,
+        pool_authority: parse(&file.pool_authority)?,
+        pool_lp_token_account: parse(&file.pool_lp_token_account)?,
+        user_reward_a_token_account: parse(&file.user_reward_a_token_account)?,
+        pool_reward_a_token_account: parse(&file.pool_reward_a_token_account)?,
+        user_reward_b_token_account: parse(&file.user_reward_b_token_account)?,
+        pool_reward_b_token_account: parse(&file.pool_reward_b_token_account)?,
+    })
+}
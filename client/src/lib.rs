@@ -0,0 +1,9 @@
+//! Off-chain instruction builders for `cetra_chamber` program.
+
+mod chamber;
+pub mod farm;
+pub mod lookup_table;
+pub mod protocol;
+pub mod tulip;
+
+pub use chamber::*;
@@ -0,0 +1,190 @@
+//! Module provide `Chamber` Address Lookup Table helpers and v0 message compilation.
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::Hash,
+        instruction::{CompiledInstruction, Instruction},
+        message::{v0, MessageHeader, VersionedMessage},
+    },
+};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+/// Size of lookup table metadata, which precedes stored addresses.
+pub const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Decoded Address Lookup Table.
+#[derive(Debug, Clone, Default)]
+pub struct LookupTable {
+    pub address: Pubkey,
+    pub addresses: Vec<Pubkey>,
+}
+
+impl LookupTable {
+    /// Decode lookup table `address` from raw account `data`.
+    pub fn deserialize(address: &Pubkey, data: &[u8]) -> Result<Self> {
+        let addresses_data = data
+            .get(LOOKUP_TABLE_META_SIZE..)
+            .ok_or_else(|| anyhow!("Invalid lookup table: {}", address))?;
+
+        if addresses_data.len() % 32 != 0 {
+            return Err(anyhow!("Invalid lookup table: {}", address));
+        }
+
+        Ok(LookupTable {
+            address: *address,
+            addresses: addresses_data
+                .chunks(32)
+                .map(|chunk| Pubkey::new(chunk))
+                .collect(),
+        })
+    }
+
+    /// Addresses of `addresses`, which are missing in lookup table.
+    pub fn get_missing_addresses(&self, addresses: &[Pubkey]) -> Vec<Pubkey> {
+        addresses
+            .iter()
+            .filter(|address| !self.addresses.contains(address))
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct KeyMeta {
+    is_signer: bool,
+    is_writable: bool,
+    is_invoked: bool,
+}
+
+/// Compile `instructions` into v0 message, loading non-signer accounts from `lookup_table`.
+pub fn compile_v0_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_table: &LookupTable,
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage> {
+    // 1. Collect account keys with merged privileges, payer always goes first
+    let mut key_metas = BTreeMap::<Pubkey, KeyMeta>::new();
+    key_metas.insert(
+        *payer,
+        KeyMeta {
+            is_signer: true,
+            is_writable: true,
+            is_invoked: false,
+        },
+    );
+
+    for ix in instructions {
+        key_metas.entry(ix.program_id).or_default().is_invoked = true;
+
+        for account in &ix.accounts {
+            let key_meta = key_metas.entry(account.pubkey).or_default();
+            key_meta.is_signer |= account.is_signer;
+            key_meta.is_writable |= account.is_writable;
+        }
+    }
+
+    // 2. Split keys into static and loaded from lookup table
+    let mut writable_signers = vec![*payer];
+    let mut readonly_signers = vec![];
+    let mut writable_keys = vec![];
+    let mut readonly_keys = vec![];
+    let mut writable_indexes = vec![];
+    let mut readonly_indexes = vec![];
+    let mut writable_loaded = vec![];
+    let mut readonly_loaded = vec![];
+
+    for (key, key_meta) in &key_metas {
+        if key == payer {
+            continue;
+        }
+
+        let table_index = lookup_table
+            .addresses
+            .iter()
+            .position(|address| address == key);
+
+        match (key_meta.is_signer, key_meta.is_invoked, table_index) {
+            (true, _, _) if key_meta.is_writable => writable_signers.push(*key),
+            (true, _, _) => readonly_signers.push(*key),
+            (false, false, Some(index)) => {
+                let index = u8::try_from(index)
+                    .map_err(|_| anyhow!("Lookup table index overflow: {}", key))?;
+
+                if key_meta.is_writable {
+                    writable_indexes.push(index);
+                    writable_loaded.push(*key);
+                } else {
+                    readonly_indexes.push(index);
+                    readonly_loaded.push(*key);
+                }
+            }
+            _ if key_meta.is_writable => writable_keys.push(*key),
+            _ => readonly_keys.push(*key),
+        }
+    }
+
+    let header = MessageHeader {
+        num_required_signatures: (writable_signers.len() + readonly_signers.len()) as u8,
+        num_readonly_signed_accounts: readonly_signers.len() as u8,
+        num_readonly_unsigned_accounts: readonly_keys.len() as u8,
+    };
+
+    let account_keys: Vec<Pubkey> = writable_signers
+        .into_iter()
+        .chain(readonly_signers)
+        .chain(writable_keys)
+        .chain(readonly_keys)
+        .collect();
+
+    // 3. Resolve instruction account indexes, loaded keys follow static keys
+    let all_keys: Vec<Pubkey> = account_keys
+        .iter()
+        .chain(writable_loaded.iter())
+        .chain(readonly_loaded.iter())
+        .copied()
+        .collect();
+
+    let get_index = |key: &Pubkey| -> Result<u8> {
+        all_keys
+            .iter()
+            .position(|account_key| account_key == key)
+            .and_then(|index| u8::try_from(index).ok())
+            .ok_or_else(|| anyhow!("Unable to compile account: {}", key))
+    };
+
+    let instructions = instructions
+        .iter()
+        .map(|ix| {
+            Ok(CompiledInstruction {
+                program_id_index: get_index(&ix.program_id)?,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|account| get_index(&account.pubkey))
+                    .collect::<Result<_>>()?,
+                data: ix.data.clone(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    let address_table_lookups = if writable_indexes.is_empty() && readonly_indexes.is_empty() {
+        vec![]
+    } else {
+        vec![v0::MessageAddressTableLookup {
+            account_key: lookup_table.address,
+            writable_indexes,
+            readonly_indexes,
+        }]
+    };
+
+    Ok(VersionedMessage::V0(v0::Message {
+        header,
+        account_keys,
+        recent_blockhash,
+        instructions,
+        address_table_lookups,
+    }))
+}
@@ -0,0 +1,158 @@
+use crate::chamber::build_instruction;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program},
+    InstructionData,
+};
+use cetra_chamber::{accounts, instruction, utils};
+
+/// Build `initialize_protocol_config` instruction, program upgrade authority `admin` pays
+/// and becomes protocol admin.
+pub fn initialize_protocol_config(admin: &Pubkey) -> Instruction {
+    let accounts = accounts::InitializeProtocolConfig {
+        protocol_config: utils::derive_protocol_config_address().0,
+        program: cetra_chamber::id(),
+        program_data: utils::derive_program_data_address().0,
+        admin: *admin,
+        system_program: system_program::id(),
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::InitializeProtocolConfig {}.data(),
+    )
+}
+
+pub fn update_protocol_config(
+    signer: &Pubkey,
+    admin: &Pubkey,
+    protocol_fee_bps: u64,
+    farm_programs: Vec<Pubkey>,
+    oracle_programs: Vec<Pubkey>,
+) -> Instruction {
+    let accounts = accounts::UpdateProtocolConfig {
+        protocol_config: utils::derive_protocol_config_address().0,
+        signer: *signer,
+    };
+
+    let data = instruction::UpdateProtocolConfig {
+        admin: *admin,
+        protocol_fee_bps,
+        farm_programs,
+        oracle_programs,
+    };
+
+    build_instruction(accounts.to_account_metas(None), vec![], data.data())
+}
+
+pub fn set_protocol_pause(admin: &Pubkey, is_paused: bool) -> Instruction {
+    let accounts = accounts::SetProtocolPause {
+        protocol_config: utils::derive_protocol_config_address().0,
+        admin: *admin,
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::SetProtocolPause { is_paused }.data(),
+    )
+}
+
+pub fn add_farm_entry(admin: &Pubkey, farm: &Pubkey, farm_program: &Pubkey) -> Instruction {
+    let accounts = accounts::AddFarmEntry {
+        protocol_config: utils::derive_protocol_config_address().0,
+        farm_entry: utils::derive_farm_entry_address(farm).0,
+        farm: *farm,
+        farm_program: *farm_program,
+        admin: *admin,
+        system_program: system_program::id(),
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::AddFarmEntry {}.data(),
+    )
+}
+
+pub fn remove_farm_entry(admin: &Pubkey, farm: &Pubkey) -> Instruction {
+    let accounts = accounts::RemoveFarmEntry {
+        protocol_config: utils::derive_protocol_config_address().0,
+        farm_entry: utils::derive_farm_entry_address(farm).0,
+        admin: *admin,
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::RemoveFarmEntry {}.data(),
+    )
+}
+
+/// Build `add_oracle_entry` instruction, allowing `Pyth` `product` and `price` as `mint` oracle.
+pub fn add_oracle_entry(
+    admin: &Pubkey,
+    mint: &Pubkey,
+    product: &Pubkey,
+    price: &Pubkey,
+) -> Instruction {
+    let accounts = accounts::AddOracleEntry {
+        protocol_config: utils::derive_protocol_config_address().0,
+        oracle_entry: utils::derive_oracle_entry_address(mint).0,
+        mint: *mint,
+        product: *product,
+        price: *price,
+        admin: *admin,
+        system_program: system_program::id(),
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::AddOracleEntry {}.data(),
+    )
+}
+
+pub fn remove_oracle_entry(admin: &Pubkey, mint: &Pubkey) -> Instruction {
+    let accounts = accounts::RemoveOracleEntry {
+        protocol_config: utils::derive_protocol_config_address().0,
+        oracle_entry: utils::derive_oracle_entry_address(mint).0,
+        admin: *admin,
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::RemoveOracleEntry {}.data(),
+    )
+}
+
+/// Build `create_chamber_registry_page` instruction for next `registry_page`.
+pub fn create_chamber_registry_page(payer: &Pubkey, registry_page: u32) -> Instruction {
+    let accounts = accounts::CreateChamberRegistryPage {
+        protocol_config: utils::derive_protocol_config_address().0,
+        chamber_registry: utils::derive_chamber_registry_address(registry_page).0,
+        payer: *payer,
+        system_program: system_program::id(),
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::CreateChamberRegistryPage {}.data(),
+    )
+}
+
+pub fn sync_chamber_registry(registry_page: u32, chamber: &Pubkey) -> Instruction {
+    let accounts = accounts::SyncChamberRegistry {
+        chamber_registry: utils::derive_chamber_registry_address(registry_page).0,
+        chamber: *chamber,
+    };
+
+    build_instruction(
+        accounts.to_account_metas(None),
+        vec![],
+        instruction::SyncChamberRegistry {}.data(),
+    )
+}
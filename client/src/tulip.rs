@@ -0,0 +1,818 @@
+//! Module provide `Tulip` leveraged farm accounts, used as `Chamber` remaining accounts.
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use tulipv2_sdk_common::config::levfarm::LevFarmConfig;
+use tulipv2_sdk_levfarm::accounts::{derivations, Farms};
+
+/// `Raydium` liquidity(AMM v4) program.
+pub const RAYDIUM_LIQUIDITY_PROGRAM_ID: Pubkey = cetra_chamber::utils::raydium_amm_program::ID;
+
+/// `Serum` dex(v3) program.
+pub const SERUM_DEX_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin");
+
+/// Lending reserves and `Tulip` vault accounts, which are not part of `LevFarmConfig`.
+#[derive(Debug, Clone, Default)]
+pub struct TulipMarketAccounts {
+    pub coin_reserve_account: Pubkey,
+    pub pc_reserve_account: Pubkey,
+    pub coin_reserve_liquidity_token_account: Pubkey,
+    pub pc_reserve_liquidity_token_account: Pubkey,
+    pub borrow_authorizer: Pubkey,
+
+    pub vault: Pubkey,
+    pub vault_pda_account: Pubkey,
+    pub vault_info_account: Pubkey,
+    pub vault_signer: Pubkey,
+    pub lp_token_account: Pubkey,
+    pub user_balance_account: Pubkey,
+    pub user_balance_metadata: Pubkey,
+    pub user_info_account: Pubkey,
+    pub user_balance_account_1: Pubkey,
+    pub user_balance_metadata_1: Pubkey,
+    pub user_info_account_1: Pubkey,
+
+    pub stake_program_id: Pubkey,
+    pub pool_id: Pubkey,
+    pub pool_authority: Pubkey,
+    pub pool_lp_token_account: Pubkey,
+    pub user_reward_a_token_account: Pubkey,
+    pub pool_reward_a_token_account: Pubkey,
+    pub user_reward_b_token_account: Pubkey,
+    pub pool_reward_b_token_account: Pubkey,
+}
+
+/// `Raydium` AMM accounts, used to swap harvested rewards.
+#[derive(Debug, Clone, Default)]
+pub struct RaydiumSwapAccounts {
+    pub amm_id: Pubkey,
+    pub amm_authority: Pubkey,
+    pub amm_open_orders: Pubkey,
+    pub amm_target_orders: Pubkey,
+    pub pool_coin_token_account: Pubkey,
+    pub pool_pc_token_account: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_bids: Pubkey,
+    pub serum_asks: Pubkey,
+    pub serum_event_queue: Pubkey,
+    pub serum_coin_vault_account: Pubkey,
+    pub serum_pc_vault_account: Pubkey,
+    pub serum_vault_signer: Pubkey,
+}
+
+/// `Raydium` staking pool accounts, used to collect harvested rewards.
+#[derive(Debug, Clone, Default)]
+pub struct RaydiumStakeAccounts {
+    pub program_id: Pubkey,
+    pub pool_id: Pubkey,
+    pub pool_authority: Pubkey,
+    pub user_info_account: Pubkey,
+    pub pool_lp_token_account: Pubkey,
+    pub pool_reward_token_account: Pubkey,
+}
+
+/// Resolved `Tulip` leveraged farm accounts of single `Chamber`.
+#[derive(Debug, Clone)]
+pub struct TulipAccounts {
+    pub user_farm: Pubkey,
+    pub user_farm_obligation: Pubkey,
+    pub user_farm_obligation_1: Pubkey,
+    pub obligation_vault_address: Pubkey,
+    pub obligation_vault_address_1: Pubkey,
+    pub obligation_lp_token_account: Pubkey,
+    pub obligation_lp_token_account_1: Pubkey,
+    pub position_info_account: Pubkey,
+    pub position_info_account_1: Pubkey,
+
+    pub leveraged_farm: Pubkey,
+    pub global: Pubkey,
+    pub lending_market: Pubkey,
+    pub lending_market_authority: Pubkey,
+    pub lending_program: Pubkey,
+    pub vault_program: Pubkey,
+
+    pub lev_farm_coin_token_account: Pubkey,
+    pub lev_farm_pc_token_account: Pubkey,
+    pub coin_reserve_liquidity_fee_receiver: Pubkey,
+    pub pc_reserve_liquidity_fee_receiver: Pubkey,
+
+    pub lp_price_account: Pubkey,
+    pub coin_price_account: Pubkey,
+    pub pc_price_account: Pubkey,
+
+    pub liquidity_program_id: Pubkey,
+    pub amm_id: Pubkey,
+    pub amm_authority: Pubkey,
+    pub amm_open_orders: Pubkey,
+    pub amm_quantities_or_target_orders: Pubkey,
+    pub lp_mint_address: Pubkey,
+    pub pool_coin_token_account: Pubkey,
+    pub pool_pc_token_account: Pubkey,
+    pub pool_withdraw_queue: Pubkey,
+    pub pool_temp_lp_token_account: Pubkey,
+
+    pub dex_program: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_bids: Pubkey,
+    pub serum_asks: Pubkey,
+    pub serum_event_queue: Pubkey,
+    pub serum_coin_vault_account: Pubkey,
+    pub serum_pc_vault_account: Pubkey,
+    pub serum_vault_signer: Pubkey,
+
+    pub market: TulipMarketAccounts,
+}
+
+impl TulipAccounts {
+    /// Resolve accounts for `Chamber`, which `authority` owns first user farm of `farm_type`.
+    pub fn new(
+        farm_config: &LevFarmConfig,
+        farm_type: Farms,
+        market: TulipMarketAccounts,
+        authority: &Pubkey,
+    ) -> Self {
+        let program_id = tulipv2_sdk_levfarm::ID;
+
+        let (user_farm, _) =
+            derivations::derive_user_farm_address(*authority, program_id, 0, farm_type);
+        let (user_farm_obligation, _) =
+            derivations::derive_user_farm_obligation_address(*authority, user_farm, program_id, 0);
+        let (user_farm_obligation_1, _) =
+            derivations::derive_user_farm_obligation_address(*authority, user_farm, program_id, 1);
+        let (obligation_vault_address, _) =
+            derivations::derive_user_farm_obligation_vault_address(user_farm, program_id, 0);
+        let (obligation_vault_address_1, _) =
+            derivations::derive_user_farm_obligation_vault_address(user_farm, program_id, 1);
+        let (position_info_account, _) =
+            derivations::derive_user_position_info_address(user_farm, program_id, 0);
+        let (position_info_account_1, _) =
+            derivations::derive_user_position_info_address(user_farm, program_id, 1);
+
+        let obligation_lp_token_account = associated_token::get_associated_token_address(
+            &obligation_vault_address,
+            &farm_config.raydium_lp_mint_address,
+        );
+        let obligation_lp_token_account_1 = associated_token::get_associated_token_address(
+            &obligation_vault_address_1,
+            &farm_config.raydium_lp_mint_address,
+        );
+
+        let (lending_market_authority, _) = Pubkey::find_program_address(
+            &[farm_config.lending_market.as_ref()],
+            &farm_config.lending_program,
+        );
+
+        TulipAccounts {
+            user_farm,
+            user_farm_obligation,
+            user_farm_obligation_1,
+            obligation_vault_address,
+            obligation_vault_address_1,
+            obligation_lp_token_account,
+            obligation_lp_token_account_1,
+            position_info_account,
+            position_info_account_1,
+            leveraged_farm: farm_config.account,
+            global: farm_config.global,
+            lending_market: farm_config.lending_market,
+            lending_market_authority,
+            lending_program: farm_config.lending_program,
+            vault_program: farm_config.solfarm_vault_program,
+            lev_farm_coin_token_account: farm_config.base_token_account,
+            lev_farm_pc_token_account: farm_config.quote_token_account,
+            coin_reserve_liquidity_fee_receiver: farm_config.coin_reserve_liquidity_fee_receiver,
+            pc_reserve_liquidity_fee_receiver: farm_config.pc_reserve_liquidity_fee_receiver,
+            lp_price_account: farm_config.lp_price_account,
+            coin_price_account: farm_config.coin_price_account,
+            pc_price_account: farm_config.pc_price_account,
+            liquidity_program_id: RAYDIUM_LIQUIDITY_PROGRAM_ID,
+            amm_id: farm_config.raydium_amm_id,
+            amm_authority: farm_config.raydium_amm_authority,
+            amm_open_orders: farm_config.raydium_amm_open_orders,
+            amm_quantities_or_target_orders: farm_config.raydium_amm_quantities_or_target,
+            lp_mint_address: farm_config.raydium_lp_mint_address,
+            pool_coin_token_account: farm_config.raydium_coin_account,
+            pool_pc_token_account: farm_config.raydium_pc_account,
+            pool_withdraw_queue: farm_config.raydium_pool_withdraw_queue,
+            pool_temp_lp_token_account: farm_config.raydium_pool_temp_token_account,
+            dex_program: SERUM_DEX_PROGRAM_ID,
+            serum_market: farm_config.serum_market,
+            serum_bids: farm_config.serum_market_bids,
+            serum_asks: farm_config.serum_market_asks,
+            serum_event_queue: farm_config.serum_event_queue,
+            serum_coin_vault_account: farm_config.serum_coin_vault_account,
+            serum_pc_vault_account: farm_config.serum_pc_vault_account,
+            serum_vault_signer: farm_config.serum_vault_signer,
+            market,
+        }
+    }
+
+    /// Accounts, which point to `obligation_index` obligation of user farm,
+    /// used by deposit, withdraw and rebalance instructions.
+    pub fn get_obligation(&self, obligation_index: u8) -> Self {
+        let mut accounts = self.clone();
+
+        if obligation_index == 1 {
+            accounts.user_farm_obligation = self.user_farm_obligation_1;
+            accounts.obligation_vault_address = self.obligation_vault_address_1;
+            accounts.obligation_lp_token_account = self.obligation_lp_token_account_1;
+            accounts.position_info_account = self.position_info_account_1;
+            accounts.market.user_balance_account = self.market.user_balance_account_1;
+            accounts.market.user_balance_metadata = self.market.user_balance_metadata_1;
+            accounts.market.user_info_account = self.market.user_info_account_1;
+        }
+
+        accounts
+    }
+
+    /// Remaining accounts for `initialize_chamber` instruction.
+    pub fn initialize_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new(self.user_farm_obligation_1, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new(self.obligation_vault_address_1, false),
+            AccountMeta::new_readonly(self.global, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+        ]
+    }
+
+    /// Remaining accounts for `begin_deposit_chamber` instruction.
+    pub fn begin_deposit_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.market.coin_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.market.pc_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.coin_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new(self.pc_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new_readonly(self.market.borrow_authorizer, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.position_info_account, false),
+        ]
+    }
+
+    /// Remaining accounts for `process_deposit_chamber` instruction.
+    pub fn process_deposit_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+        ]
+    }
+
+    /// Remaining accounts for `end_deposit_chamber` instruction.
+    pub fn end_deposit_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.lp_token_account, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.vault_info_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+        ]
+    }
+
+    /// Remaining accounts for `deposit_chamber` instruction.
+    pub fn deposit_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.market.coin_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.market.pc_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.coin_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new(self.pc_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new_readonly(self.market.borrow_authorizer, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.lp_token_account, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.vault_info_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+        ]
+    }
+
+    /// Remaining accounts for `withdraw_chamber` instruction.
+    pub fn withdraw_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new(self.market.user_info_account, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new(self.pool_withdraw_queue, false),
+            AccountMeta::new(self.pool_temp_lp_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+        ]
+    }
+
+    /// Remaining accounts for `deleverage_chamber` instruction, which partially
+    /// unwinds active obligation.
+    pub fn deleverage_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new(self.market.user_info_account, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new(self.pool_withdraw_queue, false),
+            AccountMeta::new(self.pool_temp_lp_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+        ]
+    }
+
+    /// Remaining accounts for `releverage_chamber` instruction, same as for `deposit_chamber`.
+    pub fn releverage_chamber_accounts(&self) -> Vec<AccountMeta> {
+        self.deposit_chamber_accounts()
+    }
+
+    /// Remaining accounts for `rebalance_chamber` instruction, which moves position
+    /// into `target` obligation.
+    pub fn rebalance_chamber_accounts(&self, target: &TulipAccounts) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new(self.market.user_info_account, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new(self.pool_withdraw_queue, false),
+            AccountMeta::new(self.pool_temp_lp_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new_readonly(self.coin_price_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.pc_price_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new(self.market.coin_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.market.pc_reserve_liquidity_token_account, false),
+            AccountMeta::new(self.coin_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new(self.pc_reserve_liquidity_fee_receiver, false),
+            AccountMeta::new_readonly(self.market.borrow_authorizer, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.market.lp_token_account, false),
+            AccountMeta::new(self.market.vault_info_account, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+            AccountMeta::new(target.user_farm_obligation, false),
+            AccountMeta::new(target.obligation_vault_address, false),
+            AccountMeta::new(target.obligation_lp_token_account, false),
+            AccountMeta::new(target.position_info_account, false),
+            AccountMeta::new(target.market.user_balance_account, false),
+            AccountMeta::new(target.market.user_balance_metadata, false),
+        ]
+    }
+
+    /// Remaining accounts for `harvest_chamber` instruction, which swaps rewards via `route`.
+    pub fn harvest_chamber_accounts(
+        &self,
+        route: &RaydiumSwapAccounts,
+        reward_farm: &RaydiumStakeAccounts,
+    ) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(route.amm_id, false),
+            AccountMeta::new_readonly(route.amm_authority, false),
+            AccountMeta::new(route.amm_open_orders, false),
+            AccountMeta::new(route.amm_target_orders, false),
+            AccountMeta::new(route.pool_coin_token_account, false),
+            AccountMeta::new(route.pool_pc_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(route.serum_market, false),
+            AccountMeta::new(route.serum_bids, false),
+            AccountMeta::new(route.serum_asks, false),
+            AccountMeta::new(route.serum_event_queue, false),
+            AccountMeta::new(route.serum_coin_vault_account, false),
+            AccountMeta::new(route.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(route.serum_vault_signer, false),
+            AccountMeta::new_readonly(reward_farm.program_id, false),
+            AccountMeta::new(reward_farm.pool_id, false),
+            AccountMeta::new_readonly(reward_farm.pool_authority, false),
+            AccountMeta::new(reward_farm.user_info_account, false),
+            AccountMeta::new(reward_farm.pool_lp_token_account, false),
+            AccountMeta::new(reward_farm.pool_reward_token_account, false),
+            AccountMeta::new(self.user_farm, false),
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new(self.user_farm_obligation, false),
+            AccountMeta::new(self.lev_farm_coin_token_account, false),
+            AccountMeta::new(self.lev_farm_pc_token_account, false),
+            AccountMeta::new(self.market.coin_reserve_account, false),
+            AccountMeta::new(self.market.pc_reserve_account, false),
+            AccountMeta::new(self.lending_market, false),
+            AccountMeta::new_readonly(self.lending_market_authority, false),
+            AccountMeta::new_readonly(self.lending_program, false),
+            AccountMeta::new(self.position_info_account, false),
+            AccountMeta::new_readonly(self.market.vault_signer, false),
+            AccountMeta::new_readonly(self.liquidity_program_id, false),
+            AccountMeta::new(self.amm_id, false),
+            AccountMeta::new_readonly(self.amm_authority, false),
+            AccountMeta::new(self.amm_open_orders, false),
+            AccountMeta::new(self.amm_quantities_or_target_orders, false),
+            AccountMeta::new(self.pool_coin_token_account, false),
+            AccountMeta::new(self.pool_pc_token_account, false),
+            AccountMeta::new_readonly(self.dex_program, false),
+            AccountMeta::new(self.serum_market, false),
+            AccountMeta::new(self.serum_bids, false),
+            AccountMeta::new(self.serum_asks, false),
+            AccountMeta::new(self.serum_event_queue, false),
+            AccountMeta::new(self.serum_coin_vault_account, false),
+            AccountMeta::new(self.serum_pc_vault_account, false),
+            AccountMeta::new_readonly(self.serum_vault_signer, false),
+            AccountMeta::new(self.lp_mint_address, false),
+            AccountMeta::new(self.obligation_lp_token_account, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new(self.obligation_vault_address, false),
+            AccountMeta::new_readonly(self.vault_program, false),
+            AccountMeta::new_readonly(self.market.vault_pda_account, false),
+            AccountMeta::new(self.market.vault, false),
+            AccountMeta::new(self.market.lp_token_account, false),
+            AccountMeta::new(self.market.user_balance_account, false),
+            AccountMeta::new_readonly(self.market.stake_program_id, false),
+            AccountMeta::new(self.market.pool_id, false),
+            AccountMeta::new_readonly(self.market.pool_authority, false),
+            AccountMeta::new(self.market.vault_info_account, false),
+            AccountMeta::new(self.market.pool_lp_token_account, false),
+            AccountMeta::new(self.market.user_reward_a_token_account, false),
+            AccountMeta::new(self.market.pool_reward_a_token_account, false),
+            AccountMeta::new(self.market.user_reward_b_token_account, false),
+            AccountMeta::new(self.market.pool_reward_b_token_account, false),
+            AccountMeta::new(self.market.user_balance_metadata, false),
+        ]
+    }
+
+    /// Remaining accounts for `close_chamber` instruction, which checks both obligations
+    /// and closes first obligation position info account.
+    pub fn close_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.user_farm, false),
+            AccountMeta::new_readonly(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.user_farm_obligation_1, false),
+            AccountMeta::new(self.position_info_account, false),
+        ]
+    }
+
+    /// Remaining accounts for `get_chamber_nav`, `get_share_price` and `get_user_position`
+    /// view instructions.
+    pub fn view_chamber_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.leveraged_farm, false),
+            AccountMeta::new_readonly(self.user_farm, false),
+            AccountMeta::new_readonly(self.user_farm_obligation, false),
+            AccountMeta::new_readonly(self.user_farm_obligation_1, false),
+            AccountMeta::new_readonly(self.lp_price_account, false),
+            AccountMeta::new_readonly(self.lp_mint_address, false),
+        ]
+    }
+
+    /// Static accounts of `Chamber` market, which fit into Address Lookup Table.
+    pub fn lookup_table_addresses(&self) -> Vec<Pubkey> {
+        let addresses = [
+            self.user_farm,
+            self.user_farm_obligation,
+            self.user_farm_obligation_1,
+            self.obligation_vault_address,
+            self.obligation_vault_address_1,
+            self.obligation_lp_token_account,
+            self.obligation_lp_token_account_1,
+            self.position_info_account,
+            self.position_info_account_1,
+            self.leveraged_farm,
+            self.global,
+            self.lending_market,
+            self.lending_market_authority,
+            self.lending_program,
+            self.vault_program,
+            self.lev_farm_coin_token_account,
+            self.lev_farm_pc_token_account,
+            self.coin_reserve_liquidity_fee_receiver,
+            self.pc_reserve_liquidity_fee_receiver,
+            self.lp_price_account,
+            self.coin_price_account,
+            self.pc_price_account,
+            self.liquidity_program_id,
+            self.amm_id,
+            self.amm_authority,
+            self.amm_open_orders,
+            self.amm_quantities_or_target_orders,
+            self.lp_mint_address,
+            self.pool_coin_token_account,
+            self.pool_pc_token_account,
+            self.pool_withdraw_queue,
+            self.pool_temp_lp_token_account,
+            self.dex_program,
+            self.serum_market,
+            self.serum_bids,
+            self.serum_asks,
+            self.serum_event_queue,
+            self.serum_coin_vault_account,
+            self.serum_pc_vault_account,
+            self.serum_vault_signer,
+            self.market.coin_reserve_account,
+            self.market.pc_reserve_account,
+            self.market.coin_reserve_liquidity_token_account,
+            self.market.pc_reserve_liquidity_token_account,
+            self.market.borrow_authorizer,
+            self.market.vault,
+            self.market.vault_pda_account,
+            self.market.vault_info_account,
+            self.market.vault_signer,
+            self.market.lp_token_account,
+            self.market.user_balance_account,
+            self.market.user_balance_metadata,
+            self.market.user_info_account,
+            self.market.user_balance_account_1,
+            self.market.user_balance_metadata_1,
+            self.market.user_info_account_1,
+            self.market.stake_program_id,
+            self.market.pool_id,
+            self.market.pool_authority,
+            self.market.pool_lp_token_account,
+            self.market.user_reward_a_token_account,
+            self.market.pool_reward_a_token_account,
+            self.market.user_reward_b_token_account,
+            self.market.pool_reward_b_token_account,
+        ];
+
+        let mut result: Vec<Pubkey> = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if address != Pubkey::default() && !result.contains(&address) {
+                result.push(address);
+            }
+        }
+
+        result
+    }
+}
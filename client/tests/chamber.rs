@@ -0,0 +1,168 @@
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use cetra_chamber::utils;
+use cetra_chamber_client::{
+    farm,
+    tulip::{RaydiumStakeAccounts, RaydiumSwapAccounts, TulipMarketAccounts},
+    ChamberClient,
+};
+
+fn build_client() -> ChamberClient {
+    let (farm_config, farm_type) = farm::get_farm("ray-sol-usdc").unwrap();
+
+    ChamberClient::new(
+        &farm_config,
+        farm_type,
+        TulipMarketAccounts::default(),
+        0,
+        &Pubkey::new_unique(),
+    )
+}
+
+fn account_keys(ix: &Instruction) -> Vec<Pubkey> {
+    ix.accounts.iter().map(|meta| meta.pubkey).collect()
+}
+
+#[test]
+fn success_create_user_account_with_allowlist_entry() {
+    let client = build_client();
+    let user = Pubkey::new_unique();
+
+    let ix = client.create_user_account(&user);
+    let permit = ix.accounts.last().unwrap();
+
+    assert_eq!(
+        permit.pubkey,
+        utils::derive_allowlist_entry_address(&client.chamber, &user).0
+    );
+    assert!(!permit.is_signer);
+    assert!(!permit.is_writable);
+}
+
+#[test]
+fn success_create_user_account_with_gatekeeper() {
+    let gatekeeper = Pubkey::new_unique();
+    let client = build_client().with_gatekeeper(&gatekeeper);
+    let user = Pubkey::new_unique();
+
+    let ix = client.create_user_account(&user);
+    let permit = ix.accounts.last().unwrap();
+
+    assert_eq!(permit.pubkey, gatekeeper);
+    assert!(permit.is_signer);
+}
+
+#[test]
+fn success_close_chamber() {
+    let client = build_client();
+    let owner = Pubkey::new_unique();
+
+    let ix = client.close_chamber(&owner);
+    let accounts = account_keys(&ix);
+
+    // Leftover tokens are swept into owner atas
+    assert!(accounts.contains(&client.user_token_address(&owner, &client.base_mint)));
+    assert!(accounts.contains(&client.user_token_address(&owner, &client.quote_mint)));
+
+    // Both obligations are checked, first obligation position info is closed
+    assert_eq!(
+        &accounts[accounts.len() - 4..],
+        &[
+            client.tulip.user_farm,
+            client.tulip.user_farm_obligation,
+            client.tulip.user_farm_obligation_1,
+            client.tulip.position_info_account,
+        ]
+    );
+    assert!(ix.accounts.last().unwrap().is_writable);
+}
+
+#[test]
+fn success_allowlist_entry() {
+    let client = build_client();
+    let owner = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let allowlist_entry = utils::derive_allowlist_entry_address(&client.chamber, &user).0;
+
+    let add_ix = client.add_allowlist_entry(&owner, &user);
+    assert!(account_keys(&add_ix).contains(&allowlist_entry));
+
+    let remove_ix = client.remove_allowlist_entry(&owner, &user);
+    assert!(account_keys(&remove_ix).contains(&allowlist_entry));
+    assert_ne!(add_ix.data, remove_ix.data);
+}
+
+#[test]
+fn success_view_chamber_accounts() {
+    let client = build_client().with_active_obligation(1);
+    let user = Pubkey::new_unique();
+
+    let view_accounts = [
+        client.tulip.leveraged_farm,
+        client.tulip.user_farm,
+        client.tulip.user_farm_obligation,
+        client.tulip.user_farm_obligation_1,
+        client.tulip.lp_price_account,
+        client.tulip.lp_mint_address,
+    ];
+
+    // Views value both obligations regardless of active one
+    for ix in [
+        client.get_chamber_nav(),
+        client.get_share_price(),
+        client.get_user_position(&user),
+    ] {
+        let accounts = account_keys(&ix);
+        assert_eq!(&accounts[accounts.len() - 6..], &view_accounts);
+        assert!(ix.accounts.iter().all(|meta| !meta.is_writable));
+    }
+
+    let accounts = account_keys(&client.get_user_position(&user));
+    assert!(accounts.contains(&client.user_account_address(&user)));
+    assert!(accounts.contains(&client.user_shares_address(&user)));
+}
+
+#[test]
+fn success_deleverage_chamber_obligation() {
+    let client = build_client();
+    let cranker = Pubkey::new_unique();
+
+    let accounts = account_keys(&client.deleverage_chamber(&cranker, 1, 0, 0, 0));
+
+    assert!(accounts.contains(&client.tulip.user_farm_obligation_1));
+    assert!(accounts.contains(&client.tulip.position_info_account_1));
+    assert!(!accounts.contains(&client.tulip.user_farm_obligation));
+}
+
+#[test]
+fn success_harvest_chamber_collects_and_stakes() {
+    let client = build_client();
+    let cranker = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let protocol_fee_token = Pubkey::new_unique();
+    let reward_farm = RaydiumStakeAccounts {
+        pool_id: Pubkey::new_unique(),
+        ..Default::default()
+    };
+
+    let ix = client.harvest_chamber(
+        &cranker,
+        &reward_mint,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &protocol_fee_token,
+        &reward_farm,
+        &RaydiumSwapAccounts::default(),
+        0,
+    );
+    let accounts = account_keys(&ix);
+
+    // Reward price is pinned by oracle entry of reward mint
+    assert!(accounts.contains(&utils::derive_oracle_entry_address(&reward_mint).0));
+
+    // Protocol share of performance fee is paid into protocol fee token
+    assert!(accounts.contains(&protocol_fee_token));
+
+    // Rewards are collected from reward farm and lp is staked into obligation vault
+    assert!(accounts.contains(&reward_farm.pool_id));
+    assert!(accounts.contains(&client.tulip.obligation_vault_address));
+}
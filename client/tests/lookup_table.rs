@@ -0,0 +1,91 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::Hash, instruction::Instruction, message::VersionedMessage},
+};
+use cetra_chamber_client::lookup_table::{self, LookupTable};
+
+#[test]
+fn success_deserialize() {
+    let address = Pubkey::new_unique();
+    let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let mut data = vec![0; lookup_table::LOOKUP_TABLE_META_SIZE];
+    for address in &addresses {
+        data.extend_from_slice(address.as_ref());
+    }
+
+    let lookup_table = LookupTable::deserialize(&address, &data).unwrap();
+    assert_eq!(lookup_table.address, address);
+    assert_eq!(lookup_table.addresses, addresses);
+
+    let missing = Pubkey::new_unique();
+    assert_eq!(
+        lookup_table.get_missing_addresses(&[addresses[0], missing]),
+        vec![missing]
+    );
+}
+
+#[test]
+fn failure_deserialize() {
+    let address = Pubkey::new_unique();
+
+    assert!(LookupTable::deserialize(&address, &[0; 10]).is_err());
+    assert!(
+        LookupTable::deserialize(&address, &[0; lookup_table::LOOKUP_TABLE_META_SIZE + 1]).is_err()
+    );
+}
+
+#[test]
+fn success_compile_v0_message() {
+    let payer = Pubkey::new_unique();
+    let program_id = Pubkey::new_unique();
+    let static_account = Pubkey::new_unique();
+    let writable_account = Pubkey::new_unique();
+    let readonly_account = Pubkey::new_unique();
+
+    let lookup_table = LookupTable {
+        address: Pubkey::new_unique(),
+        addresses: vec![readonly_account, writable_account, program_id],
+    };
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(static_account, false),
+            AccountMeta::new(writable_account, false),
+            AccountMeta::new_readonly(readonly_account, false),
+        ],
+        data: vec![1, 2, 3],
+    };
+
+    let message =
+        lookup_table::compile_v0_message(&payer, &[ix], &lookup_table, Hash::default()).unwrap();
+
+    let message = match message {
+        VersionedMessage::V0(message) => message,
+        _ => panic!("Expected v0 message"),
+    };
+
+    // Invoked program can not be loaded from lookup table
+    assert_eq!(
+        message.account_keys,
+        vec![payer, static_account, program_id]
+    );
+    assert_eq!(message.header.num_required_signatures, 1);
+    assert_eq!(message.header.num_readonly_signed_accounts, 0);
+    assert_eq!(message.header.num_readonly_unsigned_accounts, 1);
+
+    assert_eq!(message.address_table_lookups.len(), 1);
+    assert_eq!(
+        message.address_table_lookups[0].account_key,
+        lookup_table.address
+    );
+    assert_eq!(message.address_table_lookups[0].writable_indexes, vec![1]);
+    assert_eq!(message.address_table_lookups[0].readonly_indexes, vec![0]);
+
+    let ix = &message.instructions[0];
+    assert_eq!(ix.program_id_index, 2);
+    assert_eq!(ix.accounts, vec![0, 1, 3, 4]);
+    assert_eq!(ix.data, vec![1, 2, 3]);
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use cetra_chamber_client::{farm, tulip::TulipMarketAccounts, ChamberClient};
+
+fn build_client() -> ChamberClient {
+    let (farm_config, farm_type) = farm::get_farm("ray-sol-usdc").unwrap();
+    let market = TulipMarketAccounts {
+        user_balance_account: Pubkey::new_unique(),
+        user_balance_metadata: Pubkey::new_unique(),
+        user_info_account: Pubkey::new_unique(),
+        user_balance_account_1: Pubkey::new_unique(),
+        user_balance_metadata_1: Pubkey::new_unique(),
+        user_info_account_1: Pubkey::new_unique(),
+        ..TulipMarketAccounts::default()
+    };
+
+    ChamberClient::new(&farm_config, farm_type, market, 0, &Pubkey::new_unique())
+}
+
+#[test]
+fn success_get_obligation() {
+    let client = build_client();
+
+    let first = client.tulip.get_obligation(0);
+    assert_eq!(
+        first.user_farm_obligation,
+        client.tulip.user_farm_obligation
+    );
+    assert_eq!(
+        first.market.user_balance_account,
+        client.tulip.market.user_balance_account
+    );
+
+    let second = client.tulip.get_obligation(1);
+    assert_eq!(
+        second.user_farm_obligation,
+        client.tulip.user_farm_obligation_1
+    );
+    assert_eq!(
+        second.obligation_vault_address,
+        client.tulip.obligation_vault_address_1
+    );
+    assert_eq!(
+        second.obligation_lp_token_account,
+        client.tulip.obligation_lp_token_account_1
+    );
+    assert_eq!(
+        second.position_info_account,
+        client.tulip.position_info_account_1
+    );
+    assert_eq!(
+        second.market.user_balance_account,
+        client.tulip.market.user_balance_account_1
+    );
+    assert_eq!(
+        second.market.user_balance_metadata,
+        client.tulip.market.user_balance_metadata_1
+    );
+    assert_ne!(
+        second.position_info_account,
+        client.tulip.position_info_account
+    );
+}
+
+#[test]
+fn success_rebalance_chamber_into_second_obligation() {
+    let client = build_client();
+    let strategist = Pubkey::new_unique();
+
+    let ix = client.rebalance_chamber(&strategist, 1, 50);
+    let remaining_accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+
+    // Source obligation unwinds first obligation
+    assert!(remaining_accounts.contains(&client.tulip.user_farm_obligation));
+
+    // Target obligation accounts are appended last
+    let target = &remaining_accounts[remaining_accounts.len() - 6..];
+    assert_eq!(
+        target,
+        &[
+            client.tulip.user_farm_obligation_1,
+            client.tulip.obligation_vault_address_1,
+            client.tulip.obligation_lp_token_account_1,
+            client.tulip.position_info_account_1,
+            client.tulip.market.user_balance_account_1,
+            client.tulip.market.user_balance_metadata_1,
+        ]
+    );
+}
+
+#[test]
+fn success_releverage_chamber_into_active_obligation() {
+    let client = build_client().with_active_obligation(1);
+    let strategist = Pubkey::new_unique();
+
+    let ix = client.releverage_chamber(&strategist);
+    let remaining_accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+
+    // Position is topped up in place, without touching other obligation
+    assert!(remaining_accounts.contains(&client.tulip.user_farm_obligation_1));
+    assert!(remaining_accounts.contains(&client.tulip.obligation_vault_address_1));
+    assert!(!remaining_accounts.contains(&client.tulip.user_farm_obligation));
+}
+
+#[test]
+fn success_with_active_obligation() {
+    let client = build_client().with_active_obligation(1);
+    let user = Pubkey::new_unique();
+
+    let ix = client.begin_deposit_chamber(&user, 1, 1);
+    let remaining_accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+
+    assert!(remaining_accounts.contains(&client.tulip.user_farm_obligation_1));
+    assert!(!remaining_accounts.contains(&client.tulip.user_farm_obligation));
+}
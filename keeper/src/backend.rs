@@ -0,0 +1,115 @@
+//! Chain access for keeper, so it may run against rpc node or `solana-program-test`.
+
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use cetra_chamber::state;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_sdk::{
+    account::Account, instruction::Instruction, pubkey::Pubkey, signature::Keypair,
+    signature::Signature, signer::Signer, transaction::Transaction,
+};
+use std::sync::Arc;
+use tokio::task;
+
+#[async_trait]
+pub trait Backend: Send + Sync {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>>;
+
+    /// Get all `UserAccount`s of `chamber`.
+    async fn get_user_accounts(
+        &self,
+        chamber: &Pubkey,
+    ) -> Result<Vec<(Pubkey, state::UserAccount)>>;
+
+    async fn send_instructions(&self, ixs: &[Instruction], signer: &Keypair) -> Result<Signature>;
+}
+
+/// Backend, which uses json rpc node.
+///
+/// Blocking `RpcClient` calls are run on tokio blocking thread pool.
+pub struct RpcBackend {
+    rpc: Arc<RpcClient>,
+}
+
+impl RpcBackend {
+    pub fn new(rpc: RpcClient) -> Self {
+        RpcBackend { rpc: Arc::new(rpc) }
+    }
+
+    async fn call<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&RpcClient) -> Result<T> + Send + 'static,
+    {
+        let rpc = self.rpc.clone();
+
+        task::spawn_blocking(move || f(&rpc)).await?
+    }
+}
+
+#[async_trait]
+impl Backend for RpcBackend {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        let address = *address;
+
+        self.call(move |rpc| {
+            Ok(rpc
+                .get_account_with_commitment(&address, rpc.commitment())?
+                .value)
+        })
+        .await
+    }
+
+    async fn get_user_accounts(
+        &self,
+        chamber: &Pubkey,
+    ) -> Result<Vec<(Pubkey, state::UserAccount)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(state::UserAccount::LEN as u64),
+                RpcFilterType::Memcmp(Memcmp {
+                    offset: 8,
+                    bytes: MemcmpEncodedBytes::Base58(chamber.to_string()),
+                    encoding: None,
+                }),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = self
+            .call(
+                move |rpc| Ok(rpc.get_program_accounts_with_config(&cetra_chamber::id(), config)?),
+            )
+            .await?;
+
+        accounts
+            .into_iter()
+            .map(|(address, account)| {
+                let user_account =
+                    state::UserAccount::try_deserialize(&mut account.data.as_slice())
+                        .map_err(|err| anyhow!("Unable to decode {}: {}", address, err))?;
+
+                Ok((address, user_account))
+            })
+            .collect()
+    }
+
+    async fn send_instructions(&self, ixs: &[Instruction], signer: &Keypair) -> Result<Signature> {
+        let blockhash = self.call(|rpc| Ok(rpc.get_latest_blockhash()?)).await?;
+        let tx =
+            Transaction::new_signed_with_payer(ixs, Some(&signer.pubkey()), &[signer], blockhash);
+
+        self.call(move |rpc| Ok(rpc.send_and_confirm_transaction(&tx)?))
+            .await
+    }
+}
@@ -0,0 +1,106 @@
+//! Chamber debt drift, calculated with `cetra_chamber::math` used by `rebalance_chamber`.
+
+use anchor_lang::prelude::*;
+use cetra_chamber::{math, state};
+use tulipv2_sdk_common::math::{
+    common::{TryAdd, TrySub},
+    decimal::Decimal,
+};
+
+/// Chamber balances and prices, observed by keeper.
+#[derive(Debug, Clone)]
+pub struct ChamberSnapshot {
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub lp_amount: u64,
+    pub base_debt: u64,
+    pub quote_debt: u64,
+    pub base_price: Decimal,
+    pub quote_price: Decimal,
+    pub lp_price: Decimal,
+    pub lp_decimals: u64,
+}
+
+/// Difference between current and target chamber debt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChamberDrift {
+    pub base_debt: u64,
+    pub quote_debt: u64,
+    pub base_target_debt: u64,
+    pub quote_target_debt: u64,
+
+    /// Max debt drift across legs, in basis points of target debt.
+    pub drift_bps: u64,
+
+    /// Debt value to LP position value ratio, in basis points.
+    pub ltv_bps: u64,
+}
+
+impl ChamberDrift {
+    /// Indicates, that no debt leg is above target, so position can be topped up in place.
+    pub fn is_under_levered(&self) -> bool {
+        self.base_debt <= self.base_target_debt
+            && self.quote_debt <= self.quote_target_debt
+            && (self.base_debt, self.quote_debt) != (self.base_target_debt, self.quote_target_debt)
+    }
+}
+
+/// Calculate `Chamber` debt drift, i.e. how far rebalance would move each debt leg.
+pub fn compute_drift(chamber: &state::Chamber, snapshot: &ChamberSnapshot) -> Result<ChamberDrift> {
+    let base_value = math::get_token_value(
+        &snapshot.base_price,
+        snapshot.base_amount,
+        chamber.vault.base_decimals,
+    )?;
+    let quote_value = math::get_token_value(
+        &snapshot.quote_price,
+        snapshot.quote_amount,
+        chamber.vault.quote_decimals,
+    )?;
+    let lp_value =
+        math::get_token_value(&snapshot.lp_price, snapshot.lp_amount, snapshot.lp_decimals)?;
+
+    let base_debt_value = math::get_token_value(
+        &snapshot.base_price,
+        snapshot.base_debt,
+        chamber.vault.base_decimals,
+    )?;
+    let quote_debt_value = math::get_token_value(
+        &snapshot.quote_price,
+        snapshot.quote_debt,
+        chamber.vault.quote_decimals,
+    )?;
+
+    let assets_value = base_value.try_add(quote_value)?.try_add(lp_value)?;
+    let debt_value = base_debt_value.try_add(quote_debt_value)?;
+
+    let equity = if assets_value > debt_value {
+        assets_value.try_sub(debt_value)?
+    } else {
+        Decimal::zero()
+    };
+
+    let (base_target_debt, quote_target_debt) = math::get_borrow_amounts(
+        &equity,
+        &snapshot.base_price,
+        &snapshot.quote_price,
+        chamber.vault.base_decimals,
+        chamber.vault.quote_decimals,
+        chamber.strategy.leverage,
+        chamber.strategy.is_base_volatile,
+    )?;
+
+    let drift_bps = math::get_debt_drift_bps(snapshot.base_debt, base_target_debt)?.max(
+        math::get_debt_drift_bps(snapshot.quote_debt, quote_target_debt)?,
+    );
+    let ltv_bps = math::get_ltv_bps(&debt_value, &lp_value)?;
+
+    Ok(ChamberDrift {
+        base_debt: snapshot.base_debt,
+        quote_debt: snapshot.quote_debt,
+        base_target_debt,
+        quote_target_debt,
+        drift_bps,
+        ltv_bps,
+    })
+}
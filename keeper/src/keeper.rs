@@ -0,0 +1,409 @@
+use crate::{
+    backend::Backend,
+    drift::{self, ChamberDrift, ChamberSnapshot},
+    metrics::Metrics,
+};
+use anchor_lang::AccountDeserialize;
+use anchor_spl::token;
+use anyhow::{anyhow, Result};
+use cetra_chamber::{position, state};
+use cetra_chamber_client::ChamberClient;
+use solana_sdk::{
+    account::Account, account_info::AccountInfo, instruction::Instruction, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::Mutex;
+
+#[derive(Debug, Clone)]
+pub struct KeeperConfig {
+    /// Debt drift, which triggers rebalance, unless `Chamber` rebalance drift is higher.
+    pub drift_threshold_bps: u64,
+
+    /// Number of consecutive polls, after which pending `UserAccount` is considered stuck.
+    pub stuck_polls: u32,
+
+    /// Number of consecutive polls, after which stuck deposit is cancelled and refunded
+    /// instead of finished, so keeper should be `Chamber` guardian.
+    pub cancel_polls: u32,
+}
+
+pub struct Keeper<B: Backend> {
+    backend: B,
+    signer: Keypair,
+    chambers: Vec<ChamberClient>,
+    config: KeeperConfig,
+    metrics: Arc<Metrics>,
+
+    /// Consecutive polls, during which `UserAccount` had pending deposit.
+    pending_polls: Mutex<HashMap<Pubkey, u32>>,
+}
+
+impl<B: Backend> Keeper<B> {
+    pub fn new(
+        backend: B,
+        signer: Keypair,
+        chambers: Vec<ChamberClient>,
+        config: KeeperConfig,
+        metrics: Arc<Metrics>,
+    ) -> Self {
+        Keeper {
+            backend,
+            signer,
+            chambers,
+            config,
+            metrics,
+            pending_polls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Poll every chamber once.
+    pub async fn poll(&self) {
+        for client in &self.chambers {
+            if let Err(err) = self.poll_chamber(client).await {
+                log::error!("Unable to poll chamber {}: {}", client.chamber, err);
+                self.record_failure(client, "poll");
+            }
+        }
+    }
+
+    pub async fn poll_chamber(&self, client: &ChamberClient) -> Result<()> {
+        let chamber = self.fetch::<state::Chamber>(&client.chamber).await?;
+        let label = client.chamber.to_string();
+        let client = &client
+            .clone()
+            .with_active_obligation(chamber.active_obligation);
+
+        self.metrics
+            .is_paused
+            .with_label_values(&[&label])
+            .set(chamber.access.is_paused.into());
+
+        self.finish_stuck_deposits(client).await?;
+
+        if chamber.access.is_paused {
+            return Ok(());
+        }
+
+        let drift = self.fetch_drift(client, &chamber).await?;
+        self.metrics
+            .drift_bps
+            .with_label_values(&[&label])
+            .set(drift.drift_bps.try_into().unwrap_or(i64::MAX));
+        self.metrics
+            .ltv_bps
+            .with_label_values(&[&label])
+            .set(drift.ltv_bps.try_into().unwrap_or(i64::MAX));
+
+        if chamber.risk.is_enabled() && drift.ltv_bps >= chamber.risk.get_trigger_ltv_bps() {
+            log::info!(
+                "Deleveraging chamber {}, ltv: {} bps",
+                client.chamber,
+                drift.ltv_bps
+            );
+
+            match self
+                .backend
+                .send_instructions(
+                    &[client.deleverage_chamber(
+                        &self.signer.pubkey(),
+                        chamber.active_obligation,
+                        0,
+                        0,
+                        0,
+                    )],
+                    &self.signer,
+                )
+                .await
+            {
+                Ok(signature) => {
+                    log::info!("Deleveraged chamber {}: {}", client.chamber, signature);
+                    self.metrics.deleverages.with_label_values(&[&label]).inc();
+                }
+                Err(err) => {
+                    log::error!("Unable to deleverage chamber {}: {}", client.chamber, err);
+                    self.record_failure(client, "deleverage");
+                }
+            }
+
+            return Ok(());
+        }
+
+        if !chamber.roles.is_strategist(&self.signer.pubkey()) {
+            log::debug!(
+                "Skipping chamber {}, keeper is not strategist",
+                client.chamber
+            );
+            return Ok(());
+        }
+
+        let drift_threshold_bps = self
+            .config
+            .drift_threshold_bps
+            .max(chamber.risk.rebalance_drift_bps);
+
+        if chamber.risk.is_enabled()
+            && drift.drift_bps >= drift_threshold_bps
+            && drift.is_under_levered()
+        {
+            log::info!(
+                "Releveraging chamber {}, drift: {} bps",
+                client.chamber,
+                drift.drift_bps
+            );
+
+            match self
+                .backend
+                .send_instructions(
+                    &[client.releverage_chamber(&self.signer.pubkey())],
+                    &self.signer,
+                )
+                .await
+            {
+                Ok(signature) => {
+                    log::info!("Releveraged chamber {}: {}", client.chamber, signature);
+                    self.metrics.releverages.with_label_values(&[&label]).inc();
+                }
+                Err(err) => {
+                    log::error!("Unable to releverage chamber {}: {}", client.chamber, err);
+                    self.record_failure(client, "releverage");
+                }
+            }
+        } else if drift.drift_bps >= drift_threshold_bps {
+            log::info!(
+                "Rebalancing chamber {}, drift: {} bps",
+                client.chamber,
+                drift.drift_bps
+            );
+
+            match self
+                .backend
+                .send_instructions(
+                    &[client.rebalance_chamber(
+                        &self.signer.pubkey(),
+                        chamber.active_obligation,
+                        100,
+                    )],
+                    &self.signer,
+                )
+                .await
+            {
+                Ok(signature) => {
+                    log::info!("Rebalanced chamber {}: {}", client.chamber, signature);
+                    self.metrics.rebalances.with_label_values(&[&label]).inc();
+                }
+                Err(err) => {
+                    log::error!("Unable to rebalance chamber {}: {}", client.chamber, err);
+                    self.record_failure(client, "rebalance");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finish deposits of `UserAccount`s, which stay in pending stage for `stuck_polls` polls,
+    /// or cancel them, when they stay pending for `cancel_polls` polls and are not yet
+    /// moved into position.
+    ///
+    /// Returns number of stuck `UserAccount`s.
+    pub async fn finish_stuck_deposits(&self, client: &ChamberClient) -> Result<usize> {
+        let label = client.chamber.to_string();
+        let user_accounts = self.backend.get_user_accounts(&client.chamber).await?;
+
+        let mut stuck = vec![];
+        {
+            let mut pending_polls = self.pending_polls.lock().await;
+
+            for (address, user_account) in user_accounts {
+                if user_account.status == state::UserAccountStatus::Ready {
+                    pending_polls.remove(&address);
+                    continue;
+                }
+
+                let polls = pending_polls.entry(address).or_insert(0);
+                *polls += 1;
+
+                if *polls >= self.config.stuck_polls {
+                    let is_cancelled =
+                        *polls >= self.config.cancel_polls && user_account.is_cancellable();
+                    stuck.push((user_account, is_cancelled));
+                }
+            }
+        }
+
+        self.metrics
+            .stuck_user_accounts
+            .with_label_values(&[&label])
+            .set(stuck.len() as i64);
+
+        for (user_account, is_cancelled) in &stuck {
+            if *is_cancelled {
+                self.cancel_deposit(client, user_account).await;
+                continue;
+            }
+
+            let ixs = get_finish_instructions(client, user_account);
+
+            match self.backend.send_instructions(&ixs, &self.signer).await {
+                Ok(signature) => {
+                    log::info!(
+                        "Finished deposit of {} in chamber {}: {}",
+                        user_account.user,
+                        client.chamber,
+                        signature
+                    );
+                    self.metrics
+                        .finished_deposits
+                        .with_label_values(&[&label])
+                        .inc();
+                }
+                Err(err) => {
+                    log::error!(
+                        "Unable to finish deposit of {} in chamber {}: {}",
+                        user_account.user,
+                        client.chamber,
+                        err
+                    );
+                    self.record_failure(client, "finish_deposit");
+                }
+            }
+        }
+
+        Ok(stuck.len())
+    }
+
+    /// Cancel pending deposit of `user_account`, refunding locked tokens to user.
+    async fn cancel_deposit(&self, client: &ChamberClient, user_account: &state::UserAccount) {
+        let ix = client.cancel_deposit_chamber(&self.signer.pubkey(), &user_account.user);
+
+        match self.backend.send_instructions(&[ix], &self.signer).await {
+            Ok(signature) => {
+                log::info!(
+                    "Cancelled deposit of {} in chamber {}: {}",
+                    user_account.user,
+                    client.chamber,
+                    signature
+                );
+                self.metrics
+                    .cancelled_deposits
+                    .with_label_values(&[&client.chamber.to_string()])
+                    .inc();
+            }
+            Err(err) => {
+                log::error!(
+                    "Unable to cancel deposit of {} in chamber {}: {}",
+                    user_account.user,
+                    client.chamber,
+                    err
+                );
+                self.record_failure(client, "cancel_deposit");
+            }
+        }
+    }
+
+    pub async fn fetch_drift(
+        &self,
+        client: &ChamberClient,
+        chamber: &state::Chamber,
+    ) -> Result<ChamberDrift> {
+        let base_token = self
+            .fetch::<token::TokenAccount>(&client.base_token)
+            .await?;
+        let quote_token = self
+            .fetch::<token::TokenAccount>(&client.quote_token)
+            .await?;
+        let lp_mint = self
+            .fetch::<token::Mint>(&client.tulip.lp_mint_address)
+            .await?;
+
+        let base_price = self.fetch_price(&client.base_oracle).await?;
+        let quote_price = self.fetch_price(&client.quote_oracle).await?;
+        let lp_price = self.fetch_price(&client.tulip.lp_price_account).await?;
+
+        let obligation_address = client
+            .tulip
+            .get_obligation(chamber.active_obligation)
+            .user_farm_obligation;
+        let mut obligation_account = self.fetch_account(&obligation_address).await?;
+        let obligation = position::tulip::load_obligation(
+            &to_account_info(&obligation_address, &mut obligation_account),
+            &chamber.strategy.farm_program,
+        )?;
+        let (base_debt, quote_debt) = position::tulip::get_obligation_debt(&obligation)?;
+
+        let snapshot = ChamberSnapshot {
+            base_amount: base_token.amount,
+            quote_amount: quote_token.amount,
+            lp_amount: obligation.lp_tokens,
+            base_debt,
+            quote_debt,
+            base_price,
+            quote_price,
+            lp_price,
+            lp_decimals: lp_mint.decimals.into(),
+        };
+
+        Ok(drift::compute_drift(chamber, &snapshot)?)
+    }
+
+    async fn fetch_account(&self, address: &Pubkey) -> Result<Account> {
+        self.backend
+            .get_account(address)
+            .await?
+            .ok_or_else(|| anyhow!("Account is not found: {}", address))
+    }
+
+    async fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let account = self.fetch_account(address).await?;
+
+        T::try_deserialize(&mut account.data.as_slice())
+            .map_err(|err| anyhow!("Unable to decode {}: {}", address, err))
+    }
+
+    async fn fetch_price(
+        &self,
+        oracle: &Pubkey,
+    ) -> Result<tulipv2_sdk_common::math::decimal::Decimal> {
+        let account = self.fetch_account(oracle).await?;
+
+        Ok(tulipv2_sdk_common::pyth::load_pyth_price(&account.data)?)
+    }
+
+    fn record_failure(&self, client: &ChamberClient, operation: &str) {
+        self.metrics
+            .failures
+            .with_label_values(&[&client.chamber.to_string(), operation])
+            .inc();
+    }
+}
+
+/// Get instructions, which finish pending deposit stages of `user_account`.
+pub fn get_finish_instructions(
+    client: &ChamberClient,
+    user_account: &state::UserAccount,
+) -> Vec<Instruction> {
+    match user_account.status {
+        state::UserAccountStatus::BeginDeposit => vec![
+            client.process_deposit_chamber(&user_account.user),
+            client.end_deposit_chamber(&user_account.user),
+        ],
+        state::UserAccountStatus::ProcessDeposit => {
+            vec![client.end_deposit_chamber(&user_account.user)]
+        }
+        state::UserAccountStatus::Ready => vec![],
+    }
+}
+
+fn to_account_info<'a>(address: &'a Pubkey, account: &'a mut Account) -> AccountInfo<'a> {
+    AccountInfo::new(
+        address,
+        false,
+        false,
+        &mut account.lamports,
+        &mut account.data,
+        &account.owner,
+        account.executable,
+        account.rent_epoch,
+    )
+}
@@ -0,0 +1,8 @@
+//! Keeper service, which rebalances drifted chambers and finishes or cancels stuck deposits.
+
+pub mod backend;
+pub mod drift;
+mod keeper;
+pub mod metrics;
+
+pub use keeper::*;
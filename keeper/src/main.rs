@@ -0,0 +1,165 @@
+//! Keeper service for Cetra chambers.
+
+use anchor_lang::AccountDeserialize;
+use anyhow::{anyhow, Context, Result};
+use cetra_chamber::{state, utils};
+use cetra_chamber_client::{farm, ChamberClient};
+use cetra_keeper::{
+    backend::{Backend, RpcBackend},
+    metrics, Keeper, KeeperConfig,
+};
+use clap::Parser;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, pubkey::Pubkey, signature::read_keypair_file,
+};
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+
+#[derive(Debug, Parser)]
+#[clap(name = "cetra-keeper", version, about = "Keep Cetra chambers healthy")]
+struct Cli {
+    /// Solana json rpc url.
+    #[clap(long, default_value = "https://api.mainnet-beta.solana.com")]
+    url: String,
+
+    /// Keeper keypair path.
+    #[clap(long)]
+    keypair: PathBuf,
+
+    /// Tulip leveraged farm name, e.g. `ray-sol-usdc`.
+    #[clap(long)]
+    farm: String,
+
+    /// Chamber nonces to keep.
+    #[clap(long, multiple_values = true, default_value = "0")]
+    nonces: Vec<u8>,
+
+    /// Keep all not closed `farm` chambers, listed by chamber registry, instead of `nonces`.
+    #[clap(long)]
+    registry: bool,
+
+    /// Json file with lending reserve and vault accounts.
+    #[clap(long)]
+    market_accounts: Option<PathBuf>,
+
+    /// Poll interval in seconds.
+    #[clap(long, default_value_t = 60)]
+    interval: u64,
+
+    /// Debt drift in basis points, which triggers rebalance.
+    #[clap(long, default_value_t = 500)]
+    drift_threshold_bps: u64,
+
+    /// Number of polls, after which pending deposit is considered stuck.
+    #[clap(long, default_value_t = 3)]
+    stuck_polls: u32,
+
+    /// Number of polls, after which stuck deposit is cancelled, keeper should be chamber guardian.
+    #[clap(long, default_value_t = 10)]
+    cancel_polls: u32,
+
+    /// Prometheus metrics listen address.
+    #[clap(long, default_value = "0.0.0.0:9100")]
+    metrics_addr: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::init();
+
+    let cli = Cli::parse();
+
+    let signer = read_keypair_file(&cli.keypair)
+        .map_err(|err| anyhow!("Unable to read keypair: {}", err))?;
+
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let backend = RpcBackend::new(rpc);
+
+    let (farm_config, farm_type) = farm::get_farm(&cli.farm)?;
+    let market = farm::load_market_accounts(cli.market_accounts.as_deref())?;
+
+    let nonces = if cli.registry {
+        fetch_registry_nonces(&backend, &farm_config.account).await?
+    } else {
+        cli.nonces.clone()
+    };
+
+    let mut chambers = vec![];
+    for nonce in &nonces {
+        let client = ChamberClient::new(
+            &farm_config,
+            farm_type,
+            market.clone(),
+            *nonce,
+            &Default::default(),
+        );
+
+        let account = backend
+            .get_account(&client.chamber)
+            .await?
+            .ok_or_else(|| anyhow!("Chamber is not found: {}", client.chamber))?;
+        let chamber = state::Chamber::try_deserialize(&mut account.data.as_slice())
+            .map_err(|err| anyhow!("Unable to decode chamber {}: {}", client.chamber, err))?;
+
+        chambers.push(ChamberClient::new(
+            &farm_config,
+            farm_type,
+            market.clone(),
+            *nonce,
+            &chamber.config.shares_mint,
+        ));
+    }
+
+    let metrics = Arc::new(metrics::Metrics::new()?);
+    metrics::serve(metrics.clone(), cli.metrics_addr).context("Unable to serve metrics")?;
+
+    let keeper = Keeper::new(
+        backend,
+        signer,
+        chambers,
+        KeeperConfig {
+            drift_threshold_bps: cli.drift_threshold_bps,
+            stuck_polls: cli.stuck_polls,
+            cancel_polls: cli.cancel_polls,
+        },
+        metrics,
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_secs(cli.interval));
+    loop {
+        interval.tick().await;
+        keeper.poll().await;
+    }
+}
+
+/// Nonces of not closed `farm` chambers, listed by all chamber registry pages.
+async fn fetch_registry_nonces(backend: &RpcBackend, farm: &Pubkey) -> Result<Vec<u8>> {
+    let protocol_config: state::ProtocolConfig =
+        fetch_account(backend, &utils::derive_protocol_config_address().0).await?;
+
+    let mut nonces = vec![];
+    for index in 0..protocol_config.registry_pages {
+        let registry: state::ChamberRegistry =
+            fetch_account(backend, &utils::derive_chamber_registry_address(index).0).await?;
+
+        nonces.extend(
+            registry
+                .entries
+                .iter()
+                .filter(|entry| entry.farm == *farm && entry.status != state::ChamberStatus::Closed)
+                .map(|entry| entry.nonce),
+        );
+    }
+
+    Ok(nonces)
+}
+
+async fn fetch_account<T: AccountDeserialize>(backend: &RpcBackend, address: &Pubkey) -> Result<T> {
+    let account = backend
+        .get_account(address)
+        .await?
+        .ok_or_else(|| anyhow!("Account is not found: {}", address))?;
+
+    T::try_deserialize(&mut account.data.as_slice())
+        .map_err(|err| anyhow!("Unable to decode account {}: {}", address, err))
+}
@@ -0,0 +1,147 @@
+//! Prometheus metrics of keeper.
+
+use anyhow::Result;
+use prometheus::{Encoder, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder};
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener},
+    sync::Arc,
+    thread,
+};
+
+pub struct Metrics {
+    registry: Registry,
+
+    pub drift_bps: IntGaugeVec,
+    pub ltv_bps: IntGaugeVec,
+    pub is_paused: IntGaugeVec,
+    pub stuck_user_accounts: IntGaugeVec,
+
+    pub rebalances: IntCounterVec,
+    pub deleverages: IntCounterVec,
+    pub releverages: IntCounterVec,
+    pub finished_deposits: IntCounterVec,
+    pub cancelled_deposits: IntCounterVec,
+    pub failures: IntCounterVec,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+
+        let drift_bps = IntGaugeVec::new(
+            Opts::new(
+                "cetra_chamber_drift_bps",
+                "Chamber debt drift in basis points",
+            ),
+            &["chamber"],
+        )?;
+        let ltv_bps = IntGaugeVec::new(
+            Opts::new(
+                "cetra_chamber_ltv_bps",
+                "Chamber obligation LTV in basis points",
+            ),
+            &["chamber"],
+        )?;
+        let is_paused = IntGaugeVec::new(
+            Opts::new("cetra_chamber_paused", "Chamber pause flag"),
+            &["chamber"],
+        )?;
+        let stuck_user_accounts = IntGaugeVec::new(
+            Opts::new(
+                "cetra_chamber_stuck_user_accounts",
+                "User accounts with pending deposit stages",
+            ),
+            &["chamber"],
+        )?;
+        let rebalances = IntCounterVec::new(
+            Opts::new("cetra_chamber_rebalances_total", "Submitted rebalances"),
+            &["chamber"],
+        )?;
+        let deleverages = IntCounterVec::new(
+            Opts::new("cetra_chamber_deleverages_total", "Submitted deleverages"),
+            &["chamber"],
+        )?;
+        let releverages = IntCounterVec::new(
+            Opts::new("cetra_chamber_releverages_total", "Submitted releverages"),
+            &["chamber"],
+        )?;
+        let finished_deposits = IntCounterVec::new(
+            Opts::new(
+                "cetra_chamber_finished_deposits_total",
+                "Pending deposits finished by keeper",
+            ),
+            &["chamber"],
+        )?;
+        let cancelled_deposits = IntCounterVec::new(
+            Opts::new(
+                "cetra_chamber_cancelled_deposits_total",
+                "Stuck deposits cancelled by keeper",
+            ),
+            &["chamber"],
+        )?;
+        let failures = IntCounterVec::new(
+            Opts::new(
+                "cetra_keeper_failures_total",
+                "Keeper failures by operation",
+            ),
+            &["chamber", "operation"],
+        )?;
+
+        registry.register(Box::new(drift_bps.clone()))?;
+        registry.register(Box::new(ltv_bps.clone()))?;
+        registry.register(Box::new(is_paused.clone()))?;
+        registry.register(Box::new(stuck_user_accounts.clone()))?;
+        registry.register(Box::new(rebalances.clone()))?;
+        registry.register(Box::new(deleverages.clone()))?;
+        registry.register(Box::new(releverages.clone()))?;
+        registry.register(Box::new(finished_deposits.clone()))?;
+        registry.register(Box::new(cancelled_deposits.clone()))?;
+        registry.register(Box::new(failures.clone()))?;
+
+        Ok(Metrics {
+            registry,
+            drift_bps,
+            ltv_bps,
+            is_paused,
+            stuck_user_accounts,
+            rebalances,
+            deleverages,
+            releverages,
+            finished_deposits,
+            cancelled_deposits,
+            failures,
+        })
+    }
+
+    /// Encode metrics in prometheus text format.
+    pub fn encode(&self) -> Result<String> {
+        let mut buffer = vec![];
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+/// Serve metrics over http on `addr` in background thread.
+pub fn serve(metrics: Arc<Metrics>, addr: SocketAddr) -> Result<()> {
+    let listener = TcpListener::bind(addr)?;
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+
+            let body = metrics.encode().unwrap_or_default();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::Pubkey;
+use cetra_chamber::{state, utils};
+use cetra_keeper::drift::{self, ChamberSnapshot};
+use tulipv2_sdk_common::math::decimal::Decimal;
+
+fn build_chamber(leverage: u64) -> state::Chamber {
+    state::Chamber {
+        strategy: state::ChamberStrategy::new(
+            state::ChamberMarket::Tulip,
+            &Pubkey::default(),
+            &Pubkey::default(),
+            leverage,
+            true,
+        ),
+        vault: state::ChamberVault::new(
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            0,
+            0,
+        ),
+        config: state::ChamberConfig::new(
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            0,
+            0,
+        ),
+        limits: state::ChamberLimits::default(),
+        access: state::ChamberAccess::default(),
+        harvest: state::ChamberHarvest::default(),
+        obligations: Default::default(),
+        active_obligation: 0,
+        risk: state::ChamberRisk::default(),
+        debt: state::ChamberDebt::default(),
+        version: utils::CHAMBER_VERSION,
+        roles: state::ChamberRoles::default(),
+        timelock: state::ChamberTimelock::default(),
+        risk_bounds: state::ChamberRiskBounds::default(),
+        reserved: [0; utils::CHAMBER_RESERVED_LEN],
+    }
+}
+
+#[test]
+fn success() {
+    let chamber = build_chamber(3);
+
+    // 1. Equity of 400 with 3x leverage targets 800 of debt, split 3/4 to 1/4
+    let balanced = ChamberSnapshot {
+        base_amount: 1200,
+        quote_amount: 0,
+        lp_amount: 0,
+        base_debt: 600,
+        quote_debt: 200,
+        base_price: Decimal::one(),
+        quote_price: Decimal::one(),
+        lp_price: Decimal::one(),
+        lp_decimals: 0,
+    };
+
+    let result = drift::compute_drift(&chamber, &balanced).expect("Unable to compute drift!");
+
+    assert_eq!(result.base_target_debt, 600);
+    assert_eq!(result.quote_target_debt, 200);
+    assert_eq!(result.drift_bps, 0);
+    assert_eq!(result.ltv_bps, u64::MAX);
+    assert!(!result.is_under_levered());
+
+    // 2. Extra base debt drifts base leg
+    let result = drift::compute_drift(
+        &chamber,
+        &ChamberSnapshot {
+            base_amount: 1260,
+            base_debt: 660,
+            ..balanced
+        },
+    )
+    .expect("Unable to compute drift!");
+
+    assert_eq!(result.base_target_debt, 600);
+    assert_eq!(result.drift_bps, 1000);
+    assert!(!result.is_under_levered());
+
+    // 3. Debt of 800 against LP position of 1000 is 80% LTV
+    let result = drift::compute_drift(
+        &chamber,
+        &ChamberSnapshot {
+            base_amount: 200,
+            lp_amount: 1000,
+            ..balanced
+        },
+    )
+    .expect("Unable to compute drift!");
+
+    assert_eq!(result.ltv_bps, 8000);
+
+    // 4. Repaid debt on both legs leaves position under-levered
+    let result = drift::compute_drift(
+        &chamber,
+        &ChamberSnapshot {
+            base_debt: 300,
+            quote_debt: 100,
+            ..balanced
+        },
+    )
+    .expect("Unable to compute drift!");
+
+    assert_eq!(result.base_target_debt, 1200);
+    assert_eq!(result.quote_target_debt, 400);
+    assert!(result.is_under_levered());
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use cetra_chamber::{state, utils};
+use cetra_chamber_client::{farm, tulip::TulipMarketAccounts, ChamberClient};
+use cetra_keeper::{backend::Backend, metrics::Metrics, Keeper, KeeperConfig};
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    account::Account, instruction::Instruction, signature::Keypair, signature::Signature,
+    signer::Signer, transaction::Transaction,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Backend over `solana-program-test`, with known `UserAccount`s.
+struct TestBackend {
+    test_context: Mutex<TestContext>,
+    user_accounts: Vec<(Pubkey, state::UserAccount)>,
+}
+
+#[async_trait]
+impl Backend for TestBackend {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        let mut test_context = self.test_context.lock().await;
+
+        Ok(test_context
+            .context
+            .banks_client
+            .get_account(*address)
+            .await?)
+    }
+
+    async fn get_user_accounts(
+        &self,
+        _chamber: &Pubkey,
+    ) -> Result<Vec<(Pubkey, state::UserAccount)>> {
+        Ok(self.user_accounts.clone())
+    }
+
+    async fn send_instructions(&self, ixs: &[Instruction], signer: &Keypair) -> Result<Signature> {
+        let mut test_context = self.test_context.lock().await;
+
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&signer.pubkey()),
+            &[signer],
+            test_context.context.last_blockhash,
+        );
+        let signature = tx.signatures[0];
+
+        test_context
+            .process_transaction(tx)
+            .await
+            .map_err(|err| anyhow!("{}", err))?;
+
+        Ok(signature)
+    }
+}
+
+fn build_user_account(
+    client: &ChamberClient,
+    status: state::UserAccountStatus,
+) -> (Pubkey, state::UserAccount) {
+    let user = Pubkey::new_unique();
+
+    let user_account = state::UserAccount {
+        chamber: client.chamber,
+        user,
+        shares: client.user_shares_address(&user),
+        status,
+        locked_base_amount: 0,
+        locked_quote_amount: 0,
+        locked_shares_amount: 0,
+        version: utils::USER_ACCOUNT_VERSION,
+        deposited_value: 0,
+        locked_value: 0,
+        idle_base_amount: 0,
+        idle_quote_amount: 0,
+        reserved: [0; utils::USER_ACCOUNT_RESERVED_LEN],
+    };
+
+    (client.user_account_address(&user), user_account)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let signer = Keypair::from_bytes(&test_context.context.payer.to_bytes())
+        .expect("Unable to clone payer!");
+
+    let (farm_config, farm_type) = farm::get_farm("ray-sol-usdc").expect("Unknown farm!");
+    let client = ChamberClient::new(
+        &farm_config,
+        farm_type,
+        TulipMarketAccounts::default(),
+        0,
+        &Pubkey::new_unique(),
+    );
+
+    // 1. Build ready and stuck `UserAccount`s, one of which is moved into position
+    let mut position_user_account =
+        build_user_account(&client, state::UserAccountStatus::BeginDeposit);
+    position_user_account.1.locked_base_amount = 1000;
+
+    let user_accounts = vec![
+        build_user_account(&client, state::UserAccountStatus::Ready),
+        build_user_account(&client, state::UserAccountStatus::BeginDeposit),
+        build_user_account(&client, state::UserAccountStatus::ProcessDeposit),
+        position_user_account,
+    ];
+
+    let metrics = Arc::new(Metrics::new().expect("Unable to create metrics!"));
+    let keeper = Keeper::new(
+        TestBackend {
+            test_context: Mutex::new(test_context),
+            user_accounts,
+        },
+        signer,
+        vec![client.clone()],
+        KeeperConfig {
+            drift_threshold_bps: 500,
+            stuck_polls: 2,
+            cancel_polls: 3,
+        },
+        metrics.clone(),
+    );
+
+    // 2. Pending `UserAccount`s are not stuck after first poll
+    let stuck = keeper
+        .finish_stuck_deposits(&client)
+        .await
+        .expect("Unable to finish stuck deposits!");
+
+    assert_eq!(stuck, 0);
+
+    // 3. Pending `UserAccount`s are stuck after second poll, finishing fails without `Chamber`
+    let stuck = keeper
+        .finish_stuck_deposits(&client)
+        .await
+        .expect("Unable to finish stuck deposits!");
+
+    assert_eq!(stuck, 3);
+
+    let label = client.chamber.to_string();
+    assert_eq!(
+        metrics
+            .stuck_user_accounts
+            .with_label_values(&[&label])
+            .get(),
+        3
+    );
+    assert_eq!(
+        metrics
+            .failures
+            .with_label_values(&[&label, "finish_deposit"])
+            .get(),
+        3
+    );
+
+    // 4. Stuck `UserAccount`s are cancelled after third poll, cancelling fails without `Chamber`,
+    // while `UserAccount` moved into position is still finished
+    let stuck = keeper
+        .finish_stuck_deposits(&client)
+        .await
+        .expect("Unable to cancel stuck deposits!");
+
+    assert_eq!(stuck, 3);
+    assert_eq!(
+        metrics
+            .failures
+            .with_label_values(&[&label, "cancel_deposit"])
+            .get(),
+        2
+    );
+    assert_eq!(
+        metrics
+            .failures
+            .with_label_values(&[&label, "finish_deposit"])
+            .get(),
+        4
+    );
+
+    // 5. Polling missing `Chamber` is reported as failure
+    keeper.poll().await;
+
+    assert_eq!(
+        metrics.failures.with_label_values(&[&label, "poll"]).get(),
+        1
+    );
+}
@@ -0,0 +1,54 @@
+use crate::error::ChamberError;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction},
+};
+
+/// Address Lookup Table `CreateLookupTable` instruction tag.
+pub const CREATE_LOOKUP_TABLE_INSTRUCTION: u32 = 0;
+
+#[derive(Accounts)]
+pub struct CreateLookupTable<'info> {
+    #[account(mut)]
+    pub lookup_table: AccountInfo<'info>,
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(mut, signer)]
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn address_lookup_table_create(
+    accounts: Box<CreateLookupTable>,
+    program_id: &Pubkey,
+    recent_slot: u64,
+    bump_seed: u8,
+) -> Option<Instruction> {
+    let mut ix_data = Vec::with_capacity(13);
+    ix_data.extend_from_slice(&CREATE_LOOKUP_TABLE_INSTRUCTION.to_le_bytes());
+    ix_data.extend_from_slice(&recent_slot.to_le_bytes());
+    ix_data.push(bump_seed);
+
+    let accounts = accounts.to_account_metas(None);
+
+    Some(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+pub fn create_lookup_table<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Box<CreateLookupTable<'info>>>,
+    recent_slot: u64,
+    bump_seed: u8,
+) -> Result<()> {
+    let program_id = ctx.program.key();
+    let account_infos = ctx.accounts.to_account_infos();
+
+    let ix = address_lookup_table_create(ctx.accounts, &program_id, recent_slot, bump_seed)
+        .ok_or(ChamberError::CpiInstructionFormationFailed)?;
+
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
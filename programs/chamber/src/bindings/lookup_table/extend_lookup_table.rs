@@ -0,0 +1,55 @@
+use crate::error::ChamberError;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction},
+};
+
+/// Address Lookup Table `ExtendLookupTable` instruction tag.
+pub const EXTEND_LOOKUP_TABLE_INSTRUCTION: u32 = 2;
+
+#[derive(Accounts)]
+pub struct ExtendLookupTable<'info> {
+    #[account(mut)]
+    pub lookup_table: AccountInfo<'info>,
+    #[account(signer)]
+    pub authority: AccountInfo<'info>,
+    #[account(mut, signer)]
+    pub payer: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+pub fn address_lookup_table_extend(
+    accounts: Box<ExtendLookupTable>,
+    program_id: &Pubkey,
+    new_addresses: &[Pubkey],
+) -> Option<Instruction> {
+    // Instruction data is `bincode` encoded, so addresses are prefixed with `u64` length
+    let mut ix_data = Vec::with_capacity(12 + new_addresses.len() * 32);
+    ix_data.extend_from_slice(&EXTEND_LOOKUP_TABLE_INSTRUCTION.to_le_bytes());
+    ix_data.extend_from_slice(&(new_addresses.len() as u64).to_le_bytes());
+    for address in new_addresses {
+        ix_data.extend_from_slice(address.as_ref());
+    }
+
+    let accounts = accounts.to_account_metas(None);
+
+    Some(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+pub fn extend_lookup_table<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Box<ExtendLookupTable<'info>>>,
+    new_addresses: &[Pubkey],
+) -> Result<()> {
+    let program_id = ctx.program.key();
+    let account_infos = ctx.accounts.to_account_infos();
+
+    let ix = address_lookup_table_extend(ctx.accounts, &program_id, new_addresses)
+        .ok_or(ChamberError::CpiInstructionFormationFailed)?;
+
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
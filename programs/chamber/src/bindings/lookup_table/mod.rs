@@ -0,0 +1,7 @@
+//! Module provide bindings for `Address Lookup Table` program.
+
+mod create_lookup_table;
+mod extend_lookup_table;
+
+pub use create_lookup_table::*;
+pub use extend_lookup_table::*;
@@ -0,0 +1,5 @@
+//! Module provide anchor `CPI` bindings for external programs.
+
+pub mod lookup_table;
+pub mod raydium;
+pub mod tulip;
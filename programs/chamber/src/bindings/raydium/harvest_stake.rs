@@ -0,0 +1,60 @@
+use crate::error::ChamberError;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction},
+};
+use anchor_spl::token;
+
+/// Raydium staking `Deposit` instruction tag, zero amount deposit only collects pending rewards.
+pub const STAKE_DEPOSIT_INSTRUCTION: u8 = 1;
+
+#[derive(Accounts)]
+pub struct HarvestStake<'info> {
+    #[account(mut)]
+    pub pool_id: AccountInfo<'info>,
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_info_account: AccountInfo<'info>,
+    #[account(signer)]
+    pub user_owner: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_lp_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_lp_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_reward_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_reward_token_account: AccountInfo<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+pub fn raydium_harvest_stake(
+    accounts: Box<HarvestStake>,
+    program_id: &Pubkey,
+) -> Option<Instruction> {
+    let mut ix_data = Vec::with_capacity(9);
+    ix_data.push(STAKE_DEPOSIT_INSTRUCTION);
+    ix_data.extend_from_slice(&0u64.to_le_bytes());
+
+    let accounts = accounts.to_account_metas(None);
+
+    Some(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+pub fn harvest_stake<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Box<HarvestStake<'info>>>,
+) -> Result<()> {
+    let program_id = ctx.program.key();
+    let account_infos = ctx.accounts.to_account_infos();
+
+    let ix = raydium_harvest_stake(ctx.accounts, &program_id)
+        .ok_or(ChamberError::CpiInstructionFormationFailed)?;
+
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
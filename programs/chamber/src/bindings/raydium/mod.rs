@@ -0,0 +1,7 @@
+//! Module provide bindings for `Raydium` protocol.
+
+mod harvest_stake;
+mod swap_base_in;
+
+pub use harvest_stake::*;
+pub use swap_base_in::*;
@@ -0,0 +1,80 @@
+use crate::error::ChamberError;
+use anchor_lang::{
+    prelude::*,
+    solana_program::{self, instruction::Instruction},
+};
+use anchor_spl::token;
+
+/// Raydium AMM v4 `SwapBaseIn` instruction tag.
+pub const SWAP_BASE_IN_INSTRUCTION: u8 = 9;
+
+#[derive(Accounts)]
+pub struct SwapBaseIn<'info> {
+    pub token_program: Program<'info, token::Token>,
+    #[account(mut)]
+    pub amm_id: AccountInfo<'info>,
+    pub amm_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_open_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_target_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_coin_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_pc_token_account: AccountInfo<'info>,
+    pub serum_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_market: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_bids: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_asks: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_event_queue: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_coin_vault_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_pc_vault_account: AccountInfo<'info>,
+    pub serum_vault_signer: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_source_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_destination_token_account: AccountInfo<'info>,
+    #[account(signer)]
+    pub user_source_owner: AccountInfo<'info>,
+}
+
+pub fn raydium_swap_base_in(
+    accounts: Box<SwapBaseIn>,
+    program_id: &Pubkey,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Option<Instruction> {
+    let mut ix_data = Vec::with_capacity(17);
+    ix_data.push(SWAP_BASE_IN_INSTRUCTION);
+    ix_data.extend_from_slice(&amount_in.to_le_bytes());
+    ix_data.extend_from_slice(&minimum_amount_out.to_le_bytes());
+
+    let accounts = accounts.to_account_metas(None);
+
+    Some(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+pub fn swap_base_in<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, Box<SwapBaseIn<'info>>>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Result<()> {
+    let program_id = ctx.program.key();
+    let account_infos = ctx.accounts.to_account_infos();
+
+    let ix = raydium_swap_base_in(ctx.accounts, &program_id, amount_in, minimum_amount_out)
+        .ok_or(ChamberError::CpiInstructionFormationFailed)?;
+
+    solana_program::program::invoke_signed(&ix, &account_infos, ctx.signer_seeds)
+        .map_err(Into::into)
+}
@@ -4,7 +4,6 @@ use tulipv2_sdk_levfarm::instructions::close_position_info::{
     close_position_info_account as tulip_close_position_info_account, ClosePositionInfoAccount,
 };
 
-#[allow(unused)]
 pub fn close_position_info_account<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, Box<ClosePositionInfoAccount<'info>>>,
 ) -> Result<()> {
@@ -64,7 +64,6 @@ impl<'info> From<&DepositObligationCollateral<'info>> for TulipDepositObligation
     }
 }
 
-#[allow(unused)]
 pub fn top_up_position_stats<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, Box<DepositObligationCollateral<'info>>>,
     coin_amount: u64,
@@ -1,3 +0,0 @@
-//! Module provide anchor `CPI` bindings.
-
-pub mod tulip;
@@ -13,4 +13,157 @@ pub enum ChamberError {
 
     #[msg("Math overflow")]
     MathOverflow,
+
+    #[msg("Invalid output mint")]
+    InvalidOutputMint,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Chamber has no native token side")]
+    NativeTokenNotSupported,
+
+    #[msg("Shares balance is not empty")]
+    SharesBalanceNotEmpty,
+
+    #[msg("Invalid market obligation")]
+    InvalidObligation,
+
+    #[msg("Market position is not unwound")]
+    PositionNotUnwound,
+
+    #[msg("Deposit value is below minimum")]
+    DepositTooSmall,
+
+    #[msg("Chamber deposit cap exceeded")]
+    DepositCapExceeded,
+
+    #[msg("User deposit limit exceeded")]
+    UserDepositLimitExceeded,
+
+    #[msg("User is not allowed to use chamber")]
+    UserNotAllowed,
+
+    #[msg("Invalid program return data")]
+    InvalidReturnData,
+
+    #[msg("Chamber is paused")]
+    ChamberPaused,
+
+    #[msg("Chamber lookup table is already created")]
+    LookupTableAlreadyCreated,
+
+    #[msg("Too many lookup table addresses")]
+    LookupTableAddressesExceeded,
+
+    #[msg("Invalid fee")]
+    InvalidFee,
+
+    #[msg("Chamber harvesting is not configured")]
+    HarvestNotConfigured,
+
+    #[msg("Nothing to harvest")]
+    NothingToHarvest,
+
+    #[msg("Invalid swap route")]
+    InvalidSwapRoute,
+
+    #[msg("Invalid withdraw percent")]
+    InvalidWithdrawPercent,
+
+    #[msg("Invalid risk config")]
+    InvalidRiskConfig,
+
+    #[msg("Chamber risk is not configured")]
+    RiskNotConfigured,
+
+    #[msg("Obligation is healthy")]
+    ObligationHealthy,
+
+    #[msg("Position is at target leverage")]
+    PositionAtTargetLeverage,
+
+    #[msg("Signer is not authorized")]
+    Unauthorized,
+
+    #[msg("Account layout is up to date")]
+    AccountUpToDate,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Farm is not allowed")]
+    FarmNotAllowed,
+
+    #[msg("Oracle is not allowed")]
+    OracleNotAllowed,
+
+    #[msg("Too many programs")]
+    TooManyPrograms,
+
+    #[msg("Chamber registry page is full")]
+    ChamberRegistryFull,
+
+    #[msg("Chamber registry has free entries")]
+    ChamberRegistryNotFull,
+
+    #[msg("Chamber is not registered")]
+    ChamberNotRegistered,
+
+    #[msg("Invalid farm")]
+    InvalidFarm,
+
+    #[msg("Invalid oracle")]
+    InvalidOracle,
+
+    #[msg("Invalid config change")]
+    InvalidConfigChange,
+
+    #[msg("Config change is already pending")]
+    ConfigChangePending,
+
+    #[msg("No config change is pending")]
+    NoConfigChangePending,
+
+    #[msg("Config change is effective before timelock delay")]
+    ConfigChangeTooEarly,
+
+    #[msg("Config change is not effective yet")]
+    ConfigChangeNotEffective,
+
+    #[msg("Config is timelocked, propose config change instead")]
+    ConfigTimelocked,
+
+    #[msg("Insufficient shares")]
+    InsufficientShares,
+
+    #[msg("Invalid user farm")]
+    InvalidUserFarm,
+
+    #[msg("Invalid deposit limits")]
+    InvalidLimits,
+
+    #[msg("Invalid lp account")]
+    InvalidLpAccount,
+
+    #[msg("Invalid slippage")]
+    InvalidSlippage,
+
+    #[msg("Invalid reward farm")]
+    InvalidRewardFarm,
+
+    #[msg("Chamber debt drift is below rebalance threshold")]
+    RebalanceNotNeeded,
+
+    #[msg("Chamber risk config is out of owner bounds")]
+    RiskOutOfBounds,
+
+    #[msg("Pending deposit is already moved into position")]
+    DepositInPosition,
+
+    #[msg("Obligation LTV is above target after releverage")]
+    LtvAboveTarget,
+
+    #[msg("Invalid chamber leverage")]
+    InvalidLeverage,
 }
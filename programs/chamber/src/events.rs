@@ -0,0 +1,176 @@
+//! Module provide events, emitted on `Chamber` state transitions.
+//!
+//! Prices are scaled by `WAD`(1e18), values and `nav` are book value of
+//! `state::ChamberVault::get_total_value`, same value shares are minted by.
+
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ChamberInitialized {
+    pub chamber: Pubkey,
+    pub farm: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub shares_mint: Pubkey,
+    pub owner: Pubkey,
+    pub leverage: u64,
+    pub is_base_volatile: bool,
+    pub nonce: u8,
+}
+
+#[event]
+pub struct DepositBegun {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_borrow_amount: u64,
+    pub quote_borrow_amount: u64,
+    pub base_price: u128,
+    pub quote_price: u128,
+    pub deposit_value: u64,
+    pub locked_shares_amount: u64,
+    pub nav: u64,
+}
+
+#[event]
+pub struct DepositProcessed {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct DepositEnded {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub shares_minted: u64,
+    pub base_price: u128,
+    pub quote_price: u128,
+    pub nav_before: u64,
+    pub nav_after: u64,
+}
+
+#[event]
+pub struct DepositCancelled {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct Deposited {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_borrow_amount: u64,
+    pub quote_borrow_amount: u64,
+    pub base_price: u128,
+    pub quote_price: u128,
+    pub deposit_value: u64,
+    pub shares_minted: u64,
+    pub nav_before: u64,
+    pub nav_after: u64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub output_mint: Option<Pubkey>,
+    pub is_native_unwrapped: bool,
+    pub shares_burned: u64,
+    pub base_price: u128,
+    pub quote_price: u128,
+    pub nav_before: u64,
+    pub nav_after: u64,
+}
+
+#[event]
+pub struct Rebalanced {
+    pub chamber: Pubkey,
+    pub source_obligation: u8,
+    pub target_obligation: u8,
+    pub withdraw_percent: u8,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub base_borrow_amount: u64,
+    pub quote_borrow_amount: u64,
+    pub base_price: u128,
+    pub quote_price: u128,
+    pub nav: u64,
+}
+
+#[event]
+pub struct Deleveraged {
+    pub chamber: Pubkey,
+    pub obligation_index: u8,
+    pub ltv_bps: u64,
+    pub target_ltv_bps: u64,
+    pub withdraw_percent: u8,
+    pub base_debt: u64,
+    pub quote_debt: u64,
+}
+
+#[event]
+pub struct Releveraged {
+    pub chamber: Pubkey,
+    pub obligation_index: u8,
+    pub base_borrow_amount: u64,
+    pub quote_borrow_amount: u64,
+    pub base_debt: u64,
+    pub quote_debt: u64,
+}
+
+#[event]
+pub struct Harvested {
+    pub chamber: Pubkey,
+    pub reward_amount: u64,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub fee_amount: u64,
+    pub protocol_fee_amount: u64,
+}
+
+#[event]
+pub struct FeesCollected {
+    pub chamber: Pubkey,
+    pub fee_manager: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
+
+#[event]
+pub struct ConfigChangeProposed {
+    pub chamber: Pubkey,
+    pub leverage: u64,
+    pub performance_fee_bps: u64,
+    pub delay: i64,
+    pub effective_timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub chamber: Pubkey,
+    pub leverage: u64,
+    pub performance_fee_bps: u64,
+    pub delay: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub chamber: Pubkey,
+    pub signer: Pubkey,
+}
@@ -0,0 +1,107 @@
+//! Module provide stable interface for programs, which integrate `state::Chamber` via `CPI`.
+//!
+//! Instruction accounts are available in anchor generated `crate::cpi::accounts` module
+//! (`cpi` feature), while market specific remaining accounts are described by typed structs,
+//! e.g. `tulip::DepositChamberAccounts`.
+//!
+//! Integrating program may act as `user` on behalf of its own users, signing with its PDA.
+//! `deposit_chamber` and `withdraw_chamber` are processed within a single instruction, so no
+//! additional `begin_deposit_chamber` -> `process_deposit_chamber` -> `end_deposit_chamber` calls
+//! are required.
+
+pub mod tulip;
+
+#[cfg(feature = "cpi")]
+use crate::{error, views};
+#[cfg(feature = "cpi")]
+use anchor_lang::{prelude::*, solana_program::program};
+
+/// Deposit user funds into `Tulip` chamber.
+#[cfg(feature = "cpi")]
+pub fn deposit_tulip<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, crate::cpi::accounts::DepositChamber<'info>>,
+    remaining_accounts: Box<tulip::DepositChamberAccounts<'info>>,
+    base_amount: u64,
+    quote_amount: u64,
+    native_amount: u64,
+) -> Result<()> {
+    crate::cpi::deposit_chamber(
+        ctx.with_remaining_accounts(remaining_accounts.to_account_infos()),
+        base_amount,
+        quote_amount,
+        native_amount,
+    )
+}
+
+/// Withdraw user funds from `Tulip` chamber.
+#[cfg(feature = "cpi")]
+pub fn withdraw_tulip<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, crate::cpi::accounts::WithdrawChamber<'info>>,
+    remaining_accounts: Box<tulip::WithdrawChamberAccounts<'info>>,
+    shares_amount: u64,
+    output_mint: Option<Pubkey>,
+    min_base_out: u64,
+    min_quote_out: u64,
+    unwrap_native: bool,
+) -> Result<()> {
+    crate::cpi::withdraw_chamber(
+        ctx.with_remaining_accounts(remaining_accounts.to_account_infos()),
+        shares_amount,
+        output_mint,
+        min_base_out,
+        min_quote_out,
+        unwrap_native,
+    )
+}
+
+/// Get `Tulip` chamber net asset value.
+#[cfg(feature = "cpi")]
+pub fn get_chamber_nav_tulip<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, crate::cpi::accounts::GetChamberNav<'info>>,
+    remaining_accounts: Box<tulip::ViewChamberAccounts<'info>>,
+) -> Result<views::ChamberNav> {
+    crate::cpi::get_chamber_nav(
+        ctx.with_remaining_accounts(remaining_accounts.to_account_infos()),
+    )?;
+
+    load_return_data()
+}
+
+/// Get `Tulip` chamber share price.
+#[cfg(feature = "cpi")]
+pub fn get_share_price_tulip<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, crate::cpi::accounts::GetSharePrice<'info>>,
+    remaining_accounts: Box<tulip::ViewChamberAccounts<'info>>,
+) -> Result<views::SharePrice> {
+    crate::cpi::get_share_price(
+        ctx.with_remaining_accounts(remaining_accounts.to_account_infos()),
+    )?;
+
+    load_return_data()
+}
+
+/// Get user position in `Tulip` chamber.
+#[cfg(feature = "cpi")]
+pub fn get_user_position_tulip<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, crate::cpi::accounts::GetUserPosition<'info>>,
+    remaining_accounts: Box<tulip::ViewChamberAccounts<'info>>,
+) -> Result<views::UserPosition> {
+    crate::cpi::get_user_position(
+        ctx.with_remaining_accounts(remaining_accounts.to_account_infos()),
+    )?;
+
+    load_return_data()
+}
+
+/// Deserialize view, returned by `Chamber` program.
+#[cfg(feature = "cpi")]
+fn load_return_data<T: AnchorDeserialize>() -> Result<T> {
+    let (program_id, data) =
+        program::get_return_data().ok_or(error::ChamberError::InvalidReturnData)?;
+
+    if program_id != crate::id() {
+        return Err(error::ChamberError::InvalidReturnData.into());
+    }
+
+    T::try_from_slice(&data).map_err(|_| error::ChamberError::InvalidReturnData.into())
+}
@@ -0,0 +1,236 @@
+//! Module provide typed remaining accounts for `Tulip` leveraged farm `state::Chamber`.
+//!
+//! Fields are declared in the exact order, expected by the corresponding processor.
+
+use anchor_lang::prelude::*;
+
+/// Remaining accounts for `deposit_chamber` instruction.
+#[derive(Accounts)]
+pub struct DepositChamberAccounts<'info> {
+    #[account(mut)]
+    pub user_farm: AccountInfo<'info>,
+    pub leveraged_farm: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_farm_obligation: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_destination_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_destination_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_deposit_reserve_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_deposit_reserve_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub lending_market_account: AccountInfo<'info>,
+    pub lending_market_authority: AccountInfo<'info>,
+    pub lending_program: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_source_reserve_liquidity_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_source_reserve_liquidity_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_reserve_liquidity_fee_receiver: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_reserve_liquidity_fee_receiver: AccountInfo<'info>,
+    pub borrow_authorizer: AccountInfo<'info>,
+    pub lp_pyth_price_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub vault_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub position_info_account: AccountInfo<'info>,
+    pub vault_signer: AccountInfo<'info>,
+    pub swap_or_liquidity_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_id: AccountInfo<'info>,
+    pub amm_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_open_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_quantities_or_target_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_coin_tokenaccount: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_pc_tokenaccount: AccountInfo<'info>,
+    pub serum_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_market: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_bids: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_asks: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_event_queue: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_coin_vault_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_pc_vault_account: AccountInfo<'info>,
+    pub serum_vault_signer: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_wallet: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_wallet: AccountInfo<'info>,
+    pub liquidity_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub lp_mint_address: AccountInfo<'info>,
+    #[account(mut)]
+    pub lev_farm_coin_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub lev_farm_pc_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_lp_token_account: AccountInfo<'info>,
+    pub pyth_price_account: AccountInfo<'info>,
+    pub dex_program: AccountInfo<'info>,
+    #[account(mut)]
+    pub obligation_vault_address: AccountInfo<'info>,
+    pub vault_program: AccountInfo<'info>,
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+    pub vault_pda_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub lp_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_balance_account: AccountInfo<'info>,
+    pub stake_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_id: AccountInfo<'info>,
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub vault_info_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_lp_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_reward_a_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_reward_a_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_reward_b_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_reward_b_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_balance_metadata: AccountInfo<'info>,
+}
+
+/// Remaining accounts for `withdraw_chamber` instruction.
+#[derive(Accounts)]
+pub struct WithdrawChamberAccounts<'info> {
+    #[account(mut)]
+    pub user_farm: AccountInfo<'info>,
+    #[account(mut)]
+    pub obligation_vault_address: AccountInfo<'info>,
+    pub leveraged_farm: AccountInfo<'info>,
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    pub vault_program: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_balance_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_info_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_lp_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_reward_a_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_reward_a_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_reward_b_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_reward_b_token_account: AccountInfo<'info>,
+    pub vault_pda_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_lp_token_account: AccountInfo<'info>,
+    pub pool_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_id: AccountInfo<'info>,
+    pub stake_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_balance_meta: AccountInfo<'info>,
+    #[account(mut)]
+    pub lending_market_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_farm_obligation: AccountInfo<'info>,
+    pub lending_market_authority: AccountInfo<'info>,
+    pub lending_program: AccountInfo<'info>,
+    #[account(mut)]
+    pub position_info_account: AccountInfo<'info>,
+    pub liquidity_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_id: AccountInfo<'info>,
+    pub amm_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_open_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub amm_quantities_or_target_orders: AccountInfo<'info>,
+    #[account(mut)]
+    pub lp_mint_address: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_coin_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_pc_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_withdraw_queue: AccountInfo<'info>,
+    #[account(mut)]
+    pub pool_temp_lp_token_account: AccountInfo<'info>,
+    pub serum_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_market: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_coin_vault_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_pc_vault_account: AccountInfo<'info>,
+    pub serum_vault_signer: AccountInfo<'info>,
+    #[account(mut)]
+    pub lev_farm_coin_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub lev_farm_pc_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub user_obligation_account: AccountInfo<'info>,
+    pub vault_signer: AccountInfo<'info>,
+    pub swap_or_liquidity_program_id: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_bids: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_asks: AccountInfo<'info>,
+    #[account(mut)]
+    pub serum_event_queue: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_wallet: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_wallet: AccountInfo<'info>,
+    pub asset_price_account: AccountInfo<'info>,
+    pub base_price_account: AccountInfo<'info>,
+    pub quote_price_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub asset_vault: AccountInfo<'info>,
+    pub first_reserve: AccountInfo<'info>,
+    pub first_reserve_price: AccountInfo<'info>,
+    pub second_reserve: AccountInfo<'info>,
+    pub second_reserve_price: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_source_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_destination_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_source_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_destination_token_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub coin_reserve_account: AccountInfo<'info>,
+    #[account(mut)]
+    pub pc_reserve_account: AccountInfo<'info>,
+    pub lp_pyth_price_account: AccountInfo<'info>,
+}
+
+/// Remaining accounts for `get_chamber_nav`, `get_share_price` and `get_user_position` instructions.
+#[derive(Accounts)]
+pub struct ViewChamberAccounts<'info> {
+    pub leveraged_farm: AccountInfo<'info>,
+    pub user_farm: AccountInfo<'info>,
+    pub user_farm_obligation: AccountInfo<'info>,
+    pub user_farm_obligation_1: AccountInfo<'info>,
+    pub lp_pyth_price_account: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+}
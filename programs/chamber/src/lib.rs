@@ -1,10 +1,16 @@
 #![allow(clippy::result_large_err)]
 
-mod cpi;
+mod bindings;
 pub mod error;
+pub mod events;
+pub mod interface;
+pub mod math;
+pub mod oracle;
+pub mod position;
 mod processor;
 pub mod state;
 pub mod utils;
+pub mod views;
 
 use anchor_lang::prelude::*;
 use processor::*;
@@ -33,8 +39,17 @@ mod chamber {
         )
     }
 
-    pub fn create_user_account<'info>(ctx: Context<CreateUserAccount<'info>>) -> Result<()> {
-        ctx.accounts.process()
+    pub fn create_user_account<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CreateUserAccount<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn close_user_account<'info>(
+        ctx: Context<CloseUserAccount<'info>>,
+        close_shares: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(close_shares)
     }
 
     pub fn begin_deposit_chamber<'c, 'info>(
@@ -58,26 +73,290 @@ mod chamber {
         ctx.accounts.process(ctx.remaining_accounts)
     }
 
+    pub fn cancel_deposit_chamber<'info>(ctx: Context<CancelDepositChamber<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     pub fn deposit_chamber<'c, 'info>(
         ctx: Context<'_, '_, 'c, 'info, DepositChamber<'info>>,
         base_amount: u64,
         quote_amount: u64,
+        native_amount: u64,
     ) -> Result<()> {
-        ctx.accounts
-            .process(ctx.remaining_accounts, base_amount, quote_amount)
+        ctx.accounts.process(
+            ctx.remaining_accounts,
+            base_amount,
+            quote_amount,
+            native_amount,
+        )
     }
 
     pub fn withdraw_chamber<'c, 'info>(
         ctx: Context<'_, '_, 'c, 'info, WithdrawChamber<'info>>,
-        base_amount: u64,
-        quote_amount: u64,
+        shares_amount: u64,
+        output_mint: Option<Pubkey>,
+        min_base_out: u64,
+        min_quote_out: u64,
+        unwrap_native: bool,
     ) -> Result<()> {
-        ctx.accounts
-            .process(ctx.remaining_accounts, base_amount, quote_amount)
+        ctx.accounts.process(
+            ctx.remaining_accounts,
+            shares_amount,
+            output_mint,
+            min_base_out,
+            min_quote_out,
+            unwrap_native,
+        )
     }
 
     pub fn rebalance_chamber<'c, 'info>(
         ctx: Context<'_, '_, 'c, 'info, RebalanceChamber<'info>>,
+        target_obligation: u8,
+        withdraw_percent: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(ctx.remaining_accounts, target_obligation, withdraw_percent)
+    }
+
+    pub fn harvest_chamber<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, HarvestChamber<'info>>,
+        min_out: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts, min_out)
+    }
+
+    pub fn deleverage_chamber<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DeleverageChamber<'info>>,
+        obligation_index: u8,
+        min_coin_swap: u64,
+        min_pc_swap: u64,
+        withdraw_percent: u8,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            ctx.remaining_accounts,
+            obligation_index,
+            min_coin_swap,
+            min_pc_swap,
+            withdraw_percent,
+        )
+    }
+
+    pub fn releverage_chamber<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ReleverageChamber<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn migrate_chamber<'info>(ctx: Context<MigrateChamber<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn migrate_user_account<'info>(ctx: Context<MigrateUserAccount<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn update_chamber_limits<'info>(
+        ctx: Context<UpdateChamberLimits<'info>>,
+        tvl_cap: u64,
+        max_user_deposit: u64,
+        min_deposit: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(tvl_cap, max_user_deposit, min_deposit)
+    }
+
+    pub fn update_chamber_access<'info>(
+        ctx: Context<UpdateChamberAccess<'info>>,
+        is_allowlist_enabled: bool,
+        gatekeeper: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(is_allowlist_enabled, gatekeeper)
+    }
+
+    pub fn update_chamber_harvest<'info>(
+        ctx: Context<UpdateChamberHarvest<'info>>,
+        reward_mint: Pubkey,
+        swap_amm: Pubkey,
+        reward_farm: Pubkey,
+        is_reward_to_base: bool,
+        max_slippage_bps: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            reward_mint,
+            swap_amm,
+            reward_farm,
+            is_reward_to_base,
+            max_slippage_bps,
+        )
+    }
+
+    pub fn update_chamber_risk<'info>(
+        ctx: Context<UpdateChamberRisk<'info>>,
+        liquidation_threshold_bps: u64,
+        safety_margin_bps: u64,
+        target_ltv_bps: u64,
+        rebalance_drift_bps: u64,
+        max_slippage_bps: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_slippage_bps,
+        )
+    }
+
+    pub fn update_chamber_risk_bounds<'info>(
+        ctx: Context<UpdateChamberRiskBounds<'info>>,
+        min_safety_margin_bps: u64,
+        max_safety_margin_bps: u64,
+        min_target_ltv_bps: u64,
+        max_target_ltv_bps: u64,
+        max_slippage_bps: u64,
+        min_rebalance_drift_bps: u64,
+        max_rebalance_drift_bps: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_slippage_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+        )
+    }
+
+    pub fn update_chamber_roles<'info>(
+        ctx: Context<UpdateChamberRoles<'info>>,
+        strategist: Pubkey,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(strategist, guardian)
+    }
+
+    pub fn propose_config_change<'info>(
+        ctx: Context<ProposeConfigChange<'info>>,
+        leverage: u64,
+        performance_fee_bps: u64,
+        delay: i64,
+        effective_timestamp: i64,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(leverage, performance_fee_bps, delay, effective_timestamp)
+    }
+
+    pub fn execute_config_change<'info>(ctx: Context<ExecuteConfigChange<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn cancel_config_change<'info>(ctx: Context<CancelConfigChange<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn add_allowlist_entry<'info>(
+        ctx: Context<AddAllowlistEntry<'info>>,
+        user: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(user)
+    }
+
+    pub fn remove_allowlist_entry<'info>(ctx: Context<RemoveAllowlistEntry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn set_chamber_pause<'info>(
+        ctx: Context<SetChamberPause<'info>>,
+        is_paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(is_paused)
+    }
+
+    pub fn initialize_protocol_config<'info>(
+        ctx: Context<InitializeProtocolConfig<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn update_protocol_config<'info>(
+        ctx: Context<UpdateProtocolConfig<'info>>,
+        admin: Pubkey,
+        protocol_fee_bps: u64,
+        farm_programs: Vec<Pubkey>,
+        oracle_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .process(admin, protocol_fee_bps, farm_programs, oracle_programs)
+    }
+
+    pub fn set_protocol_pause<'info>(
+        ctx: Context<SetProtocolPause<'info>>,
+        is_paused: bool,
+    ) -> Result<()> {
+        ctx.accounts.process(is_paused)
+    }
+
+    pub fn add_farm_entry<'info>(ctx: Context<AddFarmEntry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn remove_farm_entry<'info>(ctx: Context<RemoveFarmEntry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn add_oracle_entry<'info>(ctx: Context<AddOracleEntry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn remove_oracle_entry<'info>(ctx: Context<RemoveOracleEntry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn create_chamber_registry_page<'info>(
+        ctx: Context<CreateChamberRegistryPage<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn sync_chamber_registry<'info>(ctx: Context<SyncChamberRegistry<'info>>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn create_chamber_lookup_table<'info>(
+        ctx: Context<CreateChamberLookupTable<'info>>,
+        recent_slot: u64,
+        lookup_table_bump: u8,
+    ) -> Result<()> {
+        ctx.accounts.process(recent_slot, lookup_table_bump)
+    }
+
+    pub fn extend_chamber_lookup_table<'info>(
+        ctx: Context<ExtendChamberLookupTable<'info>>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.process(addresses)
+    }
+
+    pub fn close_chamber<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CloseChamber<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn get_chamber_nav<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, GetChamberNav<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn get_share_price<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, GetSharePrice<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn get_user_position<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, GetUserPosition<'info>>,
     ) -> Result<()> {
         ctx.accounts.process(ctx.remaining_accounts)
     }
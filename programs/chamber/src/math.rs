@@ -0,0 +1,260 @@
+//! Module provide `Chamber` math, shared by processors and off-chain tooling.
+
+use crate::error;
+use anchor_lang::prelude::*;
+use tulipv2_sdk_common::math::{
+    common::{TryDiv, TryMul},
+    decimal::Decimal,
+    rate::Rate,
+};
+
+/// Basis points in 100%.
+pub const BPS: u64 = 10_000;
+
+/// Volatile token part(in quarters) of borrowed value.
+pub const VOLATILE_BORROW_QUARTERS: u64 = 3;
+
+/// Underlying token part(in quarters) of borrowed value.
+pub const UNDERLYING_BORROW_QUARTERS: u64 = 1;
+
+/// Calculate token `amount` value in `price`, given token `decimals`.
+pub fn get_token_value(price: &Decimal, amount: u64, decimals: u64) -> Result<Decimal> {
+    Ok(price.try_mul(amount)?.try_div(get_precision(decimals)?)?)
+}
+
+/// Calculate token amount, which has `value` in `price`, given token `decimals`.
+pub fn get_token_amount(value: &Decimal, price: &Decimal, decimals: u64) -> Result<u64> {
+    Ok(value
+        .try_mul(get_precision(decimals)?)?
+        .try_div(*price)?
+        .try_floor_u64()?)
+}
+
+/// Calculate `10^decimals`.
+fn get_precision(decimals: u64) -> Result<u64> {
+    let decimals: u32 = decimals
+        .try_into()
+        .map_err(|_| error::ChamberError::MathOverflow)?;
+
+    Ok(10u64
+        .checked_pow(decimals)
+        .ok_or(error::ChamberError::MathOverflow)?)
+}
+
+/// Calculate base and quote borrow amounts for `total_value` with `leverage`.
+///
+/// Volatile token covers 3/4 of borrowed value, while underlying token covers 1/4.
+#[allow(clippy::too_many_arguments)]
+pub fn get_borrow_amounts(
+    total_value: &Decimal,
+    base_price: &Decimal,
+    quote_price: &Decimal,
+    base_decimals: u64,
+    quote_decimals: u64,
+    leverage: u64,
+    is_base_volatile: bool,
+) -> Result<(u64, u64)> {
+    let borrow_leverage = leverage
+        .checked_sub(1)
+        .ok_or(error::ChamberError::MathOverflow)?;
+
+    let (volatile_price, volatile_decimals, underlying_price, underlying_decimals) =
+        if is_base_volatile {
+            (base_price, base_decimals, quote_price, quote_decimals)
+        } else {
+            (quote_price, quote_decimals, base_price, base_decimals)
+        };
+
+    let volatile_borrow_amount = get_token_amount(
+        &total_value
+            .try_mul(VOLATILE_BORROW_QUARTERS)?
+            .try_div(4)?
+            .try_mul(borrow_leverage)?,
+        volatile_price,
+        volatile_decimals,
+    )?;
+
+    let underlying_borrow_amount = get_token_amount(
+        &total_value
+            .try_mul(UNDERLYING_BORROW_QUARTERS)?
+            .try_div(4)?
+            .try_mul(borrow_leverage)?,
+        underlying_price,
+        underlying_decimals,
+    )?;
+
+    if is_base_volatile {
+        Ok((volatile_borrow_amount, underlying_borrow_amount))
+    } else {
+        Ok((underlying_borrow_amount, volatile_borrow_amount))
+    }
+}
+
+/// Calculate shares amount for `deposit_value`, given `Chamber` shares supply and total value.
+///
+/// Empty `Chamber` mints shares 1:1 to deposit value.
+pub fn get_shares_amount(
+    deposit_value: &Decimal,
+    shares_supply: u64,
+    total_value: u64,
+) -> Result<u64> {
+    if shares_supply == 0 || total_value == 0 {
+        return Ok(deposit_value.try_floor_u64()?);
+    }
+
+    let shares_rate =
+        Rate::try_from(Decimal::from(shares_supply).try_div(Decimal::from(total_value))?)?;
+
+    Ok(deposit_value.try_mul(shares_rate)?.try_floor_u64()?)
+}
+
+/// Calculate value of `shares_amount`, given `Chamber` shares supply and total value.
+pub fn get_shares_value(shares_amount: u64, shares_supply: u64, total_value: u64) -> Result<u64> {
+    if shares_supply == 0 {
+        return Ok(0);
+    }
+
+    Ok(Decimal::from(shares_amount)
+        .try_mul(total_value)?
+        .try_div(shares_supply)?
+        .try_floor_u64()?)
+}
+
+/// Calculate debt value to position value ratio(LTV) in basis points.
+///
+/// Position without value, but with debt, has maximum LTV.
+pub fn get_ltv_bps(debt_value: &Decimal, position_value: &Decimal) -> Result<u64> {
+    if *position_value == Decimal::zero() {
+        return Ok(if *debt_value == Decimal::zero() {
+            0
+        } else {
+            u64::MAX
+        });
+    }
+
+    Ok(debt_value
+        .try_mul(BPS)?
+        .try_div(*position_value)?
+        .try_ceil_u64()?)
+}
+
+/// Calculate LTV of position at `leverage`, i.e. `(leverage - 1) / leverage`, in basis points.
+pub fn get_leverage_ltv_bps(leverage: u64) -> Result<u64> {
+    if leverage == 0 {
+        return Err(error::ChamberError::MathOverflow.into());
+    }
+
+    Ok((leverage - 1)
+        .checked_mul(BPS)
+        .ok_or(error::ChamberError::MathOverflow)?
+        / leverage)
+}
+
+/// Calculate `debt` drift from `target_debt` in basis points of target debt.
+///
+/// Any debt without target debt has 100% drift.
+pub fn get_debt_drift_bps(debt: u64, target_debt: u64) -> Result<u64> {
+    if target_debt == 0 {
+        return Ok(if debt == 0 { 0 } else { BPS });
+    }
+
+    Ok(Decimal::from(debt.abs_diff(target_debt))
+        .try_mul(BPS)?
+        .try_div(target_debt)?
+        .try_floor_u64()?)
+}
+
+/// Calculate position part(in percents), which should be unwound and repaid
+/// to bring position LTV from `ltv_bps` down to `target_ltv_bps`.
+///
+/// Repaying part `f` of position with LTV `L` gives `(L - f) / (1 - f)` LTV,
+/// so `f = (L - T) / (1 - T)` for target LTV `T`.
+pub fn get_deleverage_percent(ltv_bps: u64, target_ltv_bps: u64) -> Result<u8> {
+    if ltv_bps <= target_ltv_bps {
+        return Ok(0);
+    }
+
+    let denominator = BPS
+        .checked_sub(target_ltv_bps)
+        .filter(|denominator| *denominator != 0)
+        .ok_or(error::ChamberError::MathOverflow)?;
+    let percent = u128::from(ltv_bps - target_ltv_bps)
+        .checked_mul(100)
+        .and_then(|numerator| numerator.checked_add(u128::from(denominator) - 1))
+        .map(|numerator| numerator / u128::from(denominator))
+        .ok_or(error::ChamberError::MathOverflow)?;
+
+    Ok(percent.min(100) as u8)
+}
+
+/// Calculate swap output of pool token, which repays `withdraw_percent` of token `debt`
+/// on top of token half of unwound `released_value`, given token `price` and `decimals`.
+///
+/// Pool releases both tokens in equal value, so only short token is swapped in.
+pub fn get_repay_swap_amount(
+    debt: u64,
+    withdraw_percent: u8,
+    released_value: &Decimal,
+    price: &Decimal,
+    decimals: u64,
+) -> Result<u64> {
+    let repay_amount = Decimal::from(debt)
+        .try_mul(u64::from(withdraw_percent))?
+        .try_div(100)?
+        .try_floor_u64()?;
+    let released_amount = get_token_amount(&released_value.try_div(2)?, price, decimals)?;
+
+    Ok(repay_amount.saturating_sub(released_amount))
+}
+
+/// Calculate position part(in percents), which should be unwound to redeem
+/// `shares_amount` out of `shares_supply`.
+///
+/// Market withdraws whole percents, so part is rounded up.
+pub fn get_withdraw_percent(shares_amount: u64, shares_supply: u64) -> Result<u8> {
+    if shares_amount == 0 || shares_amount > shares_supply {
+        return Err(error::ChamberError::InvalidWithdrawPercent.into());
+    }
+
+    let percent = u128::from(shares_amount)
+        .checked_mul(100)
+        .and_then(|numerator| numerator.checked_add(u128::from(shares_supply) - 1))
+        .map(|numerator| numerator / u128::from(shares_supply))
+        .ok_or(error::ChamberError::MathOverflow)?;
+
+    Ok(percent as u8)
+}
+
+/// Calculate part of `released_amount`, unwound with `withdraw_percent`, which
+/// belongs to `shares_amount` out of `shares_supply`.
+pub fn get_redeem_amount(
+    released_amount: u64,
+    shares_amount: u64,
+    shares_supply: u64,
+    withdraw_percent: u8,
+) -> Result<u64> {
+    if shares_supply == 0 || withdraw_percent == 0 {
+        return Err(error::ChamberError::InvalidWithdrawPercent.into());
+    }
+
+    let redeem_amount = Decimal::from(released_amount)
+        .try_mul(shares_amount)?
+        .try_mul(100)?
+        .try_div(shares_supply)?
+        .try_div(u64::from(withdraw_percent))?
+        .try_floor_u64()?;
+
+    Ok(redeem_amount.min(released_amount))
+}
+
+/// Calculate fee, charged from `amount` with `fee_bps` rate.
+pub fn get_fee_amount(amount: u64, fee_bps: u64) -> Result<u64> {
+    let fee_amount = u128::from(amount)
+        .checked_mul(fee_bps.into())
+        .and_then(|fee_amount| fee_amount.checked_div(BPS.into()))
+        .ok_or(error::ChamberError::MathOverflow)?;
+
+    Ok(fee_amount
+        .try_into()
+        .map_err(|_| error::ChamberError::MathOverflow)?)
+}
@@ -0,0 +1,60 @@
+//! Module provide validation of `Pyth` product and price accounts.
+
+use crate::error;
+use anchor_lang::prelude::*;
+
+/// `Pyth` account magic number.
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+
+/// Supported `Pyth` account layout version.
+pub const PYTH_VERSION: u32 = 2;
+
+/// `Pyth` product account type.
+pub const PYTH_PRODUCT_TYPE: u32 = 2;
+
+/// `Pyth` price account type.
+pub const PYTH_PRICE_TYPE: u32 = 3;
+
+/// Offset of price account key in `Pyth` product account.
+const PRODUCT_PRICE_OFFSET: usize = 16;
+
+/// Offset of product account key in `Pyth` price account.
+const PRICE_PRODUCT_OFFSET: usize = 112;
+
+/// Ensure, that `product` and `price` are linked `Pyth` accounts, owned by same program.
+pub fn assert_pyth_accounts(product: &AccountInfo, price: &AccountInfo) -> Result<()> {
+    if product.owner != price.owner {
+        return Err(error::ChamberError::InvalidOracle.into());
+    }
+
+    let product_price = load_linked_account(product, PYTH_PRODUCT_TYPE, PRODUCT_PRICE_OFFSET)?;
+    let price_product = load_linked_account(price, PYTH_PRICE_TYPE, PRICE_PRODUCT_OFFSET)?;
+
+    if product_price != *price.key || price_product != *product.key {
+        return Err(error::ChamberError::InvalidOracle.into());
+    }
+
+    Ok(())
+}
+
+/// Read account key at `offset` of `Pyth` account with `account_type`.
+fn load_linked_account(account: &AccountInfo, account_type: u32, offset: usize) -> Result<Pubkey> {
+    let data = account.try_borrow_data()?;
+
+    if data.len() < offset + 32
+        || read_u32(&data, 0) != PYTH_MAGIC
+        || read_u32(&data, 4) != PYTH_VERSION
+        || read_u32(&data, 8) != account_type
+    {
+        return Err(error::ChamberError::InvalidOracle.into());
+    }
+
+    Ok(Pubkey::new(&data[offset..offset + 32]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+
+    u32::from_le_bytes(bytes)
+}
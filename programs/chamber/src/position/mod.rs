@@ -0,0 +1,3 @@
+//! Module provide read-only access to underlying market positions.
+
+pub mod tulip;
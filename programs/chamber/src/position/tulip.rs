@@ -0,0 +1,212 @@
+use crate::{error, state, utils};
+use anchor_lang::prelude::*;
+use tulipv2_sdk_common::math::decimal::Decimal;
+use tulipv2_sdk_levfarm::accounts::{
+    derivations, leveraged_farm::LeveragedFarm, obligation_account::ObligationAccount,
+    user_farm::UserFarm,
+};
+
+/// Deserialize `Tulip` leveraged farm, owned by `farm_program`.
+pub fn load_leveraged_farm(farm: &AccountInfo, farm_program: &Pubkey) -> Result<LeveragedFarm> {
+    if farm.owner != farm_program {
+        return Err(error::ChamberError::InvalidFarm.into());
+    }
+
+    let data = farm.try_borrow_data()?;
+
+    LeveragedFarm::try_deserialize(&mut data.as_ref())
+        .map_err(|_| error::ChamberError::InvalidFarm.into())
+}
+
+/// Deserialize `Tulip` user farm, owned by `farm_program`.
+pub fn load_user_farm(user_farm: &AccountInfo, farm_program: &Pubkey) -> Result<UserFarm> {
+    if user_farm.owner != farm_program {
+        return Err(error::ChamberError::InvalidUserFarm.into());
+    }
+
+    let data = user_farm.try_borrow_data()?;
+
+    UserFarm::try_deserialize(&mut data.as_ref())
+        .map_err(|_| error::ChamberError::InvalidUserFarm.into())
+}
+
+/// Ensure, that `user_farm` is created by `authority` for `farm`, and
+/// `user_farm_obligations` are its obligations in index order.
+pub fn assert_user_farm_obligations(
+    user_farm: &AccountInfo,
+    user_farm_obligations: &[&AccountInfo],
+    authority: &Pubkey,
+    farm: &Pubkey,
+    farm_program: &Pubkey,
+) -> Result<()> {
+    assert_user_farm(user_farm, authority, farm, farm_program)?;
+
+    for (obligation_index, user_farm_obligation) in user_farm_obligations.iter().enumerate() {
+        assert_obligation_address(
+            user_farm,
+            user_farm_obligation,
+            obligation_index as u8,
+            authority,
+            farm_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Ensure, that `user_farm` is created by `authority` for `farm`, and
+/// `user_farm_obligation` is its `obligation_index` obligation.
+pub fn assert_user_farm_obligation(
+    user_farm: &AccountInfo,
+    user_farm_obligation: &AccountInfo,
+    obligation_index: u8,
+    authority: &Pubkey,
+    farm: &Pubkey,
+    farm_program: &Pubkey,
+) -> Result<()> {
+    assert_user_farm(user_farm, authority, farm, farm_program)?;
+    assert_obligation_address(
+        user_farm,
+        user_farm_obligation,
+        obligation_index,
+        authority,
+        farm_program,
+    )
+}
+
+fn assert_user_farm(
+    user_farm: &AccountInfo,
+    authority: &Pubkey,
+    farm: &Pubkey,
+    farm_program: &Pubkey,
+) -> Result<()> {
+    let user_farm_state = load_user_farm(user_farm, farm_program)?;
+
+    if user_farm_state.authority != *authority || user_farm_state.leveraged_farm != *farm {
+        return Err(error::ChamberError::InvalidUserFarm.into());
+    }
+
+    Ok(())
+}
+
+fn assert_obligation_address(
+    user_farm: &AccountInfo,
+    user_farm_obligation: &AccountInfo,
+    obligation_index: u8,
+    authority: &Pubkey,
+    farm_program: &Pubkey,
+) -> Result<()> {
+    let (expected_obligation, _) = derivations::derive_user_farm_obligation_address(
+        *authority,
+        user_farm.key(),
+        *farm_program,
+        obligation_index,
+    );
+
+    if user_farm_obligation.key() != expected_obligation {
+        return Err(error::ChamberError::InvalidObligation.into());
+    }
+
+    Ok(())
+}
+
+/// Ensure, that `lp_pyth_price_account` and `lp_mint` are lp price oracle and mint of `leveraged_farm`.
+pub fn assert_lp_accounts(
+    leveraged_farm: &LeveragedFarm,
+    lp_pyth_price_account: &AccountInfo,
+    lp_mint: &AccountInfo,
+) -> Result<()> {
+    if lp_pyth_price_account.key() != leveraged_farm.lp_token_price_account
+        || lp_mint.key() != leveraged_farm.raydium_lp_mint_address
+    {
+        return Err(error::ChamberError::InvalidLpAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Ensure, that `liquidity_program` is `Raydium` AMM program, and AMM and serum market
+/// accounts belong to `leveraged_farm` AMM.
+#[allow(clippy::too_many_arguments)]
+pub fn assert_amm_accounts(
+    leveraged_farm: &LeveragedFarm,
+    liquidity_program: &AccountInfo,
+    amm_id: &AccountInfo,
+    amm_open_orders: &AccountInfo,
+    amm_target_orders: &AccountInfo,
+    pool_coin_token_account: &AccountInfo,
+    pool_pc_token_account: &AccountInfo,
+    serum_market: &AccountInfo,
+    serum_coin_vault_account: &AccountInfo,
+    serum_pc_vault_account: &AccountInfo,
+) -> Result<()> {
+    if liquidity_program.key() != utils::raydium_amm_program::ID
+        || amm_id.owner != &utils::raydium_amm_program::ID
+    {
+        return Err(error::ChamberError::InvalidSwapRoute.into());
+    }
+
+    if amm_id.key() != leveraged_farm.raydium_amm_id
+        || amm_open_orders.key() != leveraged_farm.raydium_amm_open_orders
+        || amm_target_orders.key() != leveraged_farm.raydium_amm_quantities_or_target
+        || pool_coin_token_account.key() != leveraged_farm.raydium_coin_account
+        || pool_pc_token_account.key() != leveraged_farm.raydium_pc_account
+        || serum_market.key() != leveraged_farm.serum_market
+        || serum_coin_vault_account.key() != leveraged_farm.serum_coin_vault_account
+        || serum_pc_vault_account.key() != leveraged_farm.serum_pc_vault_account
+    {
+        return Err(error::ChamberError::InvalidSwapRoute.into());
+    }
+
+    Ok(())
+}
+
+/// Deserialize `Tulip` leveraged farm obligation, owned by `farm_program`.
+pub fn load_obligation(
+    user_farm_obligation: &AccountInfo,
+    farm_program: &Pubkey,
+) -> Result<ObligationAccount> {
+    if user_farm_obligation.owner != farm_program {
+        return Err(error::ChamberError::InvalidObligation.into());
+    }
+
+    let data = user_farm_obligation.try_borrow_data()?;
+
+    ObligationAccount::try_deserialize(&mut data.as_ref())
+        .map_err(|_| error::ChamberError::InvalidObligation.into())
+}
+
+/// Indicates, that obligation has no lp deposited and no outstanding debt.
+pub fn is_obligation_unwound(obligation: &ObligationAccount) -> bool {
+    obligation.lp_tokens == 0
+        && obligation.vault_shares == 0
+        && obligation.borrowed_value == Decimal::zero()
+}
+
+/// Get outstanding obligation debt for base(first borrow) and quote(second borrow) legs.
+pub fn get_obligation_debt(obligation: &ObligationAccount) -> Result<(u64, u64)> {
+    let base_debt = obligation
+        .obligation_borrow_1
+        .borrowed_amount_wads
+        .try_ceil_u64()?;
+    let quote_debt = obligation
+        .obligation_borrow_2
+        .borrowed_amount_wads
+        .try_ceil_u64()?;
+
+    Ok((base_debt, quote_debt))
+}
+
+/// Sync `chamber_obligation` with lp and debt of `Tulip` obligation.
+pub fn sync_obligation(
+    chamber_obligation: &mut state::ChamberObligation,
+    user_farm_obligation: &AccountInfo,
+    farm_program: &Pubkey,
+) -> Result<()> {
+    let obligation = load_obligation(user_farm_obligation, farm_program)?;
+    let (base_debt, quote_debt) = get_obligation_debt(&obligation)?;
+
+    chamber_obligation.update(obligation.lp_tokens, base_debt, quote_debt);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct AddAllowlistEntry<'info> {
+    #[account(constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = state::AllowlistEntry::LEN,
+        seeds = [
+            utils::ALLOWLIST_ENTRY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+            user.as_ref(),
+        ],
+        bump
+    )]
+    pub allowlist_entry: Box<Account<'info, state::AllowlistEntry>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAllowlistEntry<'info> {
+    pub fn process(&mut self, user: Pubkey) -> Result<()> {
+        // 1. Initialize `AllowlistEntry` for provided `user`
+        self.allowlist_entry.init(&self.chamber.key(), &user);
+
+        Ok(())
+    }
+}
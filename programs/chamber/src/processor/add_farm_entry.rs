@@ -0,0 +1,46 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddFarmEntry<'info> {
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == admin.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = state::FarmEntry::LEN,
+        seeds = [utils::FARM_ENTRY_PREFIX.as_bytes(), farm.key().as_ref()],
+        bump
+    )]
+    pub farm_entry: Box<Account<'info, state::FarmEntry>>,
+
+    /// CHECK: Farm, allowed for new chambers.
+    #[account(owner = farm_program.key())]
+    pub farm: UncheckedAccount<'info>,
+
+    /// CHECK: Program for `farm`, validated against `protocol_config`.
+    pub farm_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddFarmEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Ensure `farm_program` is allowed
+        self.protocol_config
+            .assert_farm_program(self.farm_program.key)?;
+
+        // 2. Initialize `FarmEntry` for provided `farm`
+        self.farm_entry.init(self.farm.key, self.farm_program.key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{oracle, state, utils};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct AddOracleEntry<'info> {
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == admin.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = state::OracleEntry::LEN,
+        seeds = [utils::ORACLE_ENTRY_PREFIX.as_bytes(), mint.key().as_ref()],
+        bump
+    )]
+    pub oracle_entry: Box<Account<'info, state::OracleEntry>>,
+
+    pub mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: `Pyth` product account for `mint`, validated against `price`.
+    pub product: UncheckedAccount<'info>,
+
+    /// CHECK: `Pyth` price account for `mint`, validated against `product`.
+    pub price: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddOracleEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Ensure, that oracle program is allowed
+        self.protocol_config
+            .assert_oracle_program(self.price.owner)?;
+
+        // 2. Ensure, that `product` and `price` are linked `Pyth` accounts
+        oracle::assert_pyth_accounts(&self.product, &self.price)?;
+
+        // 3. Initialize `OracleEntry` for provided `mint`
+        self.oracle_entry
+            .init(&self.mint.key(), self.product.key, self.price.key);
+
+        Ok(())
+    }
+}
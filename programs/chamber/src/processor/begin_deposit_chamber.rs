@@ -1,17 +1,20 @@
-use crate::{cpi, error, state, utils};
+use crate::{bindings, error, events, math, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use tulipv2_sdk_common::math::{
-    common::{TryAdd, TryDiv, TryMul},
-    decimal::Decimal,
-    rate::Rate,
-};
+use tulipv2_sdk_common::math::common::TryAdd;
 
 #[derive(Accounts)]
 #[instruction(base_amount: u64, quote_amount: u64)]
 pub struct BeginDepositChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
     #[account(
         mut,
         seeds = [
@@ -25,7 +28,10 @@ pub struct BeginDepositChamber<'info> {
     )]
     pub user_account: Box<Account<'info, state::UserAccount>>,
 
-    #[account(constraint = user_shares.mint == chamber_shares_mint.key())]
+    #[account(
+        constraint = user_shares.key() == user_account.shares,
+        constraint = user_shares.mint == chamber_shares_mint.key(),
+    )]
     pub user_shares: Box<Account<'info, token::TokenAccount>>,
 
     pub user_base_token: Box<Account<'info, token::TokenAccount>>,
@@ -63,6 +69,9 @@ pub struct BeginDepositChamber<'info> {
     #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
     pub chamber_farm_program: UncheckedAccount<'info>,
 
+    /// CHECK: `User` allowlist entry or gatekeeper signer, checked only for allowlist enabled `Chamber`.
+    pub permit: UncheckedAccount<'info>,
+
     pub user: Signer<'info>,
 
     pub rent_sysvar: Sysvar<'info, Rent>,
@@ -77,7 +86,19 @@ impl<'c, 'info> BeginDepositChamber<'info> {
         base_amount: u64,
         quote_amount: u64,
     ) -> Result<()> {
-        // 1. Deposit base token into `Chamber`
+        // 1. Ensure, that protocol and `Chamber` are not paused, and `User` is still allowed
+        self.protocol_config.assert_not_paused()?;
+        self.chamber.access.assert_not_paused()?;
+        self.chamber.access.assert_allowed(
+            &self.chamber.key(),
+            self.user.key,
+            &self.permit.to_account_info(),
+        )?;
+
+        let chamber_base_amount = self.chamber_base_token.amount;
+        let chamber_quote_amount = self.chamber_quote_token.amount;
+
+        // 2. Deposit base token into `Chamber`
         token::transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
@@ -90,7 +111,7 @@ impl<'c, 'info> BeginDepositChamber<'info> {
             base_amount,
         )?;
 
-        // 2. Deposit quote token into `Chamber`
+        // 3. Deposit quote token into `Chamber`
         token::transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
@@ -103,16 +124,16 @@ impl<'c, 'info> BeginDepositChamber<'info> {
             quote_amount,
         )?;
 
-        // 3. Ensure, that `UserAccount` in correct state
+        // 4. Ensure, that `UserAccount` in correct state
         self.user_account
             .assert_status(state::UserAccountStatus::Ready)?;
 
-        // 4. Ensure, that `User` is depositing something valuable
+        // 5. Ensure, that `User` is depositing something valuable
         if base_amount == 0 && quote_amount == 0 {
             return Err(error::ChamberError::InsufficientFunds.into());
         }
 
-        // 5. Process market specific logic
+        // 6. Process market specific logic
         match self.chamber.strategy.market {
             state::ChamberMarket::Tulip => {
                 let user_farm = &remaining_accounts[0];
@@ -134,64 +155,62 @@ impl<'c, 'info> BeginDepositChamber<'info> {
                 let vault_account = &remaining_accounts[16];
                 let position_info_account = &remaining_accounts[17];
 
-                // 6. Get base token price and decimals
+                let obligation_index = self.chamber.active_obligation;
+
+                // 7. Get base token price and decimals
                 let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_base_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 7. Calculate user base token value in `base_price`
-                let user_base_value = base_price
-                    .try_mul(base_amount)?
-                    .try_div(self.chamber.vault.base_decimals)?;
+                // 8. Calculate user base token value in `base_price`
+                let user_base_value = math::get_token_value(
+                    &base_price,
+                    base_amount,
+                    self.chamber.vault.base_decimals,
+                )?;
 
-                // 8. Get quote token price and decimals
+                // 9. Get quote token price and decimals
                 let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_quote_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 9. Calculate user quote token value in `quote_price`
-                let user_quote_value = quote_price
-                    .try_mul(quote_amount)?
-                    .try_div(self.chamber.vault.quote_decimals)?;
+                // 10. Calculate user quote token value in `quote_price`
+                let user_quote_value = math::get_token_value(
+                    &quote_price,
+                    quote_amount,
+                    self.chamber.vault.quote_decimals,
+                )?;
 
-                // 10. Calculate total user deposit value
+                // 11. Calculate total user deposit value
                 let user_total_value = user_base_value.try_add(user_quote_value)?;
 
-                // 11. Calculate base and quote borrow amount
-                let (user_base_borrow_amount, user_quote_borrow_amount) = {
-                    let (volatile_price, underlying_price) =
-                        if self.chamber.strategy.is_base_volatile {
-                            (base_price, quote_price)
-                        } else {
-                            (quote_price, base_price)
-                        };
-
-                    let volatile_borrow_amount = user_total_value
-                        .try_mul(3)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(volatile_price)?
-                        .try_floor_u64()?;
-
-                    let underlying_borrow_amount = user_total_value
-                        .try_mul(1)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(underlying_price)?
-                        .try_floor_u64()?;
-
-                    if self.chamber.strategy.is_base_volatile {
-                        (volatile_borrow_amount, underlying_borrow_amount)
-                    } else {
-                        (underlying_borrow_amount, volatile_borrow_amount)
-                    }
-                };
-
-                // 11. Deposit and borrow tokens with leverage
-                cpi::tulip::leveraged::deposit_borrow_dual(
+                // 12. Ensure, that deposit fits into `Chamber` limits
+                let chamber_total_value = self
+                    .chamber
+                    .vault
+                    .get_total_value(&base_price, &quote_price)?;
+                self.chamber.limits.assert_deposit(
+                    chamber_total_value,
+                    self.user_account.deposited_value,
+                    user_total_value.try_floor_u64()?,
+                )?;
+
+                // 13. Calculate base and quote borrow amount
+                let (user_base_borrow_amount, user_quote_borrow_amount) = math::get_borrow_amounts(
+                    &user_total_value,
+                    &base_price,
+                    &quote_price,
+                    self.chamber.vault.base_decimals,
+                    self.chamber.vault.quote_decimals,
+                    self.chamber.strategy.leverage,
+                    self.chamber.strategy.is_base_volatile,
+                )?;
+
+                // 14. Deposit and borrow tokens with leverage
+                bindings::tulip::leveraged::deposit_borrow_dual(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::DepositBorrowDual {
+                        Box::new(bindings::tulip::leveraged::DepositBorrowDual {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
@@ -244,26 +263,71 @@ impl<'c, 'info> BeginDepositChamber<'info> {
                     quote_amount,
                     user_base_borrow_amount,
                     user_quote_borrow_amount,
-                    0,
+                    obligation_index,
                 )?;
 
-                // TODO: Obtain total liquidity from underlying position
-                let chamber_total_liquidity = self
-                    .chamber
-                    .vault
-                    .get_total_value(&base_price, &quote_price)?;
+                // 15. Calculate locked tokens, left idle in `Chamber`
+                self.chamber_base_token.reload()?;
+                self.chamber_quote_token.reload()?;
+
+                let idle_base_amount = self
+                    .chamber_base_token
+                    .amount
+                    .saturating_sub(chamber_base_amount)
+                    .min(base_amount);
+                let idle_quote_amount = self
+                    .chamber_quote_token
+                    .amount
+                    .saturating_sub(chamber_quote_amount)
+                    .min(quote_amount);
+
+                // 16. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
 
-                // 12. Calculate user shares
-                let shares_rate = Rate::try_from(
-                    Decimal::from(self.chamber_shares_mint.supply)
-                        .try_div(Decimal::from(chamber_total_liquidity))?,
+                // 17. Record borrowed debt and reconcile it with market obligations
+                self.chamber
+                    .debt
+                    .record_borrow(user_base_borrow_amount, user_quote_borrow_amount)?;
+                self.chamber.reconcile_debt();
+
+                // 18. Calculate user shares
+                let user_shares = math::get_shares_amount(
+                    &user_total_value,
+                    self.chamber_shares_mint.supply,
+                    chamber_total_value,
                 )?;
-                let user_shares = user_total_value.try_mul(shares_rate)?.try_floor_u64()?;
 
-                // 13. Update `UserAccount` status, lock provided tokens
+                // 19. Update `UserAccount` status, lock provided tokens
                 // amount for next deposit stages
-                self.user_account
-                    .begin_deposit(base_amount, quote_amount, user_shares);
+                self.user_account.begin_deposit(
+                    base_amount,
+                    quote_amount,
+                    user_shares,
+                    user_total_value.try_floor_u64()?,
+                );
+                self.user_account.record_position_deposit(
+                    base_amount - idle_base_amount,
+                    quote_amount - idle_quote_amount,
+                );
+
+                // 20. Emit `DepositBegun` event
+                emit!(events::DepositBegun {
+                    chamber: self.chamber.key(),
+                    user: self.user.key(),
+                    base_amount,
+                    quote_amount,
+                    base_borrow_amount: user_base_borrow_amount,
+                    quote_borrow_amount: user_quote_borrow_amount,
+                    base_price: base_price.to_scaled_val()?,
+                    quote_price: quote_price.to_scaled_val()?,
+                    deposit_value: user_total_value.try_floor_u64()?,
+                    locked_shares_amount: user_shares,
+                    nav: chamber_total_value,
+                });
             }
         };
 
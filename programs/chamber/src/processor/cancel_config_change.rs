@@ -0,0 +1,30 @@
+use crate::{error, events, state};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(
+        mut,
+        constraint = chamber.config.owner == signer.key()
+            || chamber.roles.is_guardian(signer.key) @ error::ChamberError::Unauthorized
+    )]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// `Chamber` owner or guardian.
+    pub signer: Signer<'info>,
+}
+
+impl<'info> CancelConfigChange<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Drop pending change
+        self.chamber.timelock.cancel()?;
+
+        // 2. Emit `ConfigChangeCancelled` event
+        emit!(events::ConfigChangeCancelled {
+            chamber: self.chamber.key(),
+            signer: self.signer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{error, events, state, utils};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct CancelDepositChamber<'info> {
+    #[account(
+        constraint = chamber.roles.is_guardian(signer.key)
+            || user_account.user == signer.key() @ error::ChamberError::Unauthorized
+    )]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(
+        mut,
+        seeds = [
+            utils::USER_ACCOUNT_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+            user_account.user.as_ref(),
+        ],
+        bump,
+        constraint = user_account.chamber == chamber.key(),
+    )]
+    pub user_account: Box<Account<'info, state::UserAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_base_token.owner == user_account.user,
+        constraint = user_base_token.mint == chamber.vault.base_mint,
+    )]
+    pub user_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_quote_token.owner == user_account.user,
+        constraint = user_quote_token.mint == chamber.vault.quote_mint,
+    )]
+    pub user_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = chamber_base_token.key() == chamber.vault.base)]
+    pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = chamber_quote_token.key() == chamber.vault.quote)]
+    pub chamber_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Chamber authority PDA.
+    #[account(
+        seeds = [
+            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+        ],
+        bump,
+        constraint = chamber_authority.key() == chamber.config.authority
+    )]
+    pub chamber_authority: UncheckedAccount<'info>,
+
+    /// Depositing user or `Chamber` guardian.
+    pub signer: Signer<'info>,
+
+    pub token_program: Program<'info, token::Token>,
+}
+
+impl<'info> CancelDepositChamber<'info> {
+    /// Refund locked tokens of pending deposit, which are still idle in `Chamber`.
+    ///
+    /// Deposit, already moved into position, can not be cancelled and is
+    /// finished by `process_deposit_chamber` and `end_deposit_chamber` instead.
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Ensure, that `UserAccount` has pending deposit
+        if self.user_account.status == state::UserAccountStatus::Ready {
+            return Err(error::ChamberError::InvalidUserAccountStatus.into());
+        }
+
+        // 2. Ensure, that none of locked tokens is moved into position
+        if !self.user_account.is_cancellable() {
+            return Err(error::ChamberError::DepositInPosition.into());
+        }
+
+        let base_amount = self.user_account.idle_base_amount;
+        let quote_amount = self.user_account.idle_quote_amount;
+
+        // 3. Refund idle locked tokens to user
+        for (amount, chamber_token, user_token) in [
+            (base_amount, &self.chamber_base_token, &self.user_base_token),
+            (
+                quote_amount,
+                &self.chamber_quote_token,
+                &self.user_quote_token,
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: chamber_token.to_account_info(),
+                        to: user_token.to_account_info(),
+                        authority: self.chamber_authority.to_account_info(),
+                    },
+                    &[&[
+                        utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                        self.chamber.key().as_ref(),
+                        &[self.chamber.config.authority_bump],
+                    ]],
+                ),
+                amount,
+            )?;
+        }
+
+        // 4. Reset `UserAccount` state
+        self.user_account.cancel_deposit();
+
+        // 5. Emit `DepositCancelled` event
+        emit!(events::DepositCancelled {
+            chamber: self.chamber.key(),
+            user: self.user_account.user,
+            base_amount,
+            quote_amount,
+            cancelled_by: self.signer.key(),
+        });
+
+        Ok(())
+    }
+}
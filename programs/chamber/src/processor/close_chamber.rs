@@ -0,0 +1,199 @@
+use crate::{bindings, error, position, state, utils};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token;
+use tulipv2_sdk_levfarm::instructions::close_position_info::ClosePositionInfoAccount;
+
+#[derive(Accounts)]
+pub struct CloseChamber<'info> {
+    #[account(
+        mut,
+        close = owner,
+        constraint = chamber.config.owner == owner.key()
+    )]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(mut, constraint = chamber_base_token.key() == chamber.vault.base)]
+    pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = chamber_quote_token.key() == chamber.vault.quote)]
+    pub chamber_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
+    pub chamber_shares_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(mut, constraint = owner_base_token.mint == chamber.vault.base_mint)]
+    pub owner_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = owner_quote_token.mint == chamber.vault.quote_mint)]
+    pub owner_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Chamber authority PDA.
+    #[account(
+        mut,
+        seeds = [
+            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+        ],
+        bump,
+        constraint = chamber_authority.key() == chamber.config.authority
+    )]
+    pub chamber_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Program for `farm`.
+    #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
+    pub chamber_farm_program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, token::Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'c, 'info> CloseChamber<'info> {
+    pub fn process(&mut self, remaining_accounts: &'c [AccountInfo<'info>]) -> Result<()> {
+        // 1. Ensure, that all `Chamber` shares are burned
+        if self.chamber_shares_mint.supply != 0 {
+            return Err(error::ChamberError::SharesBalanceNotEmpty.into());
+        }
+
+        // 2. Process market specific logic
+        match self.chamber.strategy.market {
+            state::ChamberMarket::Tulip => {
+                let user_farm = &remaining_accounts[0];
+                let user_farm_obligation = &remaining_accounts[1];
+                let user_farm_obligation_1 = &remaining_accounts[2];
+                let position_info_account = &remaining_accounts[3];
+
+                // 3. Ensure, that obligations belong to `Chamber` and position is fully unwound
+                position::tulip::assert_user_farm_obligations(
+                    user_farm,
+                    &[user_farm_obligation, user_farm_obligation_1],
+                    &self.chamber_authority.key(),
+                    &self.chamber.strategy.farm,
+                    &self.chamber.strategy.farm_program,
+                )?;
+
+                for obligation_account in [user_farm_obligation, user_farm_obligation_1] {
+                    let obligation = position::tulip::load_obligation(
+                        obligation_account,
+                        &self.chamber.strategy.farm_program,
+                    )?;
+
+                    if !position::tulip::is_obligation_unwound(&obligation) {
+                        return Err(error::ChamberError::PositionNotUnwound.into());
+                    }
+                }
+
+                // 4. Close position info account
+                bindings::tulip::leveraged::close_position_info_account(
+                    CpiContext::new_with_signer(
+                        self.chamber_farm_program.to_account_info(),
+                        Box::new(ClosePositionInfoAccount {
+                            authority: self.chamber_authority.to_account_info(),
+                            position_info_account: position_info_account.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                )?;
+            }
+        };
+
+        // 5. Transfer leftover tokens to owner
+        for (chamber_token, owner_token) in [
+            (&self.chamber_base_token, &self.owner_base_token),
+            (&self.chamber_quote_token, &self.owner_quote_token),
+        ] {
+            if chamber_token.amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        token::Transfer {
+                            from: chamber_token.to_account_info(),
+                            to: owner_token.to_account_info(),
+                            authority: self.chamber_authority.to_account_info(),
+                        },
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    chamber_token.amount,
+                )?;
+            }
+        }
+
+        // 6. Close base token ata
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.chamber_base_token.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: self.chamber_authority.to_account_info(),
+            },
+            &[&[
+                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                self.chamber.key().as_ref(),
+                &[self.chamber.config.authority_bump],
+            ]],
+        ))?;
+
+        // 7. Close quote token ata
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.chamber_quote_token.to_account_info(),
+                destination: self.owner.to_account_info(),
+                authority: self.chamber_authority.to_account_info(),
+            },
+            &[&[
+                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                self.chamber.key().as_ref(),
+                &[self.chamber.config.authority_bump],
+            ]],
+        ))?;
+
+        // 8. Revoke shares mint authority, so no shares can be minted anymore
+        token::set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::SetAuthority {
+                    current_authority: self.chamber_authority.to_account_info(),
+                    account_or_mint: self.chamber_shares_mint.to_account_info(),
+                },
+                &[&[
+                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                    self.chamber.key().as_ref(),
+                    &[self.chamber.config.authority_bump],
+                ]],
+            ),
+            token::spl_token::instruction::AuthorityType::MintTokens,
+            None,
+        )?;
+
+        // 9. Reclaim lamports, funded into authority on initialization
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.chamber_authority.to_account_info(),
+                    to: self.owner.to_account_info(),
+                },
+                &[&[
+                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                    self.chamber.key().as_ref(),
+                    &[self.chamber.config.authority_bump],
+                ]],
+            ),
+            self.chamber_authority.lamports(),
+        )?;
+
+        // 10. `Chamber` rent is reclaimed by `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{error, state, utils};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[instruction(close_shares: bool)]
+pub struct CloseUserAccount<'info> {
+    /// Chamber associated with `UserAccount`.
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            utils::USER_ACCOUNT_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump,
+        constraint = user_account.chamber == chamber.key(),
+        constraint = user_account.user == user.key(),
+        constraint = user_account.shares == user_shares.key(),
+    )]
+    pub user_account: Box<Account<'info, state::UserAccount>>,
+
+    #[account(mut, constraint = user_shares.mint == chamber.config.shares_mint)]
+    pub user_shares: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, token::Token>,
+}
+
+impl<'info> CloseUserAccount<'info> {
+    pub fn process(&mut self, close_shares: bool) -> Result<()> {
+        // 1. Ensure, that `UserAccount` in correct state
+        self.user_account
+            .assert_status(state::UserAccountStatus::Ready)?;
+
+        // 2. Ensure, that `User` has no shares left in `Chamber`
+        if self.user_shares.amount != 0 {
+            return Err(error::ChamberError::SharesBalanceNotEmpty.into());
+        }
+
+        // 3. Close user shares token ata, if requested
+        if close_shares {
+            token::close_account(CpiContext::new(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: self.user_shares.to_account_info(),
+                    destination: self.user.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ))?;
+        }
+
+        // 4. `UserAccount` rent is reclaimed by `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{bindings, error, state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(recent_slot: u64, lookup_table_bump: u8)]
+pub struct CreateChamberLookupTable<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// CHECK: Uninitialized lookup table, derived from `chamber_authority` and `recent_slot`.
+    #[account(
+        mut,
+        seeds = [
+            chamber_authority.key().as_ref(),
+            recent_slot.to_le_bytes().as_ref(),
+        ],
+        bump = lookup_table_bump,
+        seeds::program = utils::address_lookup_table_program::id(),
+    )]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: Chamber authority PDA.
+    #[account(
+        seeds = [
+            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+        ],
+        bump,
+        constraint = chamber_authority.key() == chamber.config.authority
+    )]
+    pub chamber_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Address Lookup Table program.
+    #[account(address = utils::address_lookup_table_program::id())]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChamberLookupTable<'info> {
+    pub fn process(&mut self, recent_slot: u64, lookup_table_bump: u8) -> Result<()> {
+        // 1. Ensure, that `Chamber` has no lookup table yet
+        if self.chamber.config.has_lookup_table() {
+            return Err(error::ChamberError::LookupTableAlreadyCreated.into());
+        }
+
+        // 2. Create lookup table, owned by `Chamber` authority
+        bindings::lookup_table::create_lookup_table(
+            CpiContext::new_with_signer(
+                self.address_lookup_table_program.to_account_info(),
+                Box::new(bindings::lookup_table::CreateLookupTable {
+                    lookup_table: self.lookup_table.to_account_info(),
+                    authority: self.chamber_authority.to_account_info(),
+                    payer: self.payer.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                }),
+                &[&[
+                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                    self.chamber.key().as_ref(),
+                    &[self.chamber.config.authority_bump],
+                ]],
+            ),
+            recent_slot,
+            lookup_table_bump,
+        )?;
+
+        // 3. Record lookup table address, so clients can build versioned transactions
+        self.chamber.config.lookup_table = self.lookup_table.key();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{error, state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateChamberRegistryPage<'info> {
+    #[account(
+        mut,
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = state::ChamberRegistry::LEN,
+        seeds = [
+            utils::CHAMBER_REGISTRY_PREFIX.as_bytes(),
+            protocol_config.registry_pages.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub chamber_registry: Box<Account<'info, state::ChamberRegistry>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateChamberRegistryPage<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Ensure, that all existing pages are full
+        if !self.protocol_config.is_registry_full() {
+            return Err(error::ChamberError::ChamberRegistryNotFull.into());
+        }
+
+        // 2. Initialize next `ChamberRegistry` page
+        self.chamber_registry
+            .init(self.protocol_config.registry_pages);
+
+        self.protocol_config.registry_pages = self
+            .protocol_config
+            .registry_pages
+            .checked_add(1)
+            .ok_or(error::ChamberError::MathOverflow)?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{state, utils};
+use crate::{error, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token, token};
 
@@ -36,9 +36,20 @@ pub struct CreateUserAccount<'info> {
     pub system_program: Program<'info, System>,
 }
 
-impl<'info> CreateUserAccount<'info> {
-    pub fn process(&mut self) -> Result<()> {
-        // 1. Create user shares token ata
+impl<'c, 'info> CreateUserAccount<'info> {
+    pub fn process(&mut self, remaining_accounts: &'c [AccountInfo<'info>]) -> Result<()> {
+        // 1. Ensure, that `User` is allowed to use allowlist enabled `Chamber`
+        if self.chamber.access.is_allowlist_enabled {
+            let permit = remaining_accounts
+                .first()
+                .ok_or(error::ChamberError::UserNotAllowed)?;
+
+            self.chamber
+                .access
+                .assert_allowed(&self.chamber.key(), self.user.key, permit)?;
+        }
+
+        // 2. Create user shares token ata
         associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
             associated_token::Create {
@@ -52,7 +63,7 @@ impl<'info> CreateUserAccount<'info> {
             },
         ))?;
 
-        // 2. Initialize `UserAccount` position for provided `Chamber`
+        // 3. Initialize `UserAccount` position for provided `Chamber`
         self.user_account
             .init(&self.chamber.key(), self.user.key, self.user_shares.key);
 
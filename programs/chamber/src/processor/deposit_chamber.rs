@@ -1,17 +1,20 @@
-use crate::{cpi, error, state, utils};
+use crate::{bindings, error, events, math, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use tulipv2_sdk_common::math::{
-    common::{TryAdd, TryDiv, TryMul},
-    decimal::Decimal,
-    rate::Rate,
-};
+use tulipv2_sdk_common::math::common::TryAdd;
 
 #[derive(Accounts)]
-#[instruction(base_amount: u64, quote_amount: u64)]
+#[instruction(base_amount: u64, quote_amount: u64, native_amount: u64)]
 pub struct DepositChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
     #[account(
         mut,
         seeds = [
@@ -25,7 +28,10 @@ pub struct DepositChamber<'info> {
     )]
     pub user_account: Box<Account<'info, state::UserAccount>>,
 
-    #[account(constraint = user_shares.mint == chamber_shares_mint.key())]
+    #[account(
+        constraint = user_shares.key() == user_account.shares,
+        constraint = user_shares.mint == chamber_shares_mint.key(),
+    )]
     pub user_shares: Box<Account<'info, token::TokenAccount>>,
 
     pub user_base_token: Box<Account<'info, token::TokenAccount>>,
@@ -63,6 +69,25 @@ pub struct DepositChamber<'info> {
     #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
     pub chamber_farm_program: UncheckedAccount<'info>,
 
+    /// CHECK: Uninitialized temporary native token account, used to wrap `native_amount` lamports.
+    #[account(
+        mut,
+        seeds = [
+            utils::NATIVE_TOKEN_PREFIX.as_bytes(),
+            user_account.key().as_ref(),
+        ],
+        bump
+    )]
+    pub native_token: UncheckedAccount<'info>,
+
+    /// CHECK: Native token(wrapped SOL) mint.
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: UncheckedAccount<'info>,
+
+    /// CHECK: `User` allowlist entry or gatekeeper signer, checked only for allowlist enabled `Chamber`.
+    pub permit: UncheckedAccount<'info>,
+
+    #[account(mut)]
     pub user: Signer<'info>,
 
     pub clock_sysvar: Sysvar<'info, Clock>,
@@ -77,8 +102,18 @@ impl<'c, 'info> DepositChamber<'info> {
         remaining_accounts: &'c [AccountInfo<'info>],
         base_amount: u64,
         quote_amount: u64,
+        native_amount: u64,
     ) -> Result<()> {
-        // 1. Deposit base token into `Chamber`
+        // 1. Ensure, that protocol and `Chamber` are not paused, and `User` is still allowed
+        self.protocol_config.assert_not_paused()?;
+        self.chamber.access.assert_not_paused()?;
+        self.chamber.access.assert_allowed(
+            &self.chamber.key(),
+            self.user.key,
+            &self.permit.to_account_info(),
+        )?;
+
+        // 2. Deposit base token into `Chamber`
         token::transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
@@ -91,7 +126,7 @@ impl<'c, 'info> DepositChamber<'info> {
             base_amount,
         )?;
 
-        // 2. Deposit quote token into `Chamber`
+        // 3. Deposit quote token into `Chamber`
         token::transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
@@ -104,16 +139,108 @@ impl<'c, 'info> DepositChamber<'info> {
             quote_amount,
         )?;
 
-        // 3. Ensure, that `UserAccount` in correct state
+        // 4. Wrap native lamports and deposit them into `Chamber`
+        let (base_amount, quote_amount) = if native_amount > 0 {
+            let (is_base_native, chamber_native_token) =
+                if self.chamber.vault.base_mint == token::spl_token::native_mint::ID {
+                    (true, self.chamber_base_token.to_account_info())
+                } else if self.chamber.vault.quote_mint == token::spl_token::native_mint::ID {
+                    (false, self.chamber_quote_token.to_account_info())
+                } else {
+                    return Err(error::ChamberError::NativeTokenNotSupported.into());
+                };
+
+            let (_, native_token_bump) =
+                utils::derive_native_token_address(&self.user_account.key());
+
+            utils::create_pda_account(
+                &self.native_token.to_account_info(),
+                &self.user.to_account_info(),
+                &self.system_program.to_account_info(),
+                self.rent_sysvar
+                    .minimum_balance(token::TokenAccount::LEN)
+                    .checked_add(native_amount)
+                    .ok_or(error::ChamberError::MathOverflow)?,
+                token::TokenAccount::LEN as u64,
+                &token::ID,
+                &[
+                    utils::NATIVE_TOKEN_PREFIX.as_bytes(),
+                    self.user_account.key().as_ref(),
+                    &[native_token_bump],
+                ],
+            )?;
+
+            token::initialize_account(CpiContext::new(
+                self.token_program.to_account_info(),
+                token::InitializeAccount {
+                    account: self.native_token.to_account_info(),
+                    mint: self.native_mint.to_account_info(),
+                    authority: self.chamber_authority.to_account_info(),
+                    rent: self.rent_sysvar.to_account_info(),
+                },
+            ))?;
+
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.native_token.to_account_info(),
+                        to: chamber_native_token,
+                        authority: self.chamber_authority.to_account_info(),
+                    },
+                    &[&[
+                        utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                        self.chamber.key().as_ref(),
+                        &[self.chamber.config.authority_bump],
+                    ]],
+                ),
+                native_amount,
+            )?;
+
+            // Return temporary account rent back to user
+            token::close_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: self.native_token.to_account_info(),
+                    destination: self.user.to_account_info(),
+                    authority: self.chamber_authority.to_account_info(),
+                },
+                &[&[
+                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                    self.chamber.key().as_ref(),
+                    &[self.chamber.config.authority_bump],
+                ]],
+            ))?;
+
+            if is_base_native {
+                (
+                    base_amount
+                        .checked_add(native_amount)
+                        .ok_or(error::ChamberError::MathOverflow)?,
+                    quote_amount,
+                )
+            } else {
+                (
+                    base_amount,
+                    quote_amount
+                        .checked_add(native_amount)
+                        .ok_or(error::ChamberError::MathOverflow)?,
+                )
+            }
+        } else {
+            (base_amount, quote_amount)
+        };
+
+        // 5. Ensure, that `UserAccount` in correct state
         self.user_account
             .assert_status(state::UserAccountStatus::Ready)?;
 
-        // 4. Ensure, that `User` is depositing something valuable
+        // 6. Ensure, that `User` is depositing something valuable
         if base_amount == 0 && quote_amount == 0 {
             return Err(error::ChamberError::InsufficientFunds.into());
         }
 
-        // 5. Process market specific logic
+        // 7. Process market specific logic
         match self.chamber.strategy.market {
             state::ChamberMarket::Tulip => {
                 let user_farm = &remaining_accounts[0];
@@ -177,64 +304,62 @@ impl<'c, 'info> DepositChamber<'info> {
                 let pool_reward_b_token_account = &remaining_accounts[58];
                 let user_balance_metadata = &remaining_accounts[59];
 
-                // 6. Get base token price and decimals
+                let obligation_index = self.chamber.active_obligation;
+
+                // 8. Get base token price and decimals
                 let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_base_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 7. Calculate user base token value in `base_price`
-                let user_base_value = base_price
-                    .try_mul(base_amount)?
-                    .try_div(self.chamber.vault.base_decimals)?;
+                // 9. Calculate user base token value in `base_price`
+                let user_base_value = math::get_token_value(
+                    &base_price,
+                    base_amount,
+                    self.chamber.vault.base_decimals,
+                )?;
 
-                // 8. Get quote token price and decimals
+                // 10. Get quote token price and decimals
                 let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_quote_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 9. Calculate user quote token value in `quote_price`
-                let user_quote_value = quote_price
-                    .try_mul(quote_amount)?
-                    .try_div(self.chamber.vault.quote_decimals)?;
+                // 11. Calculate user quote token value in `quote_price`
+                let user_quote_value = math::get_token_value(
+                    &quote_price,
+                    quote_amount,
+                    self.chamber.vault.quote_decimals,
+                )?;
 
-                // 10. Calculate total user deposit value
+                // 12. Calculate total user deposit value
                 let user_total_value = user_base_value.try_add(user_quote_value)?;
 
-                // 11. Calculate base and quote borrow amount
-                let (user_base_borrow_amount, user_quote_borrow_amount) = {
-                    let (volatile_price, underlying_price) =
-                        if self.chamber.strategy.is_base_volatile {
-                            (base_price, quote_price)
-                        } else {
-                            (quote_price, base_price)
-                        };
-
-                    let volatile_borrow_amount = user_total_value
-                        .try_mul(3)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(volatile_price)?
-                        .try_floor_u64()?;
-
-                    let underlying_borrow_amount = user_total_value
-                        .try_mul(1)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(underlying_price)?
-                        .try_floor_u64()?;
-
-                    if self.chamber.strategy.is_base_volatile {
-                        (volatile_borrow_amount, underlying_borrow_amount)
-                    } else {
-                        (underlying_borrow_amount, volatile_borrow_amount)
-                    }
-                };
+                // 13. Ensure, that deposit fits into `Chamber` limits
+                let chamber_total_value = self
+                    .chamber
+                    .vault
+                    .get_total_value(&base_price, &quote_price)?;
+                self.chamber.limits.assert_deposit(
+                    chamber_total_value,
+                    self.user_account.deposited_value,
+                    user_total_value.try_floor_u64()?,
+                )?;
 
-                // 12. Deposit and borrow tokens with leverage
-                cpi::tulip::leveraged::deposit_borrow_dual(
+                // 14. Calculate base and quote borrow amount
+                let (user_base_borrow_amount, user_quote_borrow_amount) = math::get_borrow_amounts(
+                    &user_total_value,
+                    &base_price,
+                    &quote_price,
+                    self.chamber.vault.base_decimals,
+                    self.chamber.vault.quote_decimals,
+                    self.chamber.strategy.leverage,
+                    self.chamber.strategy.is_base_volatile,
+                )?;
+
+                // 15. Deposit and borrow tokens with leverage
+                bindings::tulip::leveraged::deposit_borrow_dual(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::DepositBorrowDual {
+                        Box::new(bindings::tulip::leveraged::DepositBorrowDual {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
@@ -287,27 +412,21 @@ impl<'c, 'info> DepositChamber<'info> {
                     quote_amount,
                     user_base_borrow_amount,
                     user_quote_borrow_amount,
-                    0,
+                    obligation_index,
                 )?;
 
-                // TODO: Obtain total liquidity from underlying position
-                let chamber_total_liquidity = self
-                    .chamber
-                    .vault
-                    .get_total_value(&base_price, &quote_price)?;
-
-                // 13. Calculate user shares
-                let shares_rate = Rate::try_from(
-                    Decimal::from(self.chamber_shares_mint.supply)
-                        .try_div(Decimal::from(chamber_total_liquidity))?,
+                // 16. Calculate user shares
+                let user_shares = math::get_shares_amount(
+                    &user_total_value,
+                    self.chamber_shares_mint.supply,
+                    chamber_total_value,
                 )?;
-                let user_shares = user_total_value.try_mul(shares_rate)?.try_floor_u64()?;
 
-                // 14. Swap tokens via AMM
-                cpi::tulip::leveraged::raydium::swap_tokens_raydium_stats(
+                // 17. Swap tokens via AMM
+                bindings::tulip::leveraged::raydium::swap_tokens_raydium_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::RaydiumSwap {
+                        Box::new(bindings::tulip::leveraged::raydium::RaydiumSwap {
                             authority: self.chamber_authority.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
                             user_farm: user_farm.to_account_info(),
@@ -344,14 +463,14 @@ impl<'c, 'info> DepositChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
                 )?;
 
-                // 15. Deposit tokens into lp
-                cpi::tulip::leveraged::add_liquidity_stats(
+                // 18. Deposit tokens into lp
+                bindings::tulip::leveraged::add_liquidity_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::AddLiquidity {
+                        Box::new(bindings::tulip::leveraged::AddLiquidity {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
@@ -386,14 +505,14 @@ impl<'c, 'info> DepositChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
                 )?;
 
-                // 16. Deposit lp tokens into tulip vault
-                cpi::tulip::leveraged::raydium::deposit_raydium_vault(
+                // 19. Deposit lp tokens into tulip vault
+                bindings::tulip::leveraged::raydium::deposit_raydium_vault(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::DepositFarm {
+                        Box::new(bindings::tulip::leveraged::raydium::DepositFarm {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             obligation_vault_address: obligation_vault_address.to_account_info(),
@@ -435,10 +554,23 @@ impl<'c, 'info> DepositChamber<'info> {
                     ),
                     0,
                     0,
-                    0,
+                    u64::from(obligation_index),
                 )?;
 
-                // 17. Mint shares to user
+                // 20. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
+
+                // 21. Record borrowed debt and reconcile it with market obligations
+                self.chamber
+                    .debt
+                    .record_borrow(user_base_borrow_amount, user_quote_borrow_amount)?;
+                self.chamber.reconcile_debt();
+
+                // 22. Mint shares to user
                 token::mint_to(
                     CpiContext::new_with_signer(
                         self.token_program.to_account_info(),
@@ -456,8 +588,29 @@ impl<'c, 'info> DepositChamber<'info> {
                     user_shares,
                 )?;
 
-                // 18. Update `Chamber` state
+                // 23. Update `Chamber` and `UserAccount` state
                 self.chamber.vault.deposit(base_amount, quote_amount)?;
+                self.user_account
+                    .record_deposit(user_total_value.try_floor_u64()?)?;
+
+                // 24. Emit `Deposited` event
+                emit!(events::Deposited {
+                    chamber: self.chamber.key(),
+                    user: self.user.key(),
+                    base_amount,
+                    quote_amount,
+                    base_borrow_amount: user_base_borrow_amount,
+                    quote_borrow_amount: user_quote_borrow_amount,
+                    base_price: base_price.to_scaled_val()?,
+                    quote_price: quote_price.to_scaled_val()?,
+                    deposit_value: user_total_value.try_floor_u64()?,
+                    shares_minted: user_shares,
+                    nav_before: chamber_total_value,
+                    nav_after: self
+                        .chamber
+                        .vault
+                        .get_total_value(&base_price, &quote_price)?,
+                });
             }
         };
 
@@ -1,9 +1,10 @@
-use crate::{cpi, state, utils};
+use crate::{bindings, events, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
 pub struct EndDepositChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
     #[account(
@@ -23,6 +24,14 @@ pub struct EndDepositChamber<'info> {
     #[account(mut, constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
     pub chamber_shares_mint: Box<Account<'info, token::Mint>>,
 
+    /// CHECK: Pyth oracle for tracking base token price.
+    #[account(constraint = chamber_base_oracle.key() == chamber.vault.base_oracle)]
+    pub chamber_base_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth oracle for tracking quote token price.
+    #[account(constraint = chamber_quote_oracle.key() == chamber.vault.quote_oracle)]
+    pub chamber_quote_oracle: UncheckedAccount<'info>,
+
     /// CHECK: Chamber authority PDA.
     #[account(
         seeds = [
@@ -41,7 +50,8 @@ pub struct EndDepositChamber<'info> {
     #[account(mut, constraint = user_shares.key() == user_account.shares)]
     pub user_shares: Box<Account<'info, token::TokenAccount>>,
 
-    pub user: Signer<'info>,
+    /// CHECK: Depositing user, so pending deposit stage may be finished by any keeper.
+    pub user: UncheckedAccount<'info>,
 
     pub clock_sysvar: Sysvar<'info, Clock>,
     pub rent_sysvar: Sysvar<'info, Rent>,
@@ -82,11 +92,13 @@ impl<'c, 'info> EndDepositChamber<'info> {
                 let lending_market_authority = &remaining_accounts[21];
                 let lending_program = &remaining_accounts[22];
 
+                let obligation_index = self.chamber.active_obligation;
+
                 // 3. Deposit lp tokens into tulip vault
-                cpi::tulip::leveraged::raydium::deposit_raydium_vault(
+                bindings::tulip::leveraged::raydium::deposit_raydium_vault(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::DepositFarm {
+                        Box::new(bindings::tulip::leveraged::raydium::DepositFarm {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             obligation_vault_address: obligation_vault_address.to_account_info(),
@@ -128,10 +140,20 @@ impl<'c, 'info> EndDepositChamber<'info> {
                     ),
                     0,
                     0,
-                    0,
+                    u64::from(obligation_index),
+                )?;
+
+                // 4. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
                 )?;
 
-                // 4. Mint shares to user
+                // 5. Reconcile `Chamber` debt with market obligations
+                self.chamber.reconcile_debt();
+
+                // 6. Mint shares to user
                 token::mint_to(
                     CpiContext::new_with_signer(
                         self.token_program.to_account_info(),
@@ -149,14 +171,42 @@ impl<'c, 'info> EndDepositChamber<'info> {
                     self.user_account.locked_shares_amount,
                 )?;
 
-                // 5. Update `Chamber` state
+                // 7. Update `Chamber` state
+                let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                    &self.chamber_base_oracle.data.as_ref().borrow(),
+                )?;
+                let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                    &self.chamber_quote_oracle.data.as_ref().borrow(),
+                )?;
+
+                let nav_before = self
+                    .chamber
+                    .vault
+                    .get_total_value(&base_price, &quote_price)?;
+
                 self.chamber.vault.deposit(
                     self.user_account.locked_base_amount,
                     self.user_account.locked_quote_amount,
                 )?;
 
-                // 6. Update `UserAccount` state
-                self.user_account.end_deposit();
+                // 8. Emit `DepositEnded` event
+                emit!(events::DepositEnded {
+                    chamber: self.chamber.key(),
+                    user: self.user.key(),
+                    base_amount: self.user_account.locked_base_amount,
+                    quote_amount: self.user_account.locked_quote_amount,
+                    shares_minted: self.user_account.locked_shares_amount,
+                    base_price: base_price.to_scaled_val()?,
+                    quote_price: quote_price.to_scaled_val()?,
+                    nav_before,
+                    nav_after: self
+                        .chamber
+                        .vault
+                        .get_total_value(&base_price, &quote_price)?,
+                });
+
+                // 9. Update `UserAccount` state
+                self.user_account.end_deposit()?;
             }
         };
 
@@ -0,0 +1,37 @@
+use crate::{events, state};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(mut)]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// Any keeper, applying config change past its effective timestamp.
+    pub cranker: Signer<'info>,
+
+    pub clock_sysvar: Sysvar<'info, Clock>,
+}
+
+impl<'info> ExecuteConfigChange<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Take pending change, which is effective
+        let pending = self
+            .chamber
+            .timelock
+            .execute(self.clock_sysvar.unix_timestamp)?;
+
+        // 2. Apply `Chamber` leverage and performance fee
+        self.chamber.strategy.leverage = pending.leverage;
+        self.chamber.harvest.performance_fee_bps = pending.performance_fee_bps;
+
+        // 3. Emit `ConfigChangeExecuted` event
+        emit!(events::ConfigChangeExecuted {
+            chamber: self.chamber.key(),
+            leverage: pending.leverage,
+            performance_fee_bps: pending.performance_fee_bps,
+            delay: pending.delay,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{bindings, error, state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(addresses: Vec<Pubkey>)]
+pub struct ExtendChamberLookupTable<'info> {
+    #[account(constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// CHECK: `Chamber` lookup table.
+    #[account(mut, constraint = lookup_table.key() == chamber.config.lookup_table)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// CHECK: Chamber authority PDA.
+    #[account(
+        seeds = [
+            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+        ],
+        bump,
+        constraint = chamber_authority.key() == chamber.config.authority
+    )]
+    pub chamber_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Address Lookup Table program.
+    #[account(address = utils::address_lookup_table_program::id())]
+    pub address_lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExtendChamberLookupTable<'info> {
+    pub fn process(&mut self, addresses: Vec<Pubkey>) -> Result<()> {
+        // 1. Ensure, that addresses fit into single transaction
+        if addresses.is_empty() || addresses.len() > utils::MAX_LOOKUP_TABLE_EXTEND_ADDRESSES {
+            return Err(error::ChamberError::LookupTableAddressesExceeded.into());
+        }
+
+        // 2. Append addresses to lookup table
+        bindings::lookup_table::extend_lookup_table(
+            CpiContext::new_with_signer(
+                self.address_lookup_table_program.to_account_info(),
+                Box::new(bindings::lookup_table::ExtendLookupTable {
+                    lookup_table: self.lookup_table.to_account_info(),
+                    authority: self.chamber_authority.to_account_info(),
+                    payer: self.payer.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                }),
+                &[&[
+                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                    self.chamber.key().as_ref(),
+                    &[self.chamber.config.authority_bump],
+                ]],
+            ),
+            &addresses,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{state, views};
+use anchor_lang::{prelude::*, solana_program::program};
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct GetChamberNav<'info> {
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(constraint = chamber_base_token.key() == chamber.vault.base)]
+    pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(constraint = chamber_quote_token.key() == chamber.vault.quote)]
+    pub chamber_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
+    pub chamber_shares_mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: Pyth oracle for tracking base token price.
+    #[account(constraint = chamber_base_oracle.key() == chamber.vault.base_oracle)]
+    pub chamber_base_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth oracle for tracking quote token price.
+    #[account(constraint = chamber_quote_oracle.key() == chamber.vault.quote_oracle)]
+    pub chamber_quote_oracle: UncheckedAccount<'info>,
+}
+
+impl<'c, 'info> GetChamberNav<'info> {
+    pub fn process(&mut self, remaining_accounts: &'c [AccountInfo<'info>]) -> Result<()> {
+        // 1. Calculate `Chamber` net asset value
+        let nav = views::ChamberNav::load(
+            &self.chamber,
+            &self.chamber_base_token,
+            &self.chamber_quote_token,
+            &self.chamber_shares_mint,
+            &self.chamber_base_oracle,
+            &self.chamber_quote_oracle,
+            remaining_accounts,
+        )?;
+
+        // 2. Return `views::ChamberNav`
+        program::set_return_data(&nav.try_to_vec()?);
+
+        Ok(())
+    }
+}
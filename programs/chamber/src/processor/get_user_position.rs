@@ -0,0 +1,63 @@
+use crate::{state, utils, views};
+use anchor_lang::{prelude::*, solana_program::program};
+use anchor_spl::token;
+
+#[derive(Accounts)]
+pub struct GetUserPosition<'info> {
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(constraint = chamber_base_token.key() == chamber.vault.base)]
+    pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(constraint = chamber_quote_token.key() == chamber.vault.quote)]
+    pub chamber_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
+    pub chamber_shares_mint: Box<Account<'info, token::Mint>>,
+
+    /// CHECK: Pyth oracle for tracking base token price.
+    #[account(constraint = chamber_base_oracle.key() == chamber.vault.base_oracle)]
+    pub chamber_base_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth oracle for tracking quote token price.
+    #[account(constraint = chamber_quote_oracle.key() == chamber.vault.quote_oracle)]
+    pub chamber_quote_oracle: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            utils::USER_ACCOUNT_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+            user_account.user.as_ref(),
+        ],
+        bump,
+        constraint = user_account.chamber == chamber.key(),
+    )]
+    pub user_account: Box<Account<'info, state::UserAccount>>,
+
+    #[account(constraint = user_shares.key() == user_account.shares)]
+    pub user_shares: Box<Account<'info, token::TokenAccount>>,
+}
+
+impl<'c, 'info> GetUserPosition<'info> {
+    pub fn process(&mut self, remaining_accounts: &'c [AccountInfo<'info>]) -> Result<()> {
+        // 1. Calculate `Chamber` net asset value
+        let nav = views::ChamberNav::load(
+            &self.chamber,
+            &self.chamber_base_token,
+            &self.chamber_quote_token,
+            &self.chamber_shares_mint,
+            &self.chamber_base_oracle,
+            &self.chamber_quote_oracle,
+            remaining_accounts,
+        )?;
+
+        // 2. Calculate user part of `Chamber` position
+        let user_position =
+            views::UserPosition::new(&nav, &self.user_account, self.user_shares.amount)?;
+
+        // 3. Return `views::UserPosition`
+        program::set_return_data(&user_position.try_to_vec()?);
+
+        Ok(())
+    }
+}
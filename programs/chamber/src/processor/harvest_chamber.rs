@@ -0,0 +1,573 @@
+use crate::{bindings, error, events, math, position, state, utils};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+#[derive(Accounts)]
+#[instruction(min_out: u64)]
+pub struct HarvestChamber<'info> {
+    #[account(mut)]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        mut,
+        constraint = chamber_reward_token.mint == chamber.harvest.reward_mint,
+        constraint = chamber_reward_token.owner == chamber.config.authority,
+    )]
+    pub chamber_reward_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(constraint = reward_mint.key() == chamber.harvest.reward_mint)]
+    pub reward_mint: Box<Account<'info, token::Mint>>,
+
+    #[account(
+        seeds = [utils::ORACLE_ENTRY_PREFIX.as_bytes(), reward_mint.key().as_ref()],
+        bump
+    )]
+    pub reward_oracle_entry: Box<Account<'info, state::OracleEntry>>,
+
+    /// CHECK: Pyth oracle for tracking reward token price.
+    #[account(
+        constraint = reward_oracle.key() == reward_oracle_entry.price @ error::ChamberError::InvalidOracle
+    )]
+    pub reward_oracle: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = chamber_base_token.key() == chamber.vault.base)]
+    pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = chamber_quote_token.key() == chamber.vault.quote)]
+    pub chamber_quote_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = fee_manager_token.owner == chamber.config.fee_manager)]
+    pub fee_manager_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = protocol_fee_token.owner == protocol_config.admin)]
+    pub protocol_fee_token: Box<Account<'info, token::TokenAccount>>,
+
+    /// CHECK: Pyth oracle for tracking base token price.
+    #[account(constraint = chamber_base_oracle.key() == chamber.vault.base_oracle)]
+    pub chamber_base_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Pyth oracle for tracking quote token price.
+    #[account(constraint = chamber_quote_oracle.key() == chamber.vault.quote_oracle)]
+    pub chamber_quote_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Chamber authority PDA.
+    #[account(
+        seeds = [
+            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+        ],
+        bump,
+        constraint = chamber_authority.key() == chamber.config.authority
+    )]
+    pub chamber_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Program for `farm`.
+    #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
+    pub chamber_farm_program: UncheckedAccount<'info>,
+
+    /// CHECK: Raydium AMM program, which owns reward swap AMM.
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// Any keeper, harvesting `Chamber` rewards.
+    pub cranker: Signer<'info>,
+
+    pub clock_sysvar: Sysvar<'info, Clock>,
+    pub rent_sysvar: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, token::Token>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'c, 'info> HarvestChamber<'info> {
+    pub fn process(
+        &mut self,
+        remaining_accounts: &'c [AccountInfo<'info>],
+        min_out: u64,
+    ) -> Result<()> {
+        // 1. Ensure, that protocol and `Chamber` are not paused
+        self.protocol_config.assert_not_paused()?;
+        self.chamber.access.assert_not_paused()?;
+
+        // 2. Ensure, that `Chamber` harvesting is configured
+        if !self.chamber.harvest.is_enabled() {
+            return Err(error::ChamberError::HarvestNotConfigured.into());
+        }
+
+        // 3. Ensure, that fee manager and protocol receive harvest output token
+        let (output_token, output_mint) = if self.chamber.harvest.is_reward_to_base {
+            (
+                self.chamber_base_token.to_account_info(),
+                self.chamber.vault.base_mint,
+            )
+        } else {
+            (
+                self.chamber_quote_token.to_account_info(),
+                self.chamber.vault.quote_mint,
+            )
+        };
+        if self.fee_manager_token.mint != output_mint || self.protocol_fee_token.mint != output_mint
+        {
+            return Err(error::ChamberError::InvalidOutputMint.into());
+        }
+
+        // 4. Process market specific logic
+        match self.chamber.strategy.market {
+            state::ChamberMarket::Tulip => {
+                let swap_amm_id = &remaining_accounts[0];
+                let swap_amm_authority = &remaining_accounts[1];
+                let swap_amm_open_orders = &remaining_accounts[2];
+                let swap_amm_target_orders = &remaining_accounts[3];
+                let swap_pool_coin_token_account = &remaining_accounts[4];
+                let swap_pool_pc_token_account = &remaining_accounts[5];
+                let swap_serum_program_id = &remaining_accounts[6];
+                let swap_serum_market = &remaining_accounts[7];
+                let swap_serum_bids = &remaining_accounts[8];
+                let swap_serum_asks = &remaining_accounts[9];
+                let swap_serum_event_queue = &remaining_accounts[10];
+                let swap_serum_coin_vault_account = &remaining_accounts[11];
+                let swap_serum_pc_vault_account = &remaining_accounts[12];
+                let swap_serum_vault_signer = &remaining_accounts[13];
+                let reward_stake_program = &remaining_accounts[14];
+                let reward_pool_id = &remaining_accounts[15];
+                let reward_pool_authority = &remaining_accounts[16];
+                let reward_user_info_account = &remaining_accounts[17];
+                let reward_pool_lp_token_account = &remaining_accounts[18];
+                let reward_pool_reward_token_account = &remaining_accounts[19];
+                let user_farm = &remaining_accounts[20];
+                let leveraged_farm = &remaining_accounts[21];
+                let user_farm_obligation = &remaining_accounts[22];
+                let lev_farm_coin_token_account = &remaining_accounts[23];
+                let lev_farm_pc_token_account = &remaining_accounts[24];
+                let coin_deposit_reserve_account = &remaining_accounts[25];
+                let pc_deposit_reserve_account = &remaining_accounts[26];
+                let lending_market_account = &remaining_accounts[27];
+                let lending_market_authority = &remaining_accounts[28];
+                let lending_program = &remaining_accounts[29];
+                let position_info_account = &remaining_accounts[30];
+                let vault_signer = &remaining_accounts[31];
+                let liquidity_program_id = &remaining_accounts[32];
+                let amm_id = &remaining_accounts[33];
+                let amm_authority = &remaining_accounts[34];
+                let amm_open_orders = &remaining_accounts[35];
+                let amm_quantities_or_target_orders = &remaining_accounts[36];
+                let pool_coin_token_account = &remaining_accounts[37];
+                let pool_pc_token_account = &remaining_accounts[38];
+                let dex_program = &remaining_accounts[39];
+                let serum_market = &remaining_accounts[40];
+                let serum_bids = &remaining_accounts[41];
+                let serum_asks = &remaining_accounts[42];
+                let serum_event_queue = &remaining_accounts[43];
+                let serum_coin_vault_account = &remaining_accounts[44];
+                let serum_pc_vault_account = &remaining_accounts[45];
+                let serum_vault_signer = &remaining_accounts[46];
+                let lp_mint_address = &remaining_accounts[47];
+                let user_lp_token_account = &remaining_accounts[48];
+                let lp_pyth_price_account = &remaining_accounts[49];
+                let obligation_vault_address = &remaining_accounts[50];
+                let vault_program = &remaining_accounts[51];
+                let vault_pda_account = &remaining_accounts[52];
+                let vault = &remaining_accounts[53];
+                let lp_token_account = &remaining_accounts[54];
+                let user_balance_account = &remaining_accounts[55];
+                let stake_program_id = &remaining_accounts[56];
+                let pool_id = &remaining_accounts[57];
+                let pool_authority = &remaining_accounts[58];
+                let vault_info_account = &remaining_accounts[59];
+                let pool_lp_token_account = &remaining_accounts[60];
+                let user_reward_a_token_account = &remaining_accounts[61];
+                let pool_reward_a_token_account = &remaining_accounts[62];
+                let user_reward_b_token_account = &remaining_accounts[63];
+                let pool_reward_b_token_account = &remaining_accounts[64];
+                let user_balance_metadata = &remaining_accounts[65];
+
+                let obligation_index = self.chamber.active_obligation;
+
+                // 5. Ensure, that rewards are collected from configured farm
+                if reward_pool_id.key() != self.chamber.harvest.reward_farm
+                    || reward_pool_id.owner != reward_stake_program.key
+                {
+                    return Err(error::ChamberError::InvalidRewardFarm.into());
+                }
+
+                // 6. Ensure, that rewards are swapped via configured route
+                if swap_amm_id.key() != self.chamber.harvest.swap_amm
+                    || swap_amm_id.owner != self.swap_program.key
+                {
+                    return Err(error::ChamberError::InvalidSwapRoute.into());
+                }
+
+                // 7. Collect pending farm rewards into `Chamber` reward token account
+                bindings::raydium::harvest_stake(CpiContext::new_with_signer(
+                    reward_stake_program.to_account_info(),
+                    Box::new(bindings::raydium::HarvestStake {
+                        pool_id: reward_pool_id.to_account_info(),
+                        pool_authority: reward_pool_authority.to_account_info(),
+                        user_info_account: reward_user_info_account.to_account_info(),
+                        user_owner: self.chamber_authority.to_account_info(),
+                        user_lp_token_account: user_lp_token_account.to_account_info(),
+                        pool_lp_token_account: reward_pool_lp_token_account.to_account_info(),
+                        user_reward_token_account: self.chamber_reward_token.to_account_info(),
+                        pool_reward_token_account: reward_pool_reward_token_account
+                            .to_account_info(),
+                        clock: self.clock_sysvar.clone(),
+                        token_program: self.token_program.clone(),
+                    }),
+                    &[&[
+                        utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                        self.chamber.key().as_ref(),
+                        &[self.chamber.config.authority_bump],
+                    ]],
+                ))?;
+
+                self.chamber_reward_token.reload()?;
+
+                // 8. Ensure, that `Chamber` has collected rewards
+                let reward_amount = self.chamber_reward_token.amount;
+                if reward_amount == 0 {
+                    return Err(error::ChamberError::NothingToHarvest.into());
+                }
+
+                // 9. Calculate min swap output from oracle prices and configured slippage
+                let reward_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                    &self.reward_oracle.data.as_ref().borrow(),
+                )?;
+                let (output_oracle, output_decimals) = if self.chamber.harvest.is_reward_to_base {
+                    (&self.chamber_base_oracle, self.chamber.vault.base_decimals)
+                } else {
+                    (
+                        &self.chamber_quote_oracle,
+                        self.chamber.vault.quote_decimals,
+                    )
+                };
+                let output_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                    &output_oracle.data.as_ref().borrow(),
+                )?;
+
+                let reward_value = math::get_token_value(
+                    &reward_price,
+                    reward_amount,
+                    u64::from(self.reward_mint.decimals),
+                )?;
+                let expected_amount =
+                    math::get_token_amount(&reward_value, &output_price, output_decimals)?;
+                let min_out = min_out.max(self.chamber.harvest.get_min_out(expected_amount)?);
+
+                let chamber_base_amount = self.chamber_base_token.amount;
+                let chamber_quote_amount = self.chamber_quote_token.amount;
+
+                // 10. Swap rewards into base or quote token
+                bindings::raydium::swap_base_in(
+                    CpiContext::new_with_signer(
+                        self.swap_program.to_account_info(),
+                        Box::new(bindings::raydium::SwapBaseIn {
+                            token_program: self.token_program.clone(),
+                            amm_id: swap_amm_id.clone(),
+                            amm_authority: swap_amm_authority.clone(),
+                            amm_open_orders: swap_amm_open_orders.clone(),
+                            amm_target_orders: swap_amm_target_orders.clone(),
+                            pool_coin_token_account: swap_pool_coin_token_account.clone(),
+                            pool_pc_token_account: swap_pool_pc_token_account.clone(),
+                            serum_program_id: swap_serum_program_id.clone(),
+                            serum_market: swap_serum_market.clone(),
+                            serum_bids: swap_serum_bids.clone(),
+                            serum_asks: swap_serum_asks.clone(),
+                            serum_event_queue: swap_serum_event_queue.clone(),
+                            serum_coin_vault_account: swap_serum_coin_vault_account.clone(),
+                            serum_pc_vault_account: swap_serum_pc_vault_account.clone(),
+                            serum_vault_signer: swap_serum_vault_signer.clone(),
+                            user_source_token_account: self.chamber_reward_token.to_account_info(),
+                            user_destination_token_account: output_token.clone(),
+                            user_source_owner: self.chamber_authority.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    reward_amount,
+                    min_out,
+                )?;
+
+                self.chamber_base_token.reload()?;
+                self.chamber_quote_token.reload()?;
+
+                // 11. Calculate swapped amount and performance fee, split with protocol
+                let output_amount = if self.chamber.harvest.is_reward_to_base {
+                    self.chamber_base_token
+                        .amount
+                        .saturating_sub(chamber_base_amount)
+                } else {
+                    self.chamber_quote_token
+                        .amount
+                        .saturating_sub(chamber_quote_amount)
+                };
+                let fee_amount =
+                    math::get_fee_amount(output_amount, self.chamber.harvest.performance_fee_bps)?;
+                let compound_amount = output_amount
+                    .checked_sub(fee_amount)
+                    .ok_or(error::ChamberError::MathOverflow)?;
+                let protocol_fee_amount =
+                    math::get_fee_amount(fee_amount, self.protocol_config.protocol_fee_bps)?;
+                let manager_fee_amount = fee_amount
+                    .checked_sub(protocol_fee_amount)
+                    .ok_or(error::ChamberError::MathOverflow)?;
+
+                // 12. Transfer performance fee to fee manager and protocol
+                for (amount, fee_token) in [
+                    (manager_fee_amount, &self.fee_manager_token),
+                    (protocol_fee_amount, &self.protocol_fee_token),
+                ] {
+                    if amount == 0 {
+                        continue;
+                    }
+
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            self.token_program.to_account_info(),
+                            token::Transfer {
+                                from: output_token.clone(),
+                                to: fee_token.to_account_info(),
+                                authority: self.chamber_authority.to_account_info(),
+                            },
+                            &[&[
+                                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                self.chamber.key().as_ref(),
+                                &[self.chamber.config.authority_bump],
+                            ]],
+                        ),
+                        amount,
+                    )?;
+                }
+
+                let (base_amount, quote_amount) = if self.chamber.harvest.is_reward_to_base {
+                    (compound_amount, 0)
+                } else {
+                    (0, compound_amount)
+                };
+
+                // 13. Top up leveraged position with harvested tokens
+                bindings::tulip::leveraged::top_up_position_stats(
+                    CpiContext::new_with_signer(
+                        self.chamber_farm_program.to_account_info(),
+                        Box::new(bindings::tulip::leveraged::DepositObligationCollateral {
+                            authority: self.chamber_authority.to_account_info(),
+                            user_farm: user_farm.to_account_info(),
+                            leveraged_farm: leveraged_farm.to_account_info(),
+                            user_farm_obligation: user_farm_obligation.to_account_info(),
+                            coin_source_token_account: self.chamber_base_token.to_account_info(),
+                            coin_destination_token_account: lev_farm_coin_token_account
+                                .to_account_info(),
+                            pc_source_token_account: self.chamber_quote_token.to_account_info(),
+                            pc_destination_token_account: lev_farm_pc_token_account
+                                .to_account_info(),
+                            coin_deposit_reserve_account: coin_deposit_reserve_account
+                                .to_account_info(),
+                            pc_deposit_reserve_account: pc_deposit_reserve_account
+                                .to_account_info(),
+                            coin_reserve_liquidity_oracle: self
+                                .chamber_base_oracle
+                                .to_account_info(),
+                            pc_reserve_liquidity_oracle: self
+                                .chamber_quote_oracle
+                                .to_account_info(),
+                            lending_market_account: lending_market_account.to_account_info(),
+                            derived_lending_market_authority: lending_market_authority
+                                .to_account_info(),
+                            clock: self.clock_sysvar.clone(),
+                            lending_program: lending_program.to_account_info(),
+                            token_program: self.token_program.clone(),
+                            position_info_account: position_info_account.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    base_amount,
+                    quote_amount,
+                    obligation_index,
+                )?;
+
+                // 14. Swap topped up tokens to pool ratio
+                bindings::tulip::leveraged::raydium::swap_tokens_raydium_stats(
+                    CpiContext::new_with_signer(
+                        self.chamber_farm_program.to_account_info(),
+                        Box::new(bindings::tulip::leveraged::raydium::RaydiumSwap {
+                            authority: self.chamber_authority.to_account_info(),
+                            leveraged_farm: leveraged_farm.to_account_info(),
+                            user_farm: user_farm.to_account_info(),
+                            user_farm_obligation: user_farm_obligation.to_account_info(),
+                            token_program: self.token_program.clone(),
+                            vault_signer: vault_signer.to_account_info(),
+                            swap_or_liquidity_program_id: liquidity_program_id.to_account_info(),
+                            amm_id: amm_id.to_account_info(),
+                            amm_authority: amm_authority.to_account_info(),
+                            amm_open_orders: amm_open_orders.to_account_info(),
+                            amm_quantities_or_target_orders: amm_quantities_or_target_orders
+                                .to_account_info(),
+                            pool_coin_tokenaccount: pool_coin_token_account.to_account_info(),
+                            pool_pc_tokenaccount: pool_pc_token_account.to_account_info(),
+                            serum_program_id: dex_program.to_account_info(),
+                            serum_market: serum_market.to_account_info(),
+                            serum_bids: serum_bids.to_account_info(),
+                            serum_asks: serum_asks.to_account_info(),
+                            serum_event_queue: serum_event_queue.to_account_info(),
+                            serum_coin_vault_account: serum_coin_vault_account.to_account_info(),
+                            serum_pc_vault_account: serum_pc_vault_account.to_account_info(),
+                            serum_vault_signer: serum_vault_signer.to_account_info(),
+                            coin_wallet: lev_farm_coin_token_account.to_account_info(),
+                            pc_wallet: lev_farm_pc_token_account.to_account_info(),
+                            lending_market_account: lending_market_account.to_account_info(),
+                            lending_market_authority: lending_market_authority.to_account_info(),
+                            lending_program: lending_program.to_account_info(),
+                            position_info_account: position_info_account.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    obligation_index,
+                )?;
+
+                // 15. Deposit tokens into lp
+                bindings::tulip::leveraged::add_liquidity_stats(
+                    CpiContext::new_with_signer(
+                        self.chamber_farm_program.to_account_info(),
+                        Box::new(bindings::tulip::leveraged::AddLiquidity {
+                            authority: self.chamber_authority.to_account_info(),
+                            user_farm: user_farm.to_account_info(),
+                            leveraged_farm: leveraged_farm.to_account_info(),
+                            liquidity_program_id: liquidity_program_id.to_account_info(),
+                            amm_id: amm_id.to_account_info(),
+                            amm_authority: amm_authority.to_account_info(),
+                            amm_open_orders: amm_open_orders.to_account_info(),
+                            amm_quantities_or_target_orders: amm_quantities_or_target_orders
+                                .to_account_info(),
+                            lp_mint_address: lp_mint_address.to_account_info(),
+                            pool_coin_token_account: pool_coin_token_account.to_account_info(),
+                            pool_pc_token_account: pool_pc_token_account.to_account_info(),
+                            serum_market: serum_market.to_account_info(),
+                            token_program: self.token_program.clone(),
+                            lev_farm_coin_token_account: lev_farm_coin_token_account
+                                .to_account_info(),
+                            lev_farm_pc_token_account: lev_farm_pc_token_account.to_account_info(),
+                            user_lp_token_account: user_lp_token_account.to_account_info(),
+                            pyth_price_account: lp_pyth_price_account.to_account_info(),
+                            lending_market_account: lending_market_account.to_account_info(),
+                            user_farm_obligation: user_farm_obligation.to_account_info(),
+                            derived_lending_market_authority: lending_market_authority
+                                .to_account_info(),
+                            lending_program: lending_program.to_account_info(),
+                            clock: self.clock_sysvar.clone(),
+                            dex_program: dex_program.to_account_info(),
+                            position_info_account: position_info_account.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    obligation_index,
+                )?;
+
+                // 16. Stake lp tokens into tulip vault
+                bindings::tulip::leveraged::raydium::deposit_raydium_vault(
+                    CpiContext::new_with_signer(
+                        self.chamber_farm_program.to_account_info(),
+                        Box::new(bindings::tulip::leveraged::raydium::DepositFarm {
+                            authority: self.chamber_authority.to_account_info(),
+                            user_farm: user_farm.to_account_info(),
+                            obligation_vault_address: obligation_vault_address.to_account_info(),
+                            leveraged_farm: leveraged_farm.to_account_info(),
+                            vault_program: vault_program.to_account_info(),
+                            authority_token_account: user_lp_token_account.to_account_info(),
+                            vault_pda_account: vault_pda_account.to_account_info(),
+                            vault: vault.to_account_info(),
+                            lp_token_account: lp_token_account.to_account_info(),
+                            user_balance_account: user_balance_account.to_account_info(),
+                            system_program: self.system_program.clone(),
+                            stake_program_id: stake_program_id.to_account_info(),
+                            pool_id: pool_id.to_account_info(),
+                            pool_authority: pool_authority.to_account_info(),
+                            vault_info_account: vault_info_account.to_account_info(),
+                            pool_lp_token_account: pool_lp_token_account.to_account_info(),
+                            user_reward_a_token_account: user_reward_a_token_account
+                                .to_account_info(),
+                            pool_reward_a_token_account: pool_reward_a_token_account
+                                .to_account_info(),
+                            user_reward_b_token_account: user_reward_b_token_account
+                                .to_account_info(),
+                            pool_reward_b_token_account: pool_reward_b_token_account
+                                .to_account_info(),
+                            clock: self.clock_sysvar.clone(),
+                            rent: self.rent_sysvar.clone(),
+                            token_program_id: self.token_program.clone(),
+                            user_balance_metadata: user_balance_metadata.to_account_info(),
+                            lending_market_account: lending_market_account.to_account_info(),
+                            user_farm_obligation: user_farm_obligation.to_account_info(),
+                            lending_market_authority: lending_market_authority.to_account_info(),
+                            lending_program: lending_program.to_account_info(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[self.chamber.config.authority_bump],
+                        ]],
+                    ),
+                    0,
+                    0,
+                    u64::from(obligation_index),
+                )?;
+
+                // 17. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
+
+                // 18. Reconcile `Chamber` debt with market obligations
+                self.chamber.reconcile_debt();
+
+                // 19. Book compounded tokens into `Chamber` vault, so they reach share price
+                self.chamber.vault.deposit(base_amount, quote_amount)?;
+
+                // 20. Emit `Harvested` and `FeesCollected` events
+                emit!(events::Harvested {
+                    chamber: self.chamber.key(),
+                    reward_amount,
+                    base_amount,
+                    quote_amount,
+                    fee_amount,
+                    protocol_fee_amount,
+                });
+
+                let (fee_base_amount, fee_quote_amount) = if self.chamber.harvest.is_reward_to_base
+                {
+                    (manager_fee_amount, 0)
+                } else {
+                    (0, manager_fee_amount)
+                };
+
+                emit!(events::FeesCollected {
+                    chamber: self.chamber.key(),
+                    fee_manager: self.chamber.config.fee_manager,
+                    base_amount: fee_base_amount,
+                    quote_amount: fee_quote_amount,
+                });
+            }
+        };
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{cpi, error, state, utils};
+use crate::{bindings, error, events, position, state, utils};
 use anchor_lang::{prelude::*, system_program};
 use anchor_spl::{associated_token, token};
 
@@ -20,7 +20,33 @@ pub struct InitializeChamber<'info> {
     )]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
-    /// CHECK: Associated `market` farm.
+    #[account(
+        mut,
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            utils::CHAMBER_REGISTRY_PREFIX.as_bytes(),
+            chamber_registry.index.to_le_bytes().as_ref()
+        ],
+        bump,
+        constraint = chamber_registry.index + 1 == protocol_config.registry_pages
+    )]
+    pub chamber_registry: Box<Account<'info, state::ChamberRegistry>>,
+
+    #[account(
+        seeds = [utils::FARM_ENTRY_PREFIX.as_bytes(), farm.key().as_ref()],
+        bump,
+        constraint = farm_entry.farm_program == farm_program.key() @ error::ChamberError::FarmNotAllowed
+    )]
+    pub farm_entry: Box<Account<'info, state::FarmEntry>>,
+
+    /// CHECK: Associated `market` farm, allowed by `farm_entry`.
+    #[account(owner = farm_program.key() @ error::ChamberError::FarmNotAllowed)]
     pub farm: UncheckedAccount<'info>,
 
     /// CHECK: Uninitialized associated base token account for `base_mint`.
@@ -38,12 +64,26 @@ pub struct InitializeChamber<'info> {
     #[account(mut)]
     pub shares_mint: UncheckedAccount<'info>,
 
-    /// CHECK: Pyth oracle for tracking base token price.
+    /// CHECK: Pyth oracle for tracking base token price, allowed by `base_oracle_entry`.
     pub base_oracle: UncheckedAccount<'info>,
 
-    /// CHECK: Pyth oracle for tracking quote token price.
+    /// CHECK: Pyth oracle for tracking quote token price, allowed by `quote_oracle_entry`.
     pub quote_oracle: UncheckedAccount<'info>,
 
+    #[account(
+        seeds = [utils::ORACLE_ENTRY_PREFIX.as_bytes(), base_mint.key().as_ref()],
+        bump,
+        constraint = base_oracle_entry.price == base_oracle.key() @ error::ChamberError::InvalidOracle
+    )]
+    pub base_oracle_entry: Box<Account<'info, state::OracleEntry>>,
+
+    #[account(
+        seeds = [utils::ORACLE_ENTRY_PREFIX.as_bytes(), quote_mint.key().as_ref()],
+        bump,
+        constraint = quote_oracle_entry.price == quote_oracle.key() @ error::ChamberError::InvalidOracle
+    )]
+    pub quote_oracle_entry: Box<Account<'info, state::OracleEntry>>,
+
     /// CHECK: Chamber authority.
     #[account(mut, seeds = [utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(), chamber.key().as_ref()], bump = authority_bump)]
     pub authority: UncheckedAccount<'info>,
@@ -76,7 +116,35 @@ impl<'c, 'info> InitializeChamber<'info> {
         chamber_nonce: u8,
         authority_bump: u8,
     ) -> Result<()> {
-        // 1. Create base token ata
+        // 1. Ensure protocol is not paused and chamber programs are allowed
+        self.protocol_config.assert_not_paused()?;
+        self.protocol_config
+            .assert_farm_program(self.farm_program.key)?;
+        self.protocol_config
+            .assert_oracle_program(self.base_oracle.owner)?;
+        self.protocol_config
+            .assert_oracle_program(self.quote_oracle.owner)?;
+
+        // 2. Ensure, that `leverage` is within protocol bounds
+        if leverage == 0 || leverage > utils::MAX_LEVERAGE {
+            return Err(error::ChamberError::InvalidLeverage.into());
+        }
+
+        // 3. Ensure, that `farm` is `farm_program` farm for `base_mint` and `quote_mint`
+        match market {
+            state::ChamberMarket::Tulip => {
+                let leveraged_farm =
+                    position::tulip::load_leveraged_farm(&self.farm, self.farm_program.key)?;
+
+                if leveraged_farm.base_token_mint != self.base_mint.key()
+                    || leveraged_farm.quote_token_mint != self.quote_mint.key()
+                {
+                    return Err(error::ChamberError::InvalidFarm.into());
+                }
+            }
+        };
+
+        // 4. Create base token ata
         associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
             associated_token::Create {
@@ -90,7 +158,7 @@ impl<'c, 'info> InitializeChamber<'info> {
             },
         ))?;
 
-        // 2. Create quote token ata
+        // 5. Create quote token ata
         associated_token::create(CpiContext::new(
             self.associated_token_program.to_account_info(),
             associated_token::Create {
@@ -104,7 +172,7 @@ impl<'c, 'info> InitializeChamber<'info> {
             },
         ))?;
 
-        // 3. Create shares token mint
+        // 6. Create shares token mint
         token::initialize_mint(
             CpiContext::new(
                 self.token_program.to_account_info(),
@@ -118,7 +186,7 @@ impl<'c, 'info> InitializeChamber<'info> {
             None,
         )?;
 
-        // 4. Fund authority account
+        // 7. Fund authority account
         system_program::transfer(
             CpiContext::new(
                 self.system_program.to_account_info(),
@@ -140,7 +208,7 @@ impl<'c, 'info> InitializeChamber<'info> {
                     * 2,
         )?;
 
-        // 5. Initialize `market` related accounts
+        // 8. Initialize `market` related accounts
         match market {
             state::ChamberMarket::Tulip => {
                 // TODO: Check first & second obligation relations
@@ -154,10 +222,10 @@ impl<'c, 'info> InitializeChamber<'info> {
                 let lending_program = &remaining_accounts[7];
                 let solfarm_vault_program = &remaining_accounts[8];
 
-                cpi::tulip::leveraged::create_user_farm(
+                bindings::tulip::leveraged::create_user_farm(
                     CpiContext::new_with_signer(
                         self.farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::CreateUserFarm {
+                        Box::new(bindings::tulip::leveraged::CreateUserFarm {
                             authority: self.authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             user_farm_obligation: user_farm_obligation.to_account_info(),
@@ -180,31 +248,33 @@ impl<'c, 'info> InitializeChamber<'info> {
                     solfarm_vault_program.key,
                 )?;
 
-                cpi::tulip::leveraged::create_user_farm_obligation(CpiContext::new_with_signer(
-                    self.farm_program.to_account_info(),
-                    Box::new(cpi::tulip::leveraged::CreateUserFarmObligation {
-                        authority: self.authority.to_account_info(),
-                        user_farm: user_farm.to_account_info(),
-                        leveraged_farm: self.farm.to_account_info(),
-                        user_farm_obligation: user_farm_obligation_1.to_account_info(),
-                        lending_market: lending_market.to_account_info(),
-                        obligation_vault_address: obligation_vault_address_1.to_account_info(),
-                        clock: self.clock_sysvar.clone(),
-                        rent: self.rent_sysvar.clone(),
-                        lending_program: lending_program.to_account_info(),
-                        token_program: self.token_program.clone(),
-                        system_program: self.system_program.clone(),
-                    }),
-                    &[&[
-                        utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
-                        self.chamber.key().as_ref(),
-                        &[authority_bump],
-                    ]],
-                ))?;
+                bindings::tulip::leveraged::create_user_farm_obligation(
+                    CpiContext::new_with_signer(
+                        self.farm_program.to_account_info(),
+                        Box::new(bindings::tulip::leveraged::CreateUserFarmObligation {
+                            authority: self.authority.to_account_info(),
+                            user_farm: user_farm.to_account_info(),
+                            leveraged_farm: self.farm.to_account_info(),
+                            user_farm_obligation: user_farm_obligation_1.to_account_info(),
+                            lending_market: lending_market.to_account_info(),
+                            obligation_vault_address: obligation_vault_address_1.to_account_info(),
+                            clock: self.clock_sysvar.clone(),
+                            rent: self.rent_sysvar.clone(),
+                            lending_program: lending_program.to_account_info(),
+                            token_program: self.token_program.clone(),
+                            system_program: self.system_program.clone(),
+                        }),
+                        &[&[
+                            utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                            self.chamber.key().as_ref(),
+                            &[authority_bump],
+                        ]],
+                    ),
+                )?;
             }
         };
 
-        // 6. Initialize `chamber` state
+        // 9. Initialize `chamber` state
         self.chamber.init(
             &state::ChamberStrategy::new(
                 market,
@@ -239,6 +309,36 @@ impl<'c, 'info> InitializeChamber<'info> {
             ),
         );
 
+        // 10. List `chamber` in last `ChamberRegistry` page
+        self.chamber_registry.push(state::ChamberRegistryEntry {
+            chamber: self.chamber.key(),
+            market: self.chamber.strategy.market.clone(),
+            farm: self.farm.key(),
+            base_mint: self.base_mint.key(),
+            quote_mint: self.quote_mint.key(),
+            nonce: chamber_nonce,
+            status: state::ChamberStatus::Active,
+        })?;
+
+        self.protocol_config.chambers_count = self
+            .protocol_config
+            .chambers_count
+            .checked_add(1)
+            .ok_or(error::ChamberError::MathOverflow)?;
+
+        // 11. Emit `ChamberInitialized` event
+        emit!(events::ChamberInitialized {
+            chamber: self.chamber.key(),
+            farm: self.farm.key(),
+            base_mint: self.base_mint.key(),
+            quote_mint: self.quote_mint.key(),
+            shares_mint: self.shares_mint.key(),
+            owner: self.owner.key(),
+            leverage,
+            is_base_volatile,
+            nonce: chamber_nonce,
+        });
+
         Ok(())
     }
 }
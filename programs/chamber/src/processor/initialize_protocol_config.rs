@@ -0,0 +1,39 @@
+use crate::{error, state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = state::ProtocolConfig::LEN,
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    /// `Chamber` program, which upgrade authority initializes protocol.
+    #[account(constraint = program.programdata_address() == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Chamber>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ error::ChamberError::Unauthorized
+    )]
+    pub program_data: Box<Account<'info, ProgramData>>,
+
+    /// Program upgrade authority.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeProtocolConfig<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Initialize singleton `ProtocolConfig`, program upgrade authority becomes admin
+        self.protocol_config.init(self.admin.key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{error, events, state, utils};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateChamber<'info> {
+    /// CHECK: `Chamber` of baseline or current layout, decoded by instruction.
+    #[account(mut, owner = crate::ID)]
+    pub chamber: UncheckedAccount<'info>,
+
+    /// `Chamber` owner, funding rent of grown account.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub rent_sysvar: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateChamber<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Decode `Chamber` from its current layout
+        let mut chamber = {
+            let data = self.chamber.try_borrow_data()?;
+
+            if data.len() < 8 || data[..8] != state::Chamber::discriminator() {
+                return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            match data.len() {
+                state::ChamberV0::LEN => {
+                    state::ChamberV0::deserialize(&mut &data[8..])?.into_chamber()
+                }
+                _ => state::Chamber::try_deserialize(&mut data.as_ref())?,
+            }
+        };
+
+        // 2. Ensure, that only `Chamber` owner migrates it
+        if chamber.config.owner != self.owner.key() {
+            return Err(error::ChamberError::Unauthorized.into());
+        }
+
+        // 3. Ensure, that `Chamber` has outdated layout
+        if self.chamber.data_len() == state::Chamber::LEN
+            && chamber.version >= utils::CHAMBER_VERSION
+        {
+            return Err(error::ChamberError::AccountUpToDate.into());
+        }
+
+        chamber.version = utils::CHAMBER_VERSION;
+
+        // 4. Grow `Chamber` account to current layout size
+        utils::realloc_account(
+            &self.chamber.to_account_info(),
+            &self.owner.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.rent_sysvar,
+            state::Chamber::LEN,
+        )?;
+
+        // 5. Write `Chamber` in current layout
+        let mut data = self.chamber.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        chamber.try_serialize(&mut writer)?;
+
+        // 6. Emit `AccountMigrated` event
+        emit!(events::AccountMigrated {
+            account: self.chamber.key(),
+            version: utils::CHAMBER_VERSION,
+        });
+
+        Ok(())
+    }
+}
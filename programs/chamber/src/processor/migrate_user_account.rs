@@ -0,0 +1,71 @@
+use crate::{error, events, state, utils};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    /// CHECK: `UserAccount` of any layout version, decoded by instruction.
+    #[account(mut, owner = crate::ID)]
+    pub user_account: UncheckedAccount<'info>,
+
+    /// `UserAccount` user, funding rent of grown account.
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub rent_sysvar: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUserAccount<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Decode `UserAccount` from its current layout
+        let mut user_account = {
+            let data = self.user_account.try_borrow_data()?;
+
+            if data.len() < 8 || data[..8] != state::UserAccount::discriminator() {
+                return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            if data.len() < state::UserAccount::LEN {
+                state::UserAccountV0::deserialize(&mut &data[8..])?.into_user_account()
+            } else {
+                state::UserAccount::try_deserialize(&mut data.as_ref())?
+            }
+        };
+
+        // 2. Ensure, that only `UserAccount` user migrates it
+        if user_account.user != self.user.key() {
+            return Err(error::ChamberError::Unauthorized.into());
+        }
+
+        // 3. Ensure, that `UserAccount` has outdated layout
+        if self.user_account.data_len() >= state::UserAccount::LEN
+            && user_account.version >= utils::USER_ACCOUNT_VERSION
+        {
+            return Err(error::ChamberError::AccountUpToDate.into());
+        }
+
+        user_account.version = utils::USER_ACCOUNT_VERSION;
+
+        // 4. Grow `UserAccount` account to current layout size
+        utils::realloc_account(
+            &self.user_account.to_account_info(),
+            &self.user.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.rent_sysvar,
+            state::UserAccount::LEN,
+        )?;
+
+        // 5. Write `UserAccount` in current layout
+        let mut data = self.user_account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        user_account.try_serialize(&mut writer)?;
+
+        // 6. Emit `AccountMigrated` event
+        emit!(events::AccountMigrated {
+            account: self.user_account.key(),
+            version: utils::USER_ACCOUNT_VERSION,
+        });
+
+        Ok(())
+    }
+}
@@ -1,17 +1,85 @@
+mod add_allowlist_entry;
+mod add_farm_entry;
+mod add_oracle_entry;
 mod begin_deposit_chamber;
+mod cancel_config_change;
+mod cancel_deposit_chamber;
+mod close_chamber;
+mod close_user_account;
+mod create_chamber_lookup_table;
+mod create_chamber_registry_page;
 mod create_user_account;
+mod deleverage_chamber;
 mod deposit_chamber;
 mod end_deposit_chamber;
+mod execute_config_change;
+mod extend_chamber_lookup_table;
+mod get_chamber_nav;
+mod get_share_price;
+mod get_user_position;
+mod harvest_chamber;
 mod initialize_chamber;
+mod initialize_protocol_config;
+mod migrate_chamber;
+mod migrate_user_account;
 mod process_deposit_chamber;
+mod propose_config_change;
 mod rebalance_chamber;
+mod releverage_chamber;
+mod remove_allowlist_entry;
+mod remove_farm_entry;
+mod remove_oracle_entry;
+mod set_chamber_pause;
+mod set_protocol_pause;
+mod sync_chamber_registry;
+mod update_chamber_access;
+mod update_chamber_harvest;
+mod update_chamber_limits;
+mod update_chamber_risk;
+mod update_chamber_risk_bounds;
+mod update_chamber_roles;
+mod update_protocol_config;
 mod withdraw_chamber;
 
+pub use add_allowlist_entry::*;
+pub use add_farm_entry::*;
+pub use add_oracle_entry::*;
 pub use begin_deposit_chamber::*;
+pub use cancel_config_change::*;
+pub use cancel_deposit_chamber::*;
+pub use close_chamber::*;
+pub use close_user_account::*;
+pub use create_chamber_lookup_table::*;
+pub use create_chamber_registry_page::*;
 pub use create_user_account::*;
+pub use deleverage_chamber::*;
 pub use deposit_chamber::*;
 pub use end_deposit_chamber::*;
+pub use execute_config_change::*;
+pub use extend_chamber_lookup_table::*;
+pub use get_chamber_nav::*;
+pub use get_share_price::*;
+pub use get_user_position::*;
+pub use harvest_chamber::*;
 pub use initialize_chamber::*;
+pub use initialize_protocol_config::*;
+pub use migrate_chamber::*;
+pub use migrate_user_account::*;
 pub use process_deposit_chamber::*;
+pub use propose_config_change::*;
 pub use rebalance_chamber::*;
+pub use releverage_chamber::*;
+pub use remove_allowlist_entry::*;
+pub use remove_farm_entry::*;
+pub use remove_oracle_entry::*;
+pub use set_chamber_pause::*;
+pub use set_protocol_pause::*;
+pub use sync_chamber_registry::*;
+pub use update_chamber_access::*;
+pub use update_chamber_harvest::*;
+pub use update_chamber_limits::*;
+pub use update_chamber_risk::*;
+pub use update_chamber_risk_bounds::*;
+pub use update_chamber_roles::*;
+pub use update_protocol_config::*;
 pub use withdraw_chamber::*;
@@ -1,9 +1,10 @@
-use crate::{cpi, state, utils};
+use crate::{bindings, events, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
 pub struct ProcessDepositChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
     #[account(
@@ -34,7 +35,8 @@ pub struct ProcessDepositChamber<'info> {
     #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
     pub chamber_farm_program: UncheckedAccount<'info>,
 
-    pub user: Signer<'info>,
+    /// CHECK: Depositing user, so pending deposit stage may be finished by any keeper.
+    pub user: UncheckedAccount<'info>,
 
     pub clock_sysvar: Sysvar<'info, Clock>,
     pub rent_sysvar: Sysvar<'info, Rent>,
@@ -85,11 +87,13 @@ impl<'c, 'info> ProcessDepositChamber<'info> {
                 let pyth_price_account = &remaining_accounts[31];
                 let dex_program = &remaining_accounts[32];
 
+                let obligation_index = self.chamber.active_obligation;
+
                 // 3. Swap tokens via AMM
-                cpi::tulip::leveraged::raydium::swap_tokens_raydium_stats(
+                bindings::tulip::leveraged::raydium::swap_tokens_raydium_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::RaydiumSwap {
+                        Box::new(bindings::tulip::leveraged::raydium::RaydiumSwap {
                             authority: self.chamber_authority.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
                             user_farm: user_farm.to_account_info(),
@@ -126,14 +130,14 @@ impl<'c, 'info> ProcessDepositChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
                 )?;
 
                 // 4. Deposit tokens into lp
-                cpi::tulip::leveraged::add_liquidity_stats(
+                bindings::tulip::leveraged::add_liquidity_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::AddLiquidity {
+                        Box::new(bindings::tulip::leveraged::AddLiquidity {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.to_account_info(),
                             leveraged_farm: leveraged_farm.to_account_info(),
@@ -168,11 +172,27 @@ impl<'c, 'info> ProcessDepositChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
+                )?;
+
+                // 5. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
                 )?;
 
-                // 5. Update `UserAccount` state
+                // 6. Reconcile `Chamber` debt with market obligations
+                self.chamber.reconcile_debt();
+
+                // 7. Update `UserAccount` state
                 self.user_account.process_deposit();
+
+                // 8. Emit `DepositProcessed` event
+                emit!(events::DepositProcessed {
+                    chamber: self.chamber.key(),
+                    user: self.user.key(),
+                });
             }
         };
 
@@ -0,0 +1,46 @@
+use crate::{events, state};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(leverage: u64, performance_fee_bps: u64, delay: i64, effective_timestamp: i64)]
+pub struct ProposeConfigChange<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+
+    pub clock_sysvar: Sysvar<'info, Clock>,
+}
+
+impl<'info> ProposeConfigChange<'info> {
+    pub fn process(
+        &mut self,
+        leverage: u64,
+        performance_fee_bps: u64,
+        delay: i64,
+        effective_timestamp: i64,
+    ) -> Result<()> {
+        // 1. Store pending change, effective not earlier than current timelock delay
+        let pending = state::ChamberPendingConfig::new(
+            leverage,
+            performance_fee_bps,
+            delay,
+            effective_timestamp,
+        )?;
+
+        self.chamber
+            .timelock
+            .propose(pending, self.clock_sysvar.unix_timestamp)?;
+
+        // 2. Emit `ConfigChangeProposed` event
+        emit!(events::ConfigChangeProposed {
+            chamber: self.chamber.key(),
+            leverage,
+            performance_fee_bps,
+            delay,
+            effective_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{cpi, state, utils};
+use crate::{bindings, error, events, math, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
-use tulipv2_sdk_common::math::common::{TryAdd, TryDiv, TryMul};
+use tulipv2_sdk_common::math::{
+    common::{TryAdd, TrySub},
+    decimal::Decimal,
+};
 
 pub struct RebalanceChamberLookupTable<'a, 'info> {
     pub user_farm: &'a AccountInfo<'info>,
@@ -83,6 +86,12 @@ pub struct RebalanceChamberLookupTable<'a, 'info> {
     pub lp_token_account: &'a AccountInfo<'info>,
     pub vault_info_account: &'a AccountInfo<'info>,
     pub user_balance_metadata: &'a AccountInfo<'info>,
+    pub target_user_farm_obligation: &'a AccountInfo<'info>,
+    pub target_obligation_vault_address: &'a AccountInfo<'info>,
+    pub target_obligation_lp_token_account: &'a AccountInfo<'info>,
+    pub target_position_info_account: &'a AccountInfo<'info>,
+    pub target_user_balance_account: &'a AccountInfo<'info>,
+    pub target_user_balance_metadata: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> RebalanceChamberLookupTable<'a, 'info> {
@@ -167,14 +176,28 @@ impl<'a, 'info> RebalanceChamberLookupTable<'a, 'info> {
             lp_token_account: &remaining_accounts[76],
             vault_info_account: &remaining_accounts[77],
             user_balance_metadata: &remaining_accounts[78],
+            target_user_farm_obligation: &remaining_accounts[79],
+            target_obligation_vault_address: &remaining_accounts[80],
+            target_obligation_lp_token_account: &remaining_accounts[81],
+            target_position_info_account: &remaining_accounts[82],
+            target_user_balance_account: &remaining_accounts[83],
+            target_user_balance_metadata: &remaining_accounts[84],
         })
     }
 }
 
 #[derive(Accounts)]
+#[instruction(target_obligation: u8, withdraw_percent: u8)]
 pub struct RebalanceChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
     #[account(mut, constraint = chamber_base_token.key() == chamber.vault.base)]
     pub chamber_base_token: Box<Account<'info, token::TokenAccount>>,
 
@@ -204,7 +227,9 @@ pub struct RebalanceChamber<'info> {
     #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
     pub chamber_farm_program: UncheckedAccount<'info>,
 
-    pub user: Signer<'info>,
+    /// `Chamber` strategist.
+    #[account(constraint = chamber.roles.is_strategist(strategist.key) @ error::ChamberError::Unauthorized)]
+    pub strategist: Signer<'info>,
 
     pub clock_sysvar: Sysvar<'info, Clock>,
     pub rent_sysvar: Sysvar<'info, Rent>,
@@ -214,19 +239,149 @@ pub struct RebalanceChamber<'info> {
 
 impl<'c, 'info> RebalanceChamber<'info> {
     #[inline(always)]
-    pub fn process(&mut self, remaining_accounts: &'c [AccountInfo<'info>]) -> Result<()> {
-        // TODO: Create rebalance trigger logic
-
-        // 1. Process market specific logic
+    pub fn process(
+        &mut self,
+        remaining_accounts: &'c [AccountInfo<'info>],
+        target_obligation: u8,
+        withdraw_percent: u8,
+    ) -> Result<()> {
+        // 1. Ensure, that protocol and `Chamber` are not paused
+        self.protocol_config.assert_not_paused()?;
+        self.chamber.access.assert_not_paused()?;
+
+        // 2. Ensure, that position is moved into existing obligation
+        state::Chamber::assert_obligation_index(target_obligation)?;
+
+        // 3. Ensure, that withdrawn position part is valid
+        if withdraw_percent == 0 || withdraw_percent > 100 {
+            return Err(error::ChamberError::InvalidWithdrawPercent.into());
+        }
+
+        // 4. Ensure, that whole position is moved on obligation switch,
+        // so no position part is left in inactive obligation
+        let is_obligation_switch = target_obligation != self.chamber.active_obligation;
+        if is_obligation_switch && withdraw_percent != 100 {
+            return Err(error::ChamberError::InvalidWithdrawPercent.into());
+        }
+
+        // 5. Process market specific logic
         match self.chamber.strategy.market {
             state::ChamberMarket::Tulip => {
                 let alt = RebalanceChamberLookupTable::from_remaining_accounts(remaining_accounts);
 
-                // 2. Withdraw lp from vault
-                cpi::tulip::leveraged::raydium::withdraw_raydium_vault_close(
+                let source_obligation = self.chamber.active_obligation;
+
+                // 6. Ensure, that farm, obligations and lp accounts belong to `Chamber`
+                if alt.leveraged_farm.key() != self.chamber.strategy.farm {
+                    return Err(error::ChamberError::InvalidFarm.into());
+                }
+
+                position::tulip::assert_lp_accounts(
+                    &position::tulip::load_leveraged_farm(
+                        alt.leveraged_farm,
+                        &self.chamber_farm_program.key(),
+                    )?,
+                    alt.lp_pyth_price_account,
+                    alt.lp_mint_address,
+                )?;
+
+                for (user_farm_obligation, obligation_index) in [
+                    (alt.user_farm_obligation, source_obligation),
+                    (alt.target_user_farm_obligation, target_obligation),
+                ] {
+                    position::tulip::assert_user_farm_obligation(
+                        alt.user_farm,
+                        user_farm_obligation,
+                        obligation_index,
+                        &self.chamber.config.authority,
+                        &self.chamber.strategy.farm,
+                        &self.chamber_farm_program.key(),
+                    )?;
+                }
+
+                // 7. Sync and reconcile `Chamber` debt, accrued before repay
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(source_obligation)?,
+                    alt.user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
+                self.chamber.reconcile_debt();
+
+                // 8. Ensure, that in place rebalance is triggered by debt drift from target leverage
+                if !is_obligation_switch {
+                    let drift_bps = {
+                        let obligation = &self.chamber.obligations[usize::from(source_obligation)];
+
+                        let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                            &self.chamber_base_oracle.data.as_ref().borrow(),
+                        )?;
+                        let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                            &self.chamber_quote_oracle.data.as_ref().borrow(),
+                        )?;
+                        let lp_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                            &alt.lp_pyth_price_account.data.as_ref().borrow(),
+                        )?;
+                        let lp_decimals =
+                            Account::<token::Mint>::try_from(alt.lp_mint_address)?.decimals;
+
+                        let assets_value = math::get_token_value(
+                            &base_price,
+                            self.chamber_base_token.amount,
+                            self.chamber.vault.base_decimals,
+                        )?
+                        .try_add(math::get_token_value(
+                            &quote_price,
+                            self.chamber_quote_token.amount,
+                            self.chamber.vault.quote_decimals,
+                        )?)?
+                        .try_add(math::get_token_value(
+                            &lp_price,
+                            obligation.lp_amount,
+                            lp_decimals.into(),
+                        )?)?;
+                        let debt_value = math::get_token_value(
+                            &base_price,
+                            obligation.base_debt,
+                            self.chamber.vault.base_decimals,
+                        )?
+                        .try_add(math::get_token_value(
+                            &quote_price,
+                            obligation.quote_debt,
+                            self.chamber.vault.quote_decimals,
+                        )?)?;
+                        let equity = if assets_value > debt_value {
+                            assets_value.try_sub(debt_value)?
+                        } else {
+                            Decimal::zero()
+                        };
+
+                        let (base_target_debt, quote_target_debt) = math::get_borrow_amounts(
+                            &equity,
+                            &base_price,
+                            &quote_price,
+                            self.chamber.vault.base_decimals,
+                            self.chamber.vault.quote_decimals,
+                            self.chamber.strategy.leverage,
+                            self.chamber.strategy.is_base_volatile,
+                        )?;
+
+                        math::get_debt_drift_bps(obligation.base_debt, base_target_debt)?.max(
+                            math::get_debt_drift_bps(obligation.quote_debt, quote_target_debt)?,
+                        )
+                    };
+
+                    if drift_bps == 0 || drift_bps < self.chamber.risk.rebalance_drift_bps {
+                        return Err(error::ChamberError::RebalanceNotNeeded.into());
+                    }
+                }
+
+                let (base_debt_before, quote_debt_before) = self.chamber.get_total_debt();
+
+                // 9. Withdraw lp from vault
+                bindings::tulip::leveraged::raydium::withdraw_raydium_vault_close(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::WithdrawFarm {
+                        Box::new(bindings::tulip::leveraged::raydium::WithdrawFarm {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: alt.user_farm.clone(),
                             obligation_vault_address: alt.obligation_vault_address.clone(),
@@ -265,16 +420,16 @@ impl<'c, 'info> RebalanceChamber<'info> {
                     ),
                     0,
                     0,
-                    0,
-                    100,
+                    source_obligation,
+                    withdraw_percent,
                     0,
                 )?;
 
-                // 3. Remove liquidity from AMM for lp tokens
-                cpi::tulip::leveraged::remove_liquidity_new(
+                // 10. Remove liquidity from AMM for lp tokens
+                bindings::tulip::leveraged::remove_liquidity_new(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::RemoveLiquidityNew {
+                        Box::new(bindings::tulip::leveraged::RemoveLiquidityNew {
                             user_farm: alt.user_farm.clone(),
                             obligation_vault_address: alt.obligation_vault_address.clone(),
                             leveraged_farm: alt.leveraged_farm.clone(),
@@ -313,15 +468,15 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    source_obligation,
                     0,
                 )?;
 
-                // 4. Swap AMM tokens for liquidity
-                cpi::tulip::leveraged::raydium::swap_to_repay_raydium(
+                // 11. Swap AMM tokens for liquidity
+                bindings::tulip::leveraged::raydium::swap_to_repay_raydium(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::SwapToRepayRaydium {
+                        Box::new(bindings::tulip::leveraged::raydium::SwapToRepayRaydium {
                             authority: self.chamber_authority.to_account_info(),
                             leveraged_farm: alt.leveraged_farm.clone(),
                             user_farm: alt.user_farm.clone(),
@@ -366,43 +521,47 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    source_obligation,
                 )?;
 
-                // 5. Repay lending obligation
-                cpi::tulip::leveraged::repay_obligation_liquidity_external(
+                // 12. Repay lending obligation
+                bindings::tulip::leveraged::repay_obligation_liquidity_external(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::RepayObligationLiquidityExternal {
-                            authority: self.chamber_authority.to_account_info(),
-                            user_farm: alt.user_farm.clone(),
-                            user_farm_obligation: alt.user_farm_obligation.clone(),
-                            leveraged_farm: alt.leveraged_farm.clone(),
-                            coin_source_token_account: alt.coin_source_token_account.clone(),
-                            coin_destination_token_account: alt
-                                .coin_destination_token_account
-                                .clone(),
-                            pc_source_token_account: alt.pc_source_token_account.clone(),
-                            pc_destination_token_account: alt.pc_destination_token_account.clone(),
-                            coin_reserve_account: alt.coin_reserve_account.clone(),
-                            pc_reserve_account: alt.pc_reserve_account.clone(),
-                            lending_market_account: alt.lending_market_account.clone(),
-                            lending_market_authority: alt.lending_market_authority.clone(),
-                            clock_sysvar: self.clock_sysvar.clone(),
-                            token_program: self.token_program.clone(),
-                            lending_program: alt.lending_program.clone(),
-                            lp_pyth_price_account: alt.lp_pyth_price_account.clone(),
-                            coin_price_account: alt.base_price_account.clone(),
-                            pc_price_account: alt.quote_price_account.clone(),
-                            vault_account: alt.vault.clone(),
-                            user_coin_token_account: self.chamber_base_token.to_account_info(),
-                            user_pc_token_account: self.chamber_quote_token.to_account_info(),
-                            position_info_account: alt.position_info_account.clone(),
-                            first_reserve: alt.first_reserve.clone(),
-                            first_reserve_price: alt.first_reserve_price.clone(),
-                            second_reserve: alt.second_reserve.clone(),
-                            second_reserve_price: alt.second_reserve_price.clone(),
-                        }),
+                        Box::new(
+                            bindings::tulip::leveraged::RepayObligationLiquidityExternal {
+                                authority: self.chamber_authority.to_account_info(),
+                                user_farm: alt.user_farm.clone(),
+                                user_farm_obligation: alt.user_farm_obligation.clone(),
+                                leveraged_farm: alt.leveraged_farm.clone(),
+                                coin_source_token_account: alt.coin_source_token_account.clone(),
+                                coin_destination_token_account: alt
+                                    .coin_destination_token_account
+                                    .clone(),
+                                pc_source_token_account: alt.pc_source_token_account.clone(),
+                                pc_destination_token_account: alt
+                                    .pc_destination_token_account
+                                    .clone(),
+                                coin_reserve_account: alt.coin_reserve_account.clone(),
+                                pc_reserve_account: alt.pc_reserve_account.clone(),
+                                lending_market_account: alt.lending_market_account.clone(),
+                                lending_market_authority: alt.lending_market_authority.clone(),
+                                clock_sysvar: self.clock_sysvar.clone(),
+                                token_program: self.token_program.clone(),
+                                lending_program: alt.lending_program.clone(),
+                                lp_pyth_price_account: alt.lp_pyth_price_account.clone(),
+                                coin_price_account: alt.base_price_account.clone(),
+                                pc_price_account: alt.quote_price_account.clone(),
+                                vault_account: alt.vault.clone(),
+                                user_coin_token_account: self.chamber_base_token.to_account_info(),
+                                user_pc_token_account: self.chamber_quote_token.to_account_info(),
+                                position_info_account: alt.position_info_account.clone(),
+                                first_reserve: alt.first_reserve.clone(),
+                                first_reserve_price: alt.first_reserve_price.clone(),
+                                second_reserve: alt.second_reserve.clone(),
+                                second_reserve_price: alt.second_reserve_price.clone(),
+                            },
+                        ),
                         &[&[
                             utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
                             self.chamber.key().as_ref(),
@@ -410,77 +569,78 @@ impl<'c, 'info> RebalanceChamber<'info> {
                         ]],
                     ),
                     &vec![alt.first_reserve.key(), alt.second_reserve.key()],
-                    0,
+                    source_obligation,
+                )?;
+
+                // 13. Sync source obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(source_obligation)?,
+                    alt.user_farm_obligation,
+                    &self.chamber_farm_program.key(),
                 )?;
 
+                // 14. Record repaid debt and reconcile it with market obligations
+                let (base_debt_after, quote_debt_after) = self.chamber.get_total_debt();
+                self.chamber.debt.record_repay(
+                    base_debt_before.saturating_sub(base_debt_after),
+                    quote_debt_before.saturating_sub(quote_debt_after),
+                );
+                self.chamber.reconcile_debt();
+
                 self.chamber_base_token.reload()?;
                 self.chamber_quote_token.reload()?;
 
                 let base_amount = self.chamber_base_token.amount;
                 let quote_amount = self.chamber_quote_token.amount;
 
-                // 6. Get base token price and decimals
+                // 15. Get base token price and decimals
                 let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_base_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 7. Calculate chamber base token value in `base_price`
-                let chamber_base_value = base_price
-                    .try_mul(base_amount)?
-                    .try_div(self.chamber.vault.base_decimals)?;
+                // 16. Calculate chamber base token value in `base_price`
+                let chamber_base_value = math::get_token_value(
+                    &base_price,
+                    base_amount,
+                    self.chamber.vault.base_decimals,
+                )?;
 
-                // 8. Get quote token price and decimals
+                // 17. Get quote token price and decimals
                 let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
                     &self.chamber_quote_oracle.data.as_ref().borrow(),
                 )?;
 
-                // 9. Calculate chamber quote token value in `quote_price`
-                let chamber_quote_value = quote_price
-                    .try_mul(quote_amount)?
-                    .try_div(self.chamber.vault.quote_decimals)?;
+                // 18. Calculate chamber quote token value in `quote_price`
+                let chamber_quote_value = math::get_token_value(
+                    &quote_price,
+                    quote_amount,
+                    self.chamber.vault.quote_decimals,
+                )?;
 
-                // 10. Calculate total chamber deposit value
+                // 19. Calculate total chamber deposit value
                 let chamber_total_value = chamber_base_value.try_add(chamber_quote_value)?;
 
-                // 11. Calculate base and quote borrow amount
-                let (chamber_base_borrow_amount, chamber_quote_borrow_amount) = {
-                    let (volatile_price, underlying_price) =
-                        if self.chamber.strategy.is_base_volatile {
-                            (base_price, quote_price)
-                        } else {
-                            (quote_price, base_price)
-                        };
-
-                    let volatile_borrow_amount = chamber_total_value
-                        .try_mul(3)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(volatile_price)?
-                        .try_floor_u64()?;
-
-                    let underlying_borrow_amount = chamber_total_value
-                        .try_mul(1)?
-                        .try_div(4)?
-                        .try_mul(self.chamber.strategy.leverage - 1)?
-                        .try_div(underlying_price)?
-                        .try_floor_u64()?;
-
-                    if self.chamber.strategy.is_base_volatile {
-                        (volatile_borrow_amount, underlying_borrow_amount)
-                    } else {
-                        (underlying_borrow_amount, volatile_borrow_amount)
-                    }
-                };
-
-                // 12. Deposit and borrow tokens with leverage
-                cpi::tulip::leveraged::deposit_borrow_dual(
+                // 20. Calculate base and quote borrow amount
+                let (chamber_base_borrow_amount, chamber_quote_borrow_amount) =
+                    math::get_borrow_amounts(
+                        &chamber_total_value,
+                        &base_price,
+                        &quote_price,
+                        self.chamber.vault.base_decimals,
+                        self.chamber.vault.quote_decimals,
+                        self.chamber.strategy.leverage,
+                        self.chamber.strategy.is_base_volatile,
+                    )?;
+
+                // 21. Deposit and borrow tokens with leverage
+                bindings::tulip::leveraged::deposit_borrow_dual(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::DepositBorrowDual {
+                        Box::new(bindings::tulip::leveraged::DepositBorrowDual {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: alt.user_farm.to_account_info(),
                             leveraged_farm: alt.leveraged_farm.to_account_info(),
-                            user_farm_obligation: alt.user_farm_obligation.to_account_info(),
+                            user_farm_obligation: alt.target_user_farm_obligation.to_account_info(),
                             coin_source_token_account: self.chamber_base_token.to_account_info(),
                             coin_destination_token_account: alt
                                 .coin_destination_token_account
@@ -523,7 +683,9 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             borrow_authorizer: alt.borrow_authorizer.to_account_info(),
                             lp_pyth_price_account: alt.lp_pyth_price_account.to_account_info(),
                             vault_account: alt.vault_account.to_account_info(),
-                            position_info_account: alt.position_info_account.to_account_info(),
+                            position_info_account: alt
+                                .target_position_info_account
+                                .to_account_info(),
                             rent: self.rent_sysvar.clone(),
                             token_program: self.token_program.clone(),
                             system_program: self.system_program.clone(),
@@ -538,18 +700,18 @@ impl<'c, 'info> RebalanceChamber<'info> {
                     quote_amount,
                     chamber_base_borrow_amount,
                     chamber_quote_borrow_amount,
-                    0,
+                    target_obligation,
                 )?;
 
-                // 13. Swap tokens via AMM
-                cpi::tulip::leveraged::raydium::swap_tokens_raydium_stats(
+                // 22. Swap tokens via AMM
+                bindings::tulip::leveraged::raydium::swap_tokens_raydium_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::RaydiumSwap {
+                        Box::new(bindings::tulip::leveraged::raydium::RaydiumSwap {
                             authority: self.chamber_authority.to_account_info(),
                             leveraged_farm: alt.leveraged_farm.to_account_info(),
                             user_farm: alt.user_farm.to_account_info(),
-                            user_farm_obligation: alt.user_farm_obligation.to_account_info(),
+                            user_farm_obligation: alt.target_user_farm_obligation.to_account_info(),
                             token_program: self.token_program.clone(),
                             vault_signer: alt.vault_signer.to_account_info(),
                             swap_or_liquidity_program_id: alt
@@ -580,7 +742,9 @@ impl<'c, 'info> RebalanceChamber<'info> {
                                 .lending_market_authority
                                 .to_account_info(),
                             lending_program: alt.lending_program.to_account_info(),
-                            position_info_account: alt.position_info_account.to_account_info(),
+                            position_info_account: alt
+                                .target_position_info_account
+                                .to_account_info(),
                         }),
                         &[&[
                             utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
@@ -588,14 +752,14 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    target_obligation,
                 )?;
 
-                // 14. Deposit tokens into lp
-                cpi::tulip::leveraged::add_liquidity_stats(
+                // 23. Deposit tokens into lp
+                bindings::tulip::leveraged::add_liquidity_stats(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::AddLiquidity {
+                        Box::new(bindings::tulip::leveraged::AddLiquidity {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: alt.user_farm.to_account_info(),
                             leveraged_farm: alt.leveraged_farm.to_account_info(),
@@ -617,17 +781,21 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             lev_farm_pc_token_account: alt
                                 .lev_farm_pc_token_account
                                 .to_account_info(),
-                            user_lp_token_account: alt.user_lp_token_account.to_account_info(),
+                            user_lp_token_account: alt
+                                .target_obligation_lp_token_account
+                                .to_account_info(),
                             pyth_price_account: alt.pyth_price_account.to_account_info(),
                             lending_market_account: alt.lending_market_account.to_account_info(),
-                            user_farm_obligation: alt.user_farm_obligation.to_account_info(),
+                            user_farm_obligation: alt.target_user_farm_obligation.to_account_info(),
                             derived_lending_market_authority: alt
                                 .lending_market_authority
                                 .to_account_info(),
                             lending_program: alt.lending_program.to_account_info(),
                             clock: self.clock_sysvar.clone(),
                             dex_program: alt.dex_program.to_account_info(),
-                            position_info_account: alt.position_info_account.to_account_info(),
+                            position_info_account: alt
+                                .target_position_info_account
+                                .to_account_info(),
                         }),
                         &[&[
                             utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
@@ -635,26 +803,28 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    target_obligation,
                 )?;
 
-                // 15. Deposit lp tokens into tulip vault
-                cpi::tulip::leveraged::raydium::deposit_raydium_vault(
+                // 24. Deposit lp tokens into tulip vault
+                bindings::tulip::leveraged::raydium::deposit_raydium_vault(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::DepositFarm {
+                        Box::new(bindings::tulip::leveraged::raydium::DepositFarm {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: alt.user_farm.to_account_info(),
                             obligation_vault_address: alt
-                                .obligation_vault_address
+                                .target_obligation_vault_address
                                 .to_account_info(),
                             leveraged_farm: alt.leveraged_farm.to_account_info(),
                             vault_program: alt.vault_program.to_account_info(),
-                            authority_token_account: alt.authority_token_account.to_account_info(),
+                            authority_token_account: alt
+                                .target_obligation_lp_token_account
+                                .to_account_info(),
                             vault_pda_account: alt.vault_pda_account.to_account_info(),
                             vault: alt.vault.to_account_info(),
                             lp_token_account: alt.lp_token_account.to_account_info(),
-                            user_balance_account: alt.user_balance_account.to_account_info(),
+                            user_balance_account: alt.target_user_balance_account.to_account_info(),
                             system_program: self.system_program.clone(),
                             stake_program_id: alt.stake_program_id.to_account_info(),
                             pool_id: alt.pool_id.to_account_info(),
@@ -676,9 +846,11 @@ impl<'c, 'info> RebalanceChamber<'info> {
                             clock: self.clock_sysvar.clone(),
                             rent: self.rent_sysvar.clone(),
                             token_program_id: self.token_program.clone(),
-                            user_balance_metadata: alt.user_balance_metadata.to_account_info(),
+                            user_balance_metadata: alt
+                                .target_user_balance_metadata
+                                .to_account_info(),
                             lending_market_account: alt.lending_market_account.to_account_info(),
-                            user_farm_obligation: alt.user_farm_obligation.to_account_info(),
+                            user_farm_obligation: alt.target_user_farm_obligation.to_account_info(),
                             lending_market_authority: alt
                                 .lending_market_authority
                                 .to_account_info(),
@@ -692,8 +864,39 @@ impl<'c, 'info> RebalanceChamber<'info> {
                     ),
                     0,
                     0,
-                    0,
+                    u64::from(target_obligation),
+                )?;
+
+                // 25. Sync target obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(target_obligation)?,
+                    alt.target_user_farm_obligation,
+                    &self.chamber_farm_program.key(),
                 )?;
+
+                // 26. Record borrowed debt and reconcile it with market obligations
+                self.chamber
+                    .debt
+                    .record_borrow(chamber_base_borrow_amount, chamber_quote_borrow_amount)?;
+                self.chamber.reconcile_debt();
+
+                // 27. Make target obligation active, so next deposits are added into it
+                self.chamber.active_obligation = target_obligation;
+
+                // 28. Emit `Rebalanced` event
+                emit!(events::Rebalanced {
+                    chamber: self.chamber.key(),
+                    source_obligation,
+                    target_obligation,
+                    withdraw_percent,
+                    base_amount,
+                    quote_amount,
+                    base_borrow_amount: chamber_base_borrow_amount,
+                    quote_borrow_amount: chamber_quote_borrow_amount,
+                    base_price: base_price.to_scaled_val()?,
+                    quote_price: quote_price.to_scaled_val()?,
+                    nav: chamber_total_value.try_floor_u64()?,
+                });
             }
         };
 
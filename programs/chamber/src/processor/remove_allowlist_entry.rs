@@ -0,0 +1,31 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveAllowlistEntry<'info> {
+    #[account(constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            utils::ALLOWLIST_ENTRY_PREFIX.as_bytes(),
+            chamber.key().as_ref(),
+            allowlist_entry.user.as_ref(),
+        ],
+        bump,
+        constraint = allowlist_entry.chamber == chamber.key()
+    )]
+    pub allowlist_entry: Box<Account<'info, state::AllowlistEntry>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+impl<'info> RemoveAllowlistEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. `AllowlistEntry` rent is reclaimed by `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveFarmEntry<'info> {
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == admin.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [utils::FARM_ENTRY_PREFIX.as_bytes(), farm_entry.farm.as_ref()],
+        bump
+    )]
+    pub farm_entry: Box<Account<'info, state::FarmEntry>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> RemoveFarmEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. `FarmEntry` rent is reclaimed by `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveOracleEntry<'info> {
+    #[account(
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == admin.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [utils::ORACLE_ENTRY_PREFIX.as_bytes(), oracle_entry.mint.as_ref()],
+        bump
+    )]
+    pub oracle_entry: Box<Account<'info, state::OracleEntry>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+impl<'info> RemoveOracleEntry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. `OracleEntry` rent is reclaimed by `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{error, state};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(is_paused: bool)]
+pub struct SetChamberPause<'info> {
+    #[account(
+        mut,
+        constraint = chamber.config.owner == signer.key()
+            || chamber.roles.is_guardian(signer.key) @ error::ChamberError::Unauthorized
+    )]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// `Chamber` owner or guardian, which may only pause.
+    pub signer: Signer<'info>,
+}
+
+impl<'info> SetChamberPause<'info> {
+    pub fn process(&mut self, is_paused: bool) -> Result<()> {
+        // 1. Ensure, that only owner resumes `Chamber`
+        if !is_paused && self.chamber.config.owner != self.signer.key() {
+            return Err(error::ChamberError::Unauthorized.into());
+        }
+
+        // 2. Suspend or resume `Chamber` deposits and rebalances
+        self.chamber.access.is_paused = is_paused;
+
+        Ok(())
+    }
+}
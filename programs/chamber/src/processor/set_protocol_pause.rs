@@ -0,0 +1,25 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(is_paused: bool)]
+pub struct SetProtocolPause<'info> {
+    #[account(
+        mut,
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == admin.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    pub admin: Signer<'info>,
+}
+
+impl<'info> SetProtocolPause<'info> {
+    pub fn process(&mut self, is_paused: bool) -> Result<()> {
+        // 1. Suspend or resume chamber creation, deposits and rebalances protocol wide
+        self.protocol_config.is_paused = is_paused;
+
+        Ok(())
+    }
+}
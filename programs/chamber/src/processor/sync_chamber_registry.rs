@@ -0,0 +1,42 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SyncChamberRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [
+            utils::CHAMBER_REGISTRY_PREFIX.as_bytes(),
+            chamber_registry.index.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub chamber_registry: Box<Account<'info, state::ChamberRegistry>>,
+
+    /// CHECK: Registered chamber, may be already closed.
+    pub chamber: UncheckedAccount<'info>,
+}
+
+impl<'info> SyncChamberRegistry<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        // 1. Resolve `Chamber` status, closed chambers are not owned by program anymore
+        let status = if *self.chamber.owner != crate::ID || self.chamber.data_is_empty() {
+            state::ChamberStatus::Closed
+        } else {
+            let chamber = state::Chamber::try_deserialize(&mut &self.chamber.data.borrow()[..])?;
+
+            if chamber.access.is_paused {
+                state::ChamberStatus::Paused
+            } else {
+                state::ChamberStatus::Active
+            }
+        };
+
+        // 2. Update registered `Chamber` status
+        self.chamber_registry
+            .get_entry_mut(self.chamber.key)?
+            .status = status;
+
+        Ok(())
+    }
+}
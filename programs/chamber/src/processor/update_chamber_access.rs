@@ -0,0 +1,24 @@
+use crate::state;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(is_allowlist_enabled: bool, gatekeeper: Pubkey)]
+pub struct UpdateChamberAccess<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UpdateChamberAccess<'info> {
+    pub fn process(&mut self, is_allowlist_enabled: bool, gatekeeper: Pubkey) -> Result<()> {
+        // 1. Update `Chamber` access control
+        self.chamber.access = state::ChamberAccess::new(
+            is_allowlist_enabled,
+            &gatekeeper,
+            self.chamber.access.is_paused,
+        );
+
+        Ok(())
+    }
+}
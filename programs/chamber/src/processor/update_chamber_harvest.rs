@@ -0,0 +1,35 @@
+use crate::state;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(reward_mint: Pubkey, swap_amm: Pubkey, reward_farm: Pubkey, is_reward_to_base: bool, max_slippage_bps: u64)]
+pub struct UpdateChamberHarvest<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UpdateChamberHarvest<'info> {
+    pub fn process(
+        &mut self,
+        reward_mint: Pubkey,
+        swap_amm: Pubkey,
+        reward_farm: Pubkey,
+        is_reward_to_base: bool,
+        max_slippage_bps: u64,
+    ) -> Result<()> {
+        // 1. Update `Chamber` reward harvesting config, timelocked performance fee
+        // is changed by config change only
+        self.chamber.harvest = state::ChamberHarvest::new(
+            &reward_mint,
+            &swap_amm,
+            &reward_farm,
+            is_reward_to_base,
+            self.chamber.harvest.performance_fee_bps,
+            max_slippage_bps,
+        )?;
+
+        Ok(())
+    }
+}
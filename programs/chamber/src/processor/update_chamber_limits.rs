@@ -0,0 +1,20 @@
+use crate::state;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(tvl_cap: u64, max_user_deposit: u64, min_deposit: u64)]
+pub struct UpdateChamberLimits<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UpdateChamberLimits<'info> {
+    pub fn process(&mut self, tvl_cap: u64, max_user_deposit: u64, min_deposit: u64) -> Result<()> {
+        // 1. Update `Chamber` deposit limits
+        self.chamber.limits = state::ChamberLimits::new(tvl_cap, max_user_deposit, min_deposit)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{error, state};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(liquidation_threshold_bps: u64, safety_margin_bps: u64, target_ltv_bps: u64, rebalance_drift_bps: u64, max_slippage_bps: u64)]
+pub struct UpdateChamberRisk<'info> {
+    #[account(
+        mut,
+        constraint = chamber.config.owner == signer.key()
+            || chamber.roles.is_strategist(signer.key) @ error::ChamberError::Unauthorized
+    )]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    /// `Chamber` owner or strategist.
+    pub signer: Signer<'info>,
+}
+
+impl<'info> UpdateChamberRisk<'info> {
+    pub fn process(
+        &mut self,
+        liquidation_threshold_bps: u64,
+        safety_margin_bps: u64,
+        target_ltv_bps: u64,
+        rebalance_drift_bps: u64,
+        max_slippage_bps: u64,
+    ) -> Result<()> {
+        // 1. Ensure, that strategist keeps owner liquidation threshold
+        if self.chamber.config.owner != self.signer.key()
+            && self.chamber.risk.liquidation_threshold_bps != liquidation_threshold_bps
+        {
+            return Err(error::ChamberError::Unauthorized.into());
+        }
+
+        let risk = state::ChamberRisk::new(
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_slippage_bps,
+        )?;
+
+        // 2. Ensure, that safety margin, target LTV, slippage and rebalance drift are within owner bounds
+        self.chamber.risk_bounds.assert_risk(&risk)?;
+
+        // 3. Update `Chamber` obligation health config
+        self.chamber.risk = risk;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::state;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(min_safety_margin_bps: u64, max_safety_margin_bps: u64, min_target_ltv_bps: u64, max_target_ltv_bps: u64, max_slippage_bps: u64, min_rebalance_drift_bps: u64, max_rebalance_drift_bps: u64)]
+pub struct UpdateChamberRiskBounds<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UpdateChamberRiskBounds<'info> {
+    pub fn process(
+        &mut self,
+        min_safety_margin_bps: u64,
+        max_safety_margin_bps: u64,
+        min_target_ltv_bps: u64,
+        max_target_ltv_bps: u64,
+        max_slippage_bps: u64,
+        min_rebalance_drift_bps: u64,
+        max_rebalance_drift_bps: u64,
+    ) -> Result<()> {
+        let risk_bounds = state::ChamberRiskBounds::new(
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_slippage_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+        )?;
+
+        // 1. Ensure, that configured obligation health is within new bounds
+        if self.chamber.risk.is_enabled() {
+            risk_bounds.assert_risk(&self.chamber.risk)?;
+        }
+
+        // 2. Update `Chamber` obligation health bounds
+        self.chamber.risk_bounds = risk_bounds;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::state;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(strategist: Pubkey, guardian: Pubkey)]
+pub struct UpdateChamberRoles<'info> {
+    #[account(mut, constraint = chamber.config.owner == owner.key())]
+    pub chamber: Box<Account<'info, state::Chamber>>,
+
+    pub owner: Signer<'info>,
+}
+
+impl<'info> UpdateChamberRoles<'info> {
+    pub fn process(&mut self, strategist: Pubkey, guardian: Pubkey) -> Result<()> {
+        // 1. Update `Chamber` strategist and guardian
+        self.chamber.roles = state::ChamberRoles::new(&strategist, &guardian);
+
+        Ok(())
+    }
+}
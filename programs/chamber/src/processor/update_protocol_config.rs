@@ -0,0 +1,30 @@
+use crate::{state, utils};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(admin: Pubkey, protocol_fee_bps: u64, farm_programs: Vec<Pubkey>, oracle_programs: Vec<Pubkey>)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(
+        mut,
+        seeds = [utils::PROTOCOL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = protocol_config.admin == signer.key()
+    )]
+    pub protocol_config: Box<Account<'info, state::ProtocolConfig>>,
+
+    pub signer: Signer<'info>,
+}
+
+impl<'info> UpdateProtocolConfig<'info> {
+    pub fn process(
+        &mut self,
+        admin: Pubkey,
+        protocol_fee_bps: u64,
+        farm_programs: Vec<Pubkey>,
+        oracle_programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        // 1. Update admin, protocol fee and allowed programs
+        self.protocol_config
+            .update(&admin, protocol_fee_bps, &farm_programs, &oracle_programs)
+    }
+}
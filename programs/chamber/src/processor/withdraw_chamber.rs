@@ -1,10 +1,17 @@
-use crate::{cpi, error, state, utils};
+use crate::{bindings, error, events, math, position, state, utils};
 use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 #[derive(Accounts)]
-#[instruction(base_amount: u64, quote_amount: u64)]
+#[instruction(
+    shares_amount: u64,
+    output_mint: Option<Pubkey>,
+    min_base_out: u64,
+    min_quote_out: u64,
+    unwrap_native: bool
+)]
 pub struct WithdrawChamber<'info> {
+    #[account(mut)]
     pub chamber: Box<Account<'info, state::Chamber>>,
 
     #[account(
@@ -20,13 +27,20 @@ pub struct WithdrawChamber<'info> {
     )]
     pub user_account: Box<Account<'info, state::UserAccount>>,
 
-    #[account(constraint = user_shares.mint == chamber_shares_mint.key())]
+    #[account(
+        mut,
+        constraint = user_shares.key() == user_account.shares,
+        constraint = user_shares.owner == user.key(),
+    )]
     pub user_shares: Box<Account<'info, token::TokenAccount>>,
 
+    #[account(mut, constraint = user_base_token.mint == chamber.vault.base_mint)]
     pub user_base_token: Box<Account<'info, token::TokenAccount>>,
+
+    #[account(mut, constraint = user_quote_token.mint == chamber.vault.quote_mint)]
     pub user_quote_token: Box<Account<'info, token::TokenAccount>>,
 
-    #[account(constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
+    #[account(mut, constraint = chamber_shares_mint.key() == chamber.config.shares_mint)]
     pub chamber_shares_mint: Box<Account<'info, token::Mint>>,
 
     #[account(mut, constraint = chamber_base_token.key() == chamber.vault.base)]
@@ -58,6 +72,22 @@ pub struct WithdrawChamber<'info> {
     #[account(constraint = chamber_farm_program.key() == chamber.strategy.farm_program)]
     pub chamber_farm_program: UncheckedAccount<'info>,
 
+    /// CHECK: Uninitialized temporary native token account, used to unwrap withdrawn native token.
+    #[account(
+        mut,
+        seeds = [
+            utils::NATIVE_TOKEN_PREFIX.as_bytes(),
+            user_account.key().as_ref(),
+        ],
+        bump
+    )]
+    pub native_token: UncheckedAccount<'info>,
+
+    /// CHECK: Native token(wrapped SOL) mint.
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
     pub user: Signer<'info>,
 
     pub clock_sysvar: Sysvar<'info, Clock>,
@@ -70,19 +100,66 @@ impl<'c, 'info> WithdrawChamber<'info> {
     pub fn process(
         &mut self,
         remaining_accounts: &'c [AccountInfo<'info>],
-        base_amount: u64,
-        quote_amount: u64,
+        shares_amount: u64,
+        output_mint: Option<Pubkey>,
+        min_base_out: u64,
+        min_quote_out: u64,
+        unwrap_native: bool,
     ) -> Result<()> {
         // 1. Ensure, that `UserAccount` in correct state
         self.user_account
             .assert_status(state::UserAccountStatus::Ready)?;
 
-        // 2. Ensure, that `User` is withdrawing something valuable
-        if base_amount == 0 && quote_amount == 0 {
+        // 2. Ensure, that `User` is withdrawing something valuable and owns withdrawn shares
+        if shares_amount == 0 {
             return Err(error::ChamberError::InsufficientFunds.into());
         }
 
-        // 3. Process market specific logic
+        if shares_amount > self.user_shares.amount {
+            return Err(error::ChamberError::InsufficientShares.into());
+        }
+
+        // 3. Calculate position part, which should be unwound for withdrawn shares
+        let shares_supply = self.chamber_shares_mint.supply;
+        let withdraw_percent = math::get_withdraw_percent(shares_amount, shares_supply)?;
+
+        // 4. Burn withdrawn shares
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.chamber_shares_mint.to_account_info(),
+                    from: self.user_shares.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            shares_amount,
+        )?;
+
+        // 5. Release deposited amounts of withdrawn shares
+        let base_price = tulipv2_sdk_common::pyth::load_pyth_price(
+            &self.chamber_base_oracle.data.as_ref().borrow(),
+        )?;
+        let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
+            &self.chamber_quote_oracle.data.as_ref().borrow(),
+        )?;
+
+        let nav_before = self
+            .chamber
+            .vault
+            .get_total_value(&base_price, &quote_price)?;
+
+        self.chamber.vault.withdraw(shares_amount, shares_supply)?;
+
+        let nav_after = self
+            .chamber
+            .vault
+            .get_total_value(&base_price, &quote_price)?;
+
+        self.user_account
+            .record_withdraw(nav_before.saturating_sub(nav_after));
+
+        // 6. Process market specific logic
         match self.chamber.strategy.market {
             state::ChamberMarket::Tulip => {
                 let user_farm = &remaining_accounts[0];
@@ -150,12 +227,56 @@ impl<'c, 'info> WithdrawChamber<'info> {
                 let pc_reserve_account = &remaining_accounts[62];
                 let lp_pyth_price_account = &remaining_accounts[63];
 
-                // 4. Withdraw lp from vault
-                // TODO: Calculate percentage
-                cpi::tulip::leveraged::raydium::withdraw_raydium_vault_close(
+                let obligation_index = self.chamber.active_obligation;
+
+                // 7. Ensure, that farm, obligation and swap accounts belong to `Chamber` market
+                if leveraged_farm.key() != self.chamber.strategy.farm {
+                    return Err(error::ChamberError::InvalidFarm.into());
+                }
+
+                position::tulip::assert_amm_accounts(
+                    &position::tulip::load_leveraged_farm(
+                        leveraged_farm,
+                        &self.chamber_farm_program.key(),
+                    )?,
+                    liquidity_program_id,
+                    amm_id,
+                    amm_open_orders,
+                    amm_quantities_or_target_orders,
+                    pool_coin_token_account,
+                    pool_pc_token_account,
+                    serum_market,
+                    serum_coin_vault_account,
+                    serum_pc_vault_account,
+                )?;
+
+                position::tulip::assert_user_farm_obligation(
+                    user_farm,
+                    user_farm_obligation,
+                    obligation_index,
+                    &self.chamber.config.authority,
+                    &self.chamber.strategy.farm,
+                    &self.chamber_farm_program.key(),
+                )?;
+
+                let chamber_base_amount = self.chamber_base_token.amount;
+                let chamber_quote_amount = self.chamber_quote_token.amount;
+
+                // 8. Sync and reconcile `Chamber` debt, accrued before repay
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
+                self.chamber.reconcile_debt();
+
+                let (base_debt_before, quote_debt_before) = self.chamber.get_total_debt();
+
+                // 9. Withdraw lp from vault
+                bindings::tulip::leveraged::raydium::withdraw_raydium_vault_close(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::WithdrawFarm {
+                        Box::new(bindings::tulip::leveraged::raydium::WithdrawFarm {
                             authority: self.chamber_authority.to_account_info(),
                             user_farm: user_farm.clone(),
                             obligation_vault_address: obligation_vault_address.clone(),
@@ -194,16 +315,16 @@ impl<'c, 'info> WithdrawChamber<'info> {
                     ),
                     0,
                     0,
-                    0,
-                    0,
+                    obligation_index,
+                    withdraw_percent,
                     0,
                 )?;
 
-                // 5. Remove liquidity from AMM for lp tokens
-                cpi::tulip::leveraged::remove_liquidity_new(
+                // 10. Remove liquidity from AMM for lp tokens
+                bindings::tulip::leveraged::remove_liquidity_new(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::RemoveLiquidityNew {
+                        Box::new(bindings::tulip::leveraged::RemoveLiquidityNew {
                             user_farm: user_farm.clone(),
                             obligation_vault_address: obligation_vault_address.clone(),
                             leveraged_farm: leveraged_farm.clone(),
@@ -241,15 +362,15 @@ impl<'c, 'info> WithdrawChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
                     0,
                 )?;
 
-                // 6. Swap AMM tokens for liquidity
-                cpi::tulip::leveraged::raydium::swap_to_repay_raydium(
+                // 11. Swap AMM tokens for liquidity
+                bindings::tulip::leveraged::raydium::swap_to_repay_raydium(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::raydium::SwapToRepayRaydium {
+                        Box::new(bindings::tulip::leveraged::raydium::SwapToRepayRaydium {
                             authority: self.chamber_authority.to_account_info(),
                             leveraged_farm: leveraged_farm.clone(),
                             user_farm: user_farm.clone(),
@@ -293,41 +414,44 @@ impl<'c, 'info> WithdrawChamber<'info> {
                             &[self.chamber.config.authority_bump],
                         ]],
                     ),
-                    0,
+                    obligation_index,
                 )?;
 
-                // 7. Repay lending obligation
-                cpi::tulip::leveraged::repay_obligation_liquidity_external(
+                // 12. Repay lending obligation
+                bindings::tulip::leveraged::repay_obligation_liquidity_external(
                     CpiContext::new_with_signer(
                         self.chamber_farm_program.to_account_info(),
-                        Box::new(cpi::tulip::leveraged::RepayObligationLiquidityExternal {
-                            authority: self.chamber_authority.to_account_info(),
-                            user_farm: user_farm.clone(),
-                            user_farm_obligation: user_farm_obligation.clone(),
-                            leveraged_farm: leveraged_farm.clone(),
-                            coin_source_token_account: coin_source_token_account.clone(),
-                            coin_destination_token_account: coin_destination_token_account.clone(),
-                            pc_source_token_account: pc_source_token_account.clone(),
-                            pc_destination_token_account: pc_destination_token_account.clone(),
-                            coin_reserve_account: coin_reserve_account.clone(),
-                            pc_reserve_account: pc_reserve_account.clone(),
-                            lending_market_account: lending_market_account.clone(),
-                            lending_market_authority: lending_market_authority.clone(),
-                            clock_sysvar: self.clock_sysvar.clone(),
-                            token_program: self.token_program.clone(),
-                            lending_program: lending_program.clone(),
-                            lp_pyth_price_account: lp_pyth_price_account.clone(),
-                            coin_price_account: base_price_account.clone(),
-                            pc_price_account: quote_price_account.clone(),
-                            vault_account: vault.clone(),
-                            user_coin_token_account: self.chamber_base_token.to_account_info(),
-                            user_pc_token_account: self.chamber_quote_token.to_account_info(),
-                            position_info_account: position_info_account.clone(),
-                            first_reserve: first_reserve.clone(),
-                            first_reserve_price: first_reserve_price.clone(),
-                            second_reserve: second_reserve.clone(),
-                            second_reserve_price: second_reserve_price.clone(),
-                        }),
+                        Box::new(
+                            bindings::tulip::leveraged::RepayObligationLiquidityExternal {
+                                authority: self.chamber_authority.to_account_info(),
+                                user_farm: user_farm.clone(),
+                                user_farm_obligation: user_farm_obligation.clone(),
+                                leveraged_farm: leveraged_farm.clone(),
+                                coin_source_token_account: coin_source_token_account.clone(),
+                                coin_destination_token_account: coin_destination_token_account
+                                    .clone(),
+                                pc_source_token_account: pc_source_token_account.clone(),
+                                pc_destination_token_account: pc_destination_token_account.clone(),
+                                coin_reserve_account: coin_reserve_account.clone(),
+                                pc_reserve_account: pc_reserve_account.clone(),
+                                lending_market_account: lending_market_account.clone(),
+                                lending_market_authority: lending_market_authority.clone(),
+                                clock_sysvar: self.clock_sysvar.clone(),
+                                token_program: self.token_program.clone(),
+                                lending_program: lending_program.clone(),
+                                lp_pyth_price_account: lp_pyth_price_account.clone(),
+                                coin_price_account: base_price_account.clone(),
+                                pc_price_account: quote_price_account.clone(),
+                                vault_account: vault.clone(),
+                                user_coin_token_account: self.chamber_base_token.to_account_info(),
+                                user_pc_token_account: self.chamber_quote_token.to_account_info(),
+                                position_info_account: position_info_account.clone(),
+                                first_reserve: first_reserve.clone(),
+                                first_reserve_price: first_reserve_price.clone(),
+                                second_reserve: second_reserve.clone(),
+                                second_reserve_price: second_reserve_price.clone(),
+                            },
+                        ),
                         &[&[
                             utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
                             self.chamber.key().as_ref(),
@@ -335,8 +459,301 @@ impl<'c, 'info> WithdrawChamber<'info> {
                         ]],
                     ),
                     &vec![first_reserve.key(), second_reserve.key()],
-                    0,
+                    obligation_index,
+                )?;
+
+                // 13. Sync `Chamber` obligation position with market obligation
+                position::tulip::sync_obligation(
+                    self.chamber.get_obligation_mut(obligation_index)?,
+                    user_farm_obligation,
+                    &self.chamber_farm_program.key(),
+                )?;
+
+                // 14. Record repaid debt and reconcile it with market obligations
+                let (base_debt_after, quote_debt_after) = self.chamber.get_total_debt();
+                self.chamber.debt.record_repay(
+                    base_debt_before.saturating_sub(base_debt_after),
+                    quote_debt_before.saturating_sub(quote_debt_after),
+                );
+                self.chamber.reconcile_debt();
+
+                // 15. Calculate tokens amount, released by position unwinding
+                self.chamber_base_token.reload()?;
+                self.chamber_quote_token.reload()?;
+
+                let released_base_amount = self
+                    .chamber_base_token
+                    .amount
+                    .saturating_sub(chamber_base_amount);
+                let released_quote_amount = self
+                    .chamber_quote_token
+                    .amount
+                    .saturating_sub(chamber_quote_amount);
+
+                // 16. Calculate released tokens amount, which belongs to withdrawn shares,
+                // rest of released tokens stays in `Chamber`
+                let redeem_base_amount = math::get_redeem_amount(
+                    released_base_amount,
+                    shares_amount,
+                    shares_supply,
+                    withdraw_percent,
+                )?;
+                let redeem_quote_amount = math::get_redeem_amount(
+                    released_quote_amount,
+                    shares_amount,
+                    shares_supply,
+                    withdraw_percent,
                 )?;
+
+                // 17. Swap redeemed tokens into `output_mint`, if single-asset withdrawal requested
+                let (withdraw_base_amount, withdraw_quote_amount) = match output_mint {
+                    Some(output_mint) => {
+                        let is_base_output = if output_mint == self.chamber.vault.base_mint {
+                            true
+                        } else if output_mint == self.chamber.vault.quote_mint {
+                            false
+                        } else {
+                            return Err(error::ChamberError::InvalidOutputMint.into());
+                        };
+
+                        let (
+                            source_token_account,
+                            destination_token_account,
+                            swap_amount,
+                            kept_amount,
+                            min_output_amount,
+                        ) = if is_base_output {
+                            (
+                                self.chamber_quote_token.to_account_info(),
+                                self.chamber_base_token.to_account_info(),
+                                redeem_quote_amount,
+                                redeem_base_amount,
+                                min_base_out,
+                            )
+                        } else {
+                            (
+                                self.chamber_base_token.to_account_info(),
+                                self.chamber_quote_token.to_account_info(),
+                                redeem_base_amount,
+                                redeem_quote_amount,
+                                min_quote_out,
+                            )
+                        };
+
+                        let destination_amount_before = if is_base_output {
+                            self.chamber_base_token.amount
+                        } else {
+                            self.chamber_quote_token.amount
+                        };
+
+                        if swap_amount > 0 {
+                            bindings::raydium::swap_base_in(
+                                CpiContext::new_with_signer(
+                                    liquidity_program_id.clone(),
+                                    Box::new(bindings::raydium::SwapBaseIn {
+                                        token_program: self.token_program.clone(),
+                                        amm_id: amm_id.clone(),
+                                        amm_authority: amm_authority.clone(),
+                                        amm_open_orders: amm_open_orders.clone(),
+                                        amm_target_orders: amm_quantities_or_target_orders.clone(),
+                                        pool_coin_token_account: pool_coin_token_account.clone(),
+                                        pool_pc_token_account: pool_pc_token_account.clone(),
+                                        serum_program_id: serum_program_id.clone(),
+                                        serum_market: serum_market.clone(),
+                                        serum_bids: serum_bids.clone(),
+                                        serum_asks: serum_asks.clone(),
+                                        serum_event_queue: serum_event_queue.clone(),
+                                        serum_coin_vault_account: serum_coin_vault_account.clone(),
+                                        serum_pc_vault_account: serum_pc_vault_account.clone(),
+                                        serum_vault_signer: serum_vault_signer.clone(),
+                                        user_source_token_account: source_token_account,
+                                        user_destination_token_account: destination_token_account,
+                                        user_source_owner: self.chamber_authority.to_account_info(),
+                                    }),
+                                    &[&[
+                                        utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                        self.chamber.key().as_ref(),
+                                        &[self.chamber.config.authority_bump],
+                                    ]],
+                                ),
+                                swap_amount,
+                                min_output_amount.saturating_sub(kept_amount),
+                            )?;
+
+                            self.chamber_base_token.reload()?;
+                            self.chamber_quote_token.reload()?;
+                        }
+
+                        let destination_amount_after = if is_base_output {
+                            self.chamber_base_token.amount
+                        } else {
+                            self.chamber_quote_token.amount
+                        };
+
+                        let output_amount = kept_amount
+                            .checked_add(
+                                destination_amount_after.saturating_sub(destination_amount_before),
+                            )
+                            .ok_or(error::ChamberError::MathOverflow)?;
+
+                        if is_base_output {
+                            (output_amount, 0)
+                        } else {
+                            (0, output_amount)
+                        }
+                    }
+                    None => (redeem_base_amount, redeem_quote_amount),
+                };
+
+                // 18. Ensure, that `User` receives at least `min_base_out` and `min_quote_out` tokens
+                if withdraw_base_amount < min_base_out || withdraw_quote_amount < min_quote_out {
+                    return Err(error::ChamberError::SlippageExceeded.into());
+                }
+
+                // 19. Unwrap native token and transfer lamports to user
+                let (transfer_base_amount, transfer_quote_amount) = if unwrap_native {
+                    let (is_base_native, chamber_native_token, withdraw_native_amount) =
+                        if self.chamber.vault.base_mint == token::spl_token::native_mint::ID {
+                            (
+                                true,
+                                self.chamber_base_token.to_account_info(),
+                                withdraw_base_amount,
+                            )
+                        } else if self.chamber.vault.quote_mint == token::spl_token::native_mint::ID
+                        {
+                            (
+                                false,
+                                self.chamber_quote_token.to_account_info(),
+                                withdraw_quote_amount,
+                            )
+                        } else {
+                            return Err(error::ChamberError::NativeTokenNotSupported.into());
+                        };
+
+                    if withdraw_native_amount > 0 {
+                        let (_, native_token_bump) =
+                            utils::derive_native_token_address(&self.user_account.key());
+
+                        utils::create_pda_account(
+                            &self.native_token.to_account_info(),
+                            &self.user.to_account_info(),
+                            &self.system_program.to_account_info(),
+                            self.rent_sysvar.minimum_balance(token::TokenAccount::LEN),
+                            token::TokenAccount::LEN as u64,
+                            &token::ID,
+                            &[
+                                utils::NATIVE_TOKEN_PREFIX.as_bytes(),
+                                self.user_account.key().as_ref(),
+                                &[native_token_bump],
+                            ],
+                        )?;
+
+                        token::initialize_account(CpiContext::new(
+                            self.token_program.to_account_info(),
+                            token::InitializeAccount {
+                                account: self.native_token.to_account_info(),
+                                mint: self.native_mint.to_account_info(),
+                                authority: self.chamber_authority.to_account_info(),
+                                rent: self.rent_sysvar.to_account_info(),
+                            },
+                        ))?;
+
+                        token::transfer(
+                            CpiContext::new_with_signer(
+                                self.token_program.to_account_info(),
+                                token::Transfer {
+                                    from: chamber_native_token,
+                                    to: self.native_token.to_account_info(),
+                                    authority: self.chamber_authority.to_account_info(),
+                                },
+                                &[&[
+                                    utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                    self.chamber.key().as_ref(),
+                                    &[self.chamber.config.authority_bump],
+                                ]],
+                            ),
+                            withdraw_native_amount,
+                        )?;
+
+                        // Closing releases both rent and withdrawn lamports to user
+                        token::close_account(CpiContext::new_with_signer(
+                            self.token_program.to_account_info(),
+                            token::CloseAccount {
+                                account: self.native_token.to_account_info(),
+                                destination: self.user.to_account_info(),
+                                authority: self.chamber_authority.to_account_info(),
+                            },
+                            &[&[
+                                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                self.chamber.key().as_ref(),
+                                &[self.chamber.config.authority_bump],
+                            ]],
+                        ))?;
+                    }
+
+                    if is_base_native {
+                        (0, withdraw_quote_amount)
+                    } else {
+                        (withdraw_base_amount, 0)
+                    }
+                } else {
+                    (withdraw_base_amount, withdraw_quote_amount)
+                };
+
+                // 20. Transfer base token to user
+                if transfer_base_amount > 0 {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            self.token_program.to_account_info(),
+                            token::Transfer {
+                                from: self.chamber_base_token.to_account_info(),
+                                to: self.user_base_token.to_account_info(),
+                                authority: self.chamber_authority.to_account_info(),
+                            },
+                            &[&[
+                                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                self.chamber.key().as_ref(),
+                                &[self.chamber.config.authority_bump],
+                            ]],
+                        ),
+                        transfer_base_amount,
+                    )?;
+                }
+
+                // 21. Transfer quote token to user
+                if transfer_quote_amount > 0 {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            self.token_program.to_account_info(),
+                            token::Transfer {
+                                from: self.chamber_quote_token.to_account_info(),
+                                to: self.user_quote_token.to_account_info(),
+                                authority: self.chamber_authority.to_account_info(),
+                            },
+                            &[&[
+                                utils::CHAMBER_AUTHORITY_PREFIX.as_bytes(),
+                                self.chamber.key().as_ref(),
+                                &[self.chamber.config.authority_bump],
+                            ]],
+                        ),
+                        transfer_quote_amount,
+                    )?;
+                }
+
+                // 22. Emit `Withdrawn` event
+                emit!(events::Withdrawn {
+                    chamber: self.chamber.key(),
+                    user: self.user.key(),
+                    base_amount: withdraw_base_amount,
+                    quote_amount: withdraw_quote_amount,
+                    output_mint,
+                    is_native_unwrapped: unwrap_native,
+                    shares_burned: shares_amount,
+                    base_price: base_price.to_scaled_val()?,
+                    quote_price: quote_price.to_scaled_val()?,
+                    nav_before,
+                    nav_after,
+                });
             }
         };
 
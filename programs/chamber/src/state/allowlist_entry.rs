@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Represent permission for `user` to use allowlist enabled `state::Chamber`.
+#[account]
+#[derive(Debug)]
+pub struct AllowlistEntry {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+}
+
+impl AllowlistEntry {
+    pub const LEN: usize = 8 + 32 * 2;
+
+    pub fn init(&mut self, chamber: &Pubkey, user: &Pubkey) {
+        self.chamber = *chamber;
+        self.user = *user;
+    }
+}
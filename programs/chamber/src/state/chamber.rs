@@ -1,4 +1,8 @@
-use super::{ChamberConfig, ChamberStrategy, ChamberVault};
+use super::{
+    ChamberAccess, ChamberConfig, ChamberDebt, ChamberHarvest, ChamberLimits, ChamberObligation,
+    ChamberRisk, ChamberRiskBounds, ChamberRoles, ChamberStrategy, ChamberTimelock, ChamberVault,
+};
+use crate::{error, utils};
 use anchor_lang::prelude::*;
 
 /// Protocol vault, which represents pool position.
@@ -13,10 +17,61 @@ pub struct Chamber {
 
     /// `Chamber` config.
     pub config: ChamberConfig,
+
+    /// `Chamber` deposit limits.
+    pub limits: ChamberLimits,
+
+    /// `Chamber` access control.
+    pub access: ChamberAccess,
+
+    /// `Chamber` reward harvesting config.
+    pub harvest: ChamberHarvest,
+
+    /// Per market obligation positions.
+    pub obligations: [ChamberObligation; utils::OBLIGATIONS_COUNT],
+
+    /// Obligation, which receives new deposits.
+    pub active_obligation: u8,
+
+    /// `Chamber` obligation health config.
+    pub risk: ChamberRisk,
+
+    /// Borrowed principal and accrued interest across all obligations.
+    pub debt: ChamberDebt,
+
+    /// Account layout version, upgraded by `migrate_chamber`.
+    pub version: u8,
+
+    /// `Chamber` strategist and guardian.
+    pub roles: ChamberRoles,
+
+    /// Leverage and fee change timelock.
+    pub timelock: ChamberTimelock,
+
+    /// Owner bounds of obligation health config.
+    pub risk_bounds: ChamberRiskBounds,
+
+    /// Reserved for state of future layout versions.
+    pub reserved: [u8; utils::CHAMBER_RESERVED_LEN],
 }
 
 impl Chamber {
-    pub const LEN: usize = 8 + ChamberStrategy::LEN + ChamberVault::LEN + ChamberConfig::LEN;
+    pub const LEN: usize = 8
+        + ChamberStrategy::LEN
+        + ChamberVault::LEN
+        + ChamberConfig::LEN
+        + ChamberLimits::LEN
+        + ChamberAccess::LEN
+        + ChamberHarvest::LEN
+        + ChamberObligation::LEN * utils::OBLIGATIONS_COUNT
+        + 1
+        + ChamberRisk::LEN
+        + ChamberDebt::LEN
+        + 1
+        + ChamberRoles::LEN
+        + ChamberTimelock::LEN
+        + ChamberRiskBounds::LEN
+        + utils::CHAMBER_RESERVED_LEN;
 
     pub fn init(
         &mut self,
@@ -27,5 +82,51 @@ impl Chamber {
         self.strategy = strategy.clone();
         self.vault = vault.clone();
         self.config = config.clone();
+        self.limits = ChamberLimits::default();
+        self.access = ChamberAccess::default();
+        self.harvest = ChamberHarvest::default();
+        self.obligations = Default::default();
+        self.active_obligation = 0;
+        self.risk = ChamberRisk::default();
+        self.debt = ChamberDebt::default();
+        self.version = utils::CHAMBER_VERSION;
+        self.roles = ChamberRoles::new(&config.owner, &config.owner);
+        self.timelock = ChamberTimelock::new();
+        self.risk_bounds = ChamberRiskBounds::default();
+        self.reserved = [0; utils::CHAMBER_RESERVED_LEN];
+    }
+
+    /// Ensure, that `obligation_index` points to existing obligation.
+    pub fn assert_obligation_index(obligation_index: u8) -> Result<()> {
+        if usize::from(obligation_index) >= utils::OBLIGATIONS_COUNT {
+            return Err(error::ChamberError::InvalidObligation.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn get_obligation_mut(&mut self, obligation_index: u8) -> Result<&mut ChamberObligation> {
+        Self::assert_obligation_index(obligation_index)?;
+
+        Ok(&mut self.obligations[usize::from(obligation_index)])
+    }
+
+    /// Total base and quote debt across all obligations.
+    pub fn get_total_debt(&self) -> (u64, u64) {
+        self.obligations
+            .iter()
+            .fold((0, 0), |(base_debt, quote_debt), obligation| {
+                (
+                    base_debt.saturating_add(obligation.base_debt),
+                    quote_debt.saturating_add(obligation.quote_debt),
+                )
+            })
+    }
+
+    /// Reconcile `debt` with synced debt of all obligations.
+    pub fn reconcile_debt(&mut self) {
+        let (base_debt, quote_debt) = self.get_total_debt();
+
+        self.debt.reconcile(base_debt, quote_debt);
     }
 }
@@ -0,0 +1,67 @@
+use crate::{error, utils};
+use anchor_lang::prelude::*;
+
+/// Provide access control configuration for `state::Chamber`.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberAccess {
+    /// Indicates, that only allowed users can create `UserAccount` and deposit.
+    pub is_allowlist_enabled: bool,
+
+    /// Key, which signature allows user to create `UserAccount` and deposit.
+    ///
+    /// `Pubkey::default()` means no gatekeeper.
+    pub gatekeeper: Pubkey,
+
+    /// Indicates, that deposits and rebalances are suspended.
+    pub is_paused: bool,
+}
+
+impl ChamberAccess {
+    pub const LEN: usize = 1 + 32 + 1;
+
+    pub fn new(is_allowlist_enabled: bool, gatekeeper: &Pubkey, is_paused: bool) -> Self {
+        ChamberAccess {
+            is_allowlist_enabled,
+            gatekeeper: *gatekeeper,
+            is_paused,
+        }
+    }
+
+    pub fn has_gatekeeper(&self) -> bool {
+        self.gatekeeper != Pubkey::default()
+    }
+
+    /// Ensure, that `user` is allowed to use allowlist enabled `chamber`, given `permit`, which is
+    /// either `user` allowlist entry or signed gatekeeper.
+    pub fn assert_allowed(
+        &self,
+        chamber: &Pubkey,
+        user: &Pubkey,
+        permit: &AccountInfo,
+    ) -> Result<()> {
+        if !self.is_allowlist_enabled {
+            return Ok(());
+        }
+
+        let is_gatekeeper_signed =
+            self.has_gatekeeper() && permit.key() == self.gatekeeper && permit.is_signer;
+
+        let is_allowlisted = permit.key() == utils::derive_allowlist_entry_address(chamber, user).0
+            && permit.owner == &crate::id()
+            && !permit.data_is_empty();
+
+        if !is_gatekeeper_signed && !is_allowlisted {
+            return Err(error::ChamberError::UserNotAllowed.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_not_paused(&self) -> Result<()> {
+        if self.is_paused {
+            return Err(error::ChamberError::ChamberPaused.into());
+        }
+
+        Ok(())
+    }
+}
@@ -11,10 +11,13 @@ pub struct ChamberConfig {
 
     /// Chamber nonce(index).
     pub nonce: u8,
+
+    /// Address Lookup Table with static market accounts, default if not created.
+    pub lookup_table: Pubkey,
 }
 
 impl ChamberConfig {
-    pub const LEN: usize = 32 * 4 + 1 + 1;
+    pub const LEN: usize = 32 * 4 + 1 + 1 + 32;
 
     pub fn new(
         authority: &Pubkey,
@@ -31,6 +34,11 @@ impl ChamberConfig {
             shares_mint: *shares_mint,
             authority_bump,
             nonce,
+            lookup_table: Pubkey::default(),
         }
     }
+
+    pub fn has_lookup_table(&self) -> bool {
+        self.lookup_table != Pubkey::default()
+    }
 }
@@ -0,0 +1,84 @@
+use crate::error;
+use anchor_lang::prelude::*;
+
+/// Provide borrowed debt accounting of `state::Chamber` for base and quote legs.
+///
+/// Principal changes with borrows and repays only, while accrued interest is
+/// snapshot of market debt above principal, taken on every reconcile.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberDebt {
+    /// Base token amount, borrowed and not yet repaid.
+    pub base_principal: u64,
+
+    /// Quote token amount, borrowed and not yet repaid.
+    pub quote_principal: u64,
+
+    /// Base token interest, accrued on `base_principal`.
+    pub base_interest: u64,
+
+    /// Quote token interest, accrued on `quote_principal`.
+    pub quote_interest: u64,
+}
+
+impl ChamberDebt {
+    pub const LEN: usize = 8 * 4;
+
+    pub fn record_borrow(&mut self, base_amount: u64, quote_amount: u64) -> Result<()> {
+        self.base_principal = self
+            .base_principal
+            .checked_add(base_amount)
+            .ok_or(error::ChamberError::MathOverflow)?;
+        self.quote_principal = self
+            .quote_principal
+            .checked_add(quote_amount)
+            .ok_or(error::ChamberError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Repaid amounts cover accrued interest first, then principal.
+    pub fn record_repay(&mut self, base_amount: u64, quote_amount: u64) {
+        repay_leg(
+            &mut self.base_principal,
+            &mut self.base_interest,
+            base_amount,
+        );
+        repay_leg(
+            &mut self.quote_principal,
+            &mut self.quote_interest,
+            quote_amount,
+        );
+    }
+
+    /// Reconcile with outstanding market debt, which includes accrued interest.
+    ///
+    /// Market debt below principal, e.g. after liquidation, lowers principal.
+    pub fn reconcile(&mut self, base_debt: u64, quote_debt: u64) {
+        reconcile_leg(&mut self.base_principal, &mut self.base_interest, base_debt);
+        reconcile_leg(
+            &mut self.quote_principal,
+            &mut self.quote_interest,
+            quote_debt,
+        );
+    }
+
+    /// Principal with accrued interest for base and quote legs.
+    pub fn get_total_debt(&self) -> (u64, u64) {
+        (
+            self.base_principal.saturating_add(self.base_interest),
+            self.quote_principal.saturating_add(self.quote_interest),
+        )
+    }
+}
+
+fn repay_leg(principal: &mut u64, interest: &mut u64, amount: u64) {
+    let interest_amount = amount.min(*interest);
+
+    *interest -= interest_amount;
+    *principal = principal.saturating_sub(amount - interest_amount);
+}
+
+fn reconcile_leg(principal: &mut u64, interest: &mut u64, debt: u64) {
+    *principal = debt.min(*principal);
+    *interest = debt - *principal;
+}
@@ -0,0 +1,65 @@
+use crate::{error, math};
+use anchor_lang::prelude::*;
+
+/// Provide reward harvesting config for `state::Chamber`.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberHarvest {
+    /// Farm reward token mint.
+    pub reward_mint: Pubkey,
+
+    /// Raydium AMM, used to swap rewards into base or quote token.
+    pub swap_amm: Pubkey,
+
+    /// Raydium staking pool, which accrues `reward_mint` rewards for `Chamber`.
+    pub reward_farm: Pubkey,
+
+    /// Swap rewards into base token, otherwise into quote token.
+    pub is_reward_to_base: bool,
+
+    /// Fee, charged from harvested rewards, in basis points.
+    pub performance_fee_bps: u64,
+
+    /// Max reward swap output shortfall to oracle price, in basis points.
+    pub max_slippage_bps: u64,
+}
+
+impl ChamberHarvest {
+    pub const LEN: usize = 32 * 3 + 1 + 8 * 2;
+
+    pub fn new(
+        reward_mint: &Pubkey,
+        swap_amm: &Pubkey,
+        reward_farm: &Pubkey,
+        is_reward_to_base: bool,
+        performance_fee_bps: u64,
+        max_slippage_bps: u64,
+    ) -> Result<Self> {
+        if performance_fee_bps > math::BPS {
+            return Err(error::ChamberError::InvalidFee.into());
+        }
+
+        if max_slippage_bps > math::BPS {
+            return Err(error::ChamberError::InvalidSlippage.into());
+        }
+
+        Ok(ChamberHarvest {
+            reward_mint: *reward_mint,
+            swap_amm: *swap_amm,
+            reward_farm: *reward_farm,
+            is_reward_to_base,
+            performance_fee_bps,
+            max_slippage_bps,
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.reward_mint != Pubkey::default()
+    }
+
+    /// Min reward swap output, given oracle based `expected_amount`.
+    pub fn get_min_out(&self, expected_amount: u64) -> Result<u64> {
+        let slippage_amount = math::get_fee_amount(expected_amount, self.max_slippage_bps)?;
+
+        Ok(expected_amount.saturating_sub(slippage_amount))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error;
+use anchor_lang::prelude::*;
+
+/// Provide deposit limits for `state::Chamber`.
+///
+/// All limits are denominated in deposit value(see `ChamberVault::get_total_value`),
+/// zero value disables related limit. User position is measured by value, deposited
+/// by user(see `UserAccount::deposited_value`), as shares are freely transferable.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberLimits {
+    /// Maximum `Chamber` total value.
+    pub tvl_cap: u64,
+
+    /// Maximum net deposited value of single user.
+    pub max_user_deposit: u64,
+
+    /// Minimum value of single deposit.
+    pub min_deposit: u64,
+}
+
+impl ChamberLimits {
+    pub const LEN: usize = 8 * 3;
+
+    pub fn new(tvl_cap: u64, max_user_deposit: u64, min_deposit: u64) -> Result<Self> {
+        if max_user_deposit != 0 && min_deposit > max_user_deposit {
+            return Err(error::ChamberError::InvalidLimits.into());
+        }
+
+        if tvl_cap != 0 && (min_deposit > tvl_cap || max_user_deposit > tvl_cap) {
+            return Err(error::ChamberError::InvalidLimits.into());
+        }
+
+        Ok(ChamberLimits {
+            tvl_cap,
+            max_user_deposit,
+            min_deposit,
+        })
+    }
+
+    /// Ensure, that `deposit_value` fits into limits, given current
+    /// `Chamber` total value and user net deposited value.
+    pub fn assert_deposit(
+        &self,
+        chamber_value: u64,
+        user_deposited_value: u64,
+        deposit_value: u64,
+    ) -> Result<()> {
+        if deposit_value < self.min_deposit {
+            return Err(error::ChamberError::DepositTooSmall.into());
+        }
+
+        if self.tvl_cap != 0
+            && chamber_value
+                .checked_add(deposit_value)
+                .ok_or(error::ChamberError::MathOverflow)?
+                > self.tvl_cap
+        {
+            return Err(error::ChamberError::DepositCapExceeded.into());
+        }
+
+        if self.max_user_deposit != 0
+            && user_deposited_value
+                .checked_add(deposit_value)
+                .ok_or(error::ChamberError::MathOverflow)?
+                > self.max_user_deposit
+        {
+            return Err(error::ChamberError::UserDepositLimitExceeded.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Provide position state of single market obligation, owned by `state::Chamber`.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberObligation {
+    /// Lp tokens, deposited by obligation.
+    pub lp_amount: u64,
+
+    /// Outstanding base token debt.
+    pub base_debt: u64,
+
+    /// Outstanding quote token debt.
+    pub quote_debt: u64,
+}
+
+impl ChamberObligation {
+    pub const LEN: usize = 8 * 3;
+
+    pub fn update(&mut self, lp_amount: u64, base_debt: u64, quote_debt: u64) {
+        self.lp_amount = lp_amount;
+        self.base_debt = base_debt;
+        self.quote_debt = quote_debt;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lp_amount == 0 && self.base_debt == 0 && self.quote_debt == 0
+    }
+}
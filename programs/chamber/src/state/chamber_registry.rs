@@ -0,0 +1,55 @@
+use crate::{error, state, utils};
+use anchor_lang::prelude::*;
+
+/// Represent single `state::Chamber`, listed in `ChamberRegistry`.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberRegistryEntry {
+    pub chamber: Pubkey,
+    pub market: state::ChamberMarket,
+    pub farm: Pubkey,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub nonce: u8,
+    pub status: state::ChamberStatus,
+}
+
+impl ChamberRegistryEntry {
+    pub const LEN: usize = 32 + 1 + 32 * 3 + 1 + 1;
+}
+
+/// Page of `state::Chamber`s, listed in creation order.
+///
+/// Pages are indexed sequentially from zero, see `state::ProtocolConfig::registry_pages`.
+#[account]
+#[derive(Debug)]
+pub struct ChamberRegistry {
+    pub index: u32,
+    pub entries: Vec<ChamberRegistryEntry>,
+}
+
+impl ChamberRegistry {
+    pub const LEN: usize =
+        8 + 4 + 4 + ChamberRegistryEntry::LEN * utils::MAX_CHAMBER_REGISTRY_ENTRIES;
+
+    pub fn init(&mut self, index: u32) {
+        self.index = index;
+        self.entries = vec![];
+    }
+
+    pub fn push(&mut self, entry: ChamberRegistryEntry) -> Result<()> {
+        if self.entries.len() >= utils::MAX_CHAMBER_REGISTRY_ENTRIES {
+            return Err(error::ChamberError::ChamberRegistryFull.into());
+        }
+
+        self.entries.push(entry);
+
+        Ok(())
+    }
+
+    pub fn get_entry_mut(&mut self, chamber: &Pubkey) -> Result<&mut ChamberRegistryEntry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.chamber == *chamber)
+            .ok_or_else(|| error::ChamberError::ChamberNotRegistered.into())
+    }
+}
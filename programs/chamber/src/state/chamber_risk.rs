@@ -0,0 +1,74 @@
+use crate::{error, math};
+use anchor_lang::prelude::*;
+
+/// Provide obligation health config for `state::Chamber`.
+///
+/// All ratios are debt value to position value ratios(LTV) in basis points,
+/// zero `liquidation_threshold_bps` disables deleveraging.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberRisk {
+    /// LTV, at which market liquidates obligation.
+    pub liquidation_threshold_bps: u64,
+
+    /// LTV distance to `liquidation_threshold_bps`, which triggers deleveraging.
+    pub safety_margin_bps: u64,
+
+    /// LTV, which deleveraging brings obligation back to.
+    pub target_ltv_bps: u64,
+
+    /// Max debt leg drift from target leverage debt, starting from which
+    /// obligation is rebalanced in place.
+    pub rebalance_drift_bps: u64,
+
+    /// Max deleveraging swap output shortfall from oracle price.
+    pub max_slippage_bps: u64,
+}
+
+impl ChamberRisk {
+    pub const LEN: usize = 8 * 5;
+
+    pub fn new(
+        liquidation_threshold_bps: u64,
+        safety_margin_bps: u64,
+        target_ltv_bps: u64,
+        rebalance_drift_bps: u64,
+        max_slippage_bps: u64,
+    ) -> Result<Self> {
+        let risk = ChamberRisk {
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_slippage_bps,
+        };
+
+        if liquidation_threshold_bps > math::BPS
+            || safety_margin_bps >= liquidation_threshold_bps
+            || target_ltv_bps >= risk.get_trigger_ltv_bps()
+            || rebalance_drift_bps == 0
+            || rebalance_drift_bps > math::BPS
+            || max_slippage_bps > math::BPS
+        {
+            return Err(error::ChamberError::InvalidRiskConfig.into());
+        }
+
+        Ok(risk)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.liquidation_threshold_bps != 0
+    }
+
+    /// Calculate min deleveraging swap output, given `expected_amount` at oracle price.
+    pub fn get_min_swap_out(&self, expected_amount: u64) -> Result<u64> {
+        let slippage_amount = math::get_fee_amount(expected_amount, self.max_slippage_bps)?;
+
+        Ok(expected_amount.saturating_sub(slippage_amount))
+    }
+
+    /// LTV, starting from which obligation should be deleveraged.
+    pub fn get_trigger_ltv_bps(&self) -> u64 {
+        self.liquidation_threshold_bps
+            .saturating_sub(self.safety_margin_bps)
+    }
+}
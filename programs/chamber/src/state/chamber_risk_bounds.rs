@@ -0,0 +1,117 @@
+use super::ChamberRisk;
+use crate::{error, math, utils};
+use anchor_lang::prelude::*;
+
+/// Provide owner bounds of `state::ChamberRisk`, tuned by strategist.
+///
+/// Zero bound falls back to protocol default, so zeroed bounds still restrict obligation health config.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberRiskBounds {
+    /// Min `state::ChamberRisk::safety_margin_bps`.
+    pub min_safety_margin_bps: u64,
+
+    /// Max `state::ChamberRisk::safety_margin_bps`.
+    pub max_safety_margin_bps: u64,
+
+    /// Min `state::ChamberRisk::target_ltv_bps`.
+    pub min_target_ltv_bps: u64,
+
+    /// Max `state::ChamberRisk::target_ltv_bps`.
+    pub max_target_ltv_bps: u64,
+
+    /// Max `state::ChamberRisk::max_slippage_bps`.
+    pub max_slippage_bps: u64,
+
+    /// Min `state::ChamberRisk::rebalance_drift_bps`.
+    pub min_rebalance_drift_bps: u64,
+
+    /// Max `state::ChamberRisk::rebalance_drift_bps`.
+    pub max_rebalance_drift_bps: u64,
+}
+
+impl ChamberRiskBounds {
+    pub const LEN: usize = 8 * 7;
+
+    pub fn new(
+        min_safety_margin_bps: u64,
+        max_safety_margin_bps: u64,
+        min_target_ltv_bps: u64,
+        max_target_ltv_bps: u64,
+        max_slippage_bps: u64,
+        min_rebalance_drift_bps: u64,
+        max_rebalance_drift_bps: u64,
+    ) -> Result<Self> {
+        let risk_bounds = ChamberRiskBounds {
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_slippage_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+        };
+
+        for (min, max) in risk_bounds.get_bounds() {
+            if min > max || max > math::BPS {
+                return Err(error::ChamberError::InvalidRiskConfig.into());
+            }
+        }
+
+        Ok(risk_bounds)
+    }
+
+    /// Get safety margin, target LTV, slippage and rebalance drift bounds,
+    /// with zero bounds resolved to protocol defaults.
+    pub fn get_bounds(&self) -> [(u64, u64); 4] {
+        let resolve = |bound: u64, default: u64| if bound == 0 { default } else { bound };
+
+        [
+            (
+                resolve(
+                    self.min_safety_margin_bps,
+                    utils::DEFAULT_MIN_SAFETY_MARGIN_BPS,
+                ),
+                resolve(
+                    self.max_safety_margin_bps,
+                    utils::DEFAULT_MAX_SAFETY_MARGIN_BPS,
+                ),
+            ),
+            (
+                resolve(self.min_target_ltv_bps, utils::DEFAULT_MIN_TARGET_LTV_BPS),
+                resolve(self.max_target_ltv_bps, utils::DEFAULT_MAX_TARGET_LTV_BPS),
+            ),
+            (
+                0,
+                resolve(self.max_slippage_bps, utils::DEFAULT_MAX_SLIPPAGE_BPS),
+            ),
+            (
+                resolve(
+                    self.min_rebalance_drift_bps,
+                    utils::DEFAULT_MIN_REBALANCE_DRIFT_BPS,
+                ),
+                resolve(
+                    self.max_rebalance_drift_bps,
+                    utils::DEFAULT_MAX_REBALANCE_DRIFT_BPS,
+                ),
+            ),
+        ]
+    }
+
+    /// Ensure, that `risk` safety margin, target LTV, slippage and rebalance drift are within bounds.
+    pub fn assert_risk(&self, risk: &ChamberRisk) -> Result<()> {
+        let values = [
+            risk.safety_margin_bps,
+            risk.target_ltv_bps,
+            risk.max_slippage_bps,
+            risk.rebalance_drift_bps,
+        ];
+
+        for (value, (min, max)) in values.into_iter().zip(self.get_bounds()) {
+            if value < min || value > max {
+                return Err(error::ChamberError::RiskOutOfBounds.into());
+            }
+        }
+
+        Ok(())
+    }
+}
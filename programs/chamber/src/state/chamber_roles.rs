@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Provide operational roles of `state::Chamber`, managed by `state::ChamberConfig::owner`.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberRoles {
+    /// Rebalances position and tunes obligation health config within owner bounds.
+    pub strategist: Pubkey,
+
+    /// Pauses `Chamber` along with owner and unwinds position regardless of obligation health.
+    pub guardian: Pubkey,
+}
+
+impl ChamberRoles {
+    pub const LEN: usize = 32 * 2;
+
+    pub fn new(strategist: &Pubkey, guardian: &Pubkey) -> Self {
+        ChamberRoles {
+            strategist: *strategist,
+            guardian: *guardian,
+        }
+    }
+
+    pub fn is_strategist(&self, key: &Pubkey) -> bool {
+        self.strategist == *key
+    }
+
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardian == *key
+    }
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+/// Represent `state::Chamber` lifecycle status, tracked by `state::ChamberRegistry`.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ChamberStatus {
+    Active,
+    Paused,
+    Closed,
+}
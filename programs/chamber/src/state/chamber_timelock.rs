@@ -0,0 +1,121 @@
+use crate::{error, math, utils};
+use anchor_lang::prelude::*;
+
+/// Provide `state::Chamber` config change, awaiting its effective timestamp.
+///
+/// Zero `effective_timestamp` marks no pending change.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberPendingConfig {
+    /// Proposed `state::ChamberStrategy::leverage`.
+    pub leverage: u64,
+
+    /// Proposed `state::ChamberHarvest::performance_fee_bps`.
+    pub performance_fee_bps: u64,
+
+    /// Proposed `ChamberTimelock::delay`.
+    pub delay: i64,
+
+    /// Unix timestamp, starting from which change may be executed.
+    pub effective_timestamp: i64,
+}
+
+impl ChamberPendingConfig {
+    pub const LEN: usize = 8 * 4;
+
+    pub fn new(
+        leverage: u64,
+        performance_fee_bps: u64,
+        delay: i64,
+        effective_timestamp: i64,
+    ) -> Result<Self> {
+        if performance_fee_bps > math::BPS {
+            return Err(error::ChamberError::InvalidFee.into());
+        }
+
+        if leverage == 0
+            || leverage > utils::MAX_LEVERAGE
+            || !(utils::MIN_CONFIG_CHANGE_DELAY..=utils::MAX_CONFIG_CHANGE_DELAY).contains(&delay)
+            || effective_timestamp <= 0
+        {
+            return Err(error::ChamberError::InvalidConfigChange.into());
+        }
+
+        Ok(ChamberPendingConfig {
+            leverage,
+            performance_fee_bps,
+            delay,
+            effective_timestamp,
+        })
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.effective_timestamp != 0
+    }
+}
+
+/// Provide timelock of leverage and fee changes for `state::Chamber`,
+/// giving depositors notice before change is executed.
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberTimelock {
+    /// Min seconds between config change proposal and its effective timestamp.
+    pub delay: i64,
+
+    /// Config change, applied by `execute_config_change`.
+    pub pending: ChamberPendingConfig,
+}
+
+impl ChamberTimelock {
+    pub const LEN: usize = 8 + ChamberPendingConfig::LEN;
+
+    /// Timelock with min delay and no pending change.
+    pub fn new() -> Self {
+        ChamberTimelock {
+            delay: utils::MIN_CONFIG_CHANGE_DELAY,
+            pending: ChamberPendingConfig::default(),
+        }
+    }
+
+    /// Store `pending` change, which is effective not earlier than `delay` from `now`.
+    pub fn propose(&mut self, pending: ChamberPendingConfig, now: i64) -> Result<()> {
+        if self.pending.is_pending() {
+            return Err(error::ChamberError::ConfigChangePending.into());
+        }
+
+        let min_effective_timestamp = now
+            .checked_add(self.delay)
+            .ok_or(error::ChamberError::MathOverflow)?;
+
+        if pending.effective_timestamp < min_effective_timestamp {
+            return Err(error::ChamberError::ConfigChangeTooEarly.into());
+        }
+
+        self.pending = pending;
+
+        Ok(())
+    }
+
+    /// Take pending change, which is effective at `now`, and apply its delay.
+    pub fn execute(&mut self, now: i64) -> Result<ChamberPendingConfig> {
+        if !self.pending.is_pending() {
+            return Err(error::ChamberError::NoConfigChangePending.into());
+        }
+
+        if now < self.pending.effective_timestamp {
+            return Err(error::ChamberError::ConfigChangeNotEffective.into());
+        }
+
+        let pending = std::mem::take(&mut self.pending);
+        self.delay = pending.delay;
+
+        Ok(pending)
+    }
+
+    /// Drop pending change.
+    pub fn cancel(&mut self) -> Result<ChamberPendingConfig> {
+        if !self.pending.is_pending() {
+            return Err(error::ChamberError::NoConfigChangePending.into());
+        }
+
+        Ok(std::mem::take(&mut self.pending))
+    }
+}
@@ -0,0 +1,64 @@
+use super::{
+    Chamber, ChamberAccess, ChamberConfig, ChamberDebt, ChamberHarvest, ChamberLimits, ChamberRisk,
+    ChamberRiskBounds, ChamberRoles, ChamberStrategy, ChamberTimelock, ChamberVault,
+};
+use crate::utils;
+use anchor_lang::prelude::*;
+
+/// `state::ChamberConfig` layout before address lookup tables.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberConfigV0 {
+    pub authority: Pubkey,
+    pub owner: Pubkey,
+    pub fee_manager: Pubkey,
+    pub shares_mint: Pubkey,
+    pub authority_bump: u8,
+    pub nonce: u8,
+}
+
+impl ChamberConfigV0 {
+    pub const LEN: usize = 32 * 4 + 1 + 1;
+}
+
+/// `Chamber` layout before account versioning, decoded by `migrate_chamber`.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberV0 {
+    pub strategy: ChamberStrategy,
+    pub vault: ChamberVault,
+    pub config: ChamberConfigV0,
+}
+
+impl ChamberV0 {
+    pub const LEN: usize = 8 + ChamberStrategy::LEN + ChamberVault::LEN + ChamberConfigV0::LEN;
+
+    /// Upgrade into current `Chamber` layout with default config of later state,
+    /// owner holds all roles until reassigned.
+    pub fn into_chamber(self) -> Chamber {
+        let config = ChamberConfig::new(
+            &self.config.authority,
+            &self.config.owner,
+            &self.config.fee_manager,
+            &self.config.shares_mint,
+            self.config.authority_bump,
+            self.config.nonce,
+        );
+
+        Chamber {
+            strategy: self.strategy,
+            vault: self.vault,
+            roles: ChamberRoles::new(&config.owner, &config.owner),
+            config,
+            limits: ChamberLimits::default(),
+            access: ChamberAccess::default(),
+            harvest: ChamberHarvest::default(),
+            obligations: Default::default(),
+            active_obligation: 0,
+            risk: ChamberRisk::default(),
+            debt: ChamberDebt::default(),
+            version: utils::CHAMBER_VERSION,
+            timelock: ChamberTimelock::new(),
+            risk_bounds: ChamberRiskBounds::default(),
+            reserved: [0; utils::CHAMBER_RESERVED_LEN],
+        }
+    }
+}
@@ -1,9 +1,6 @@
-use crate::error;
+use crate::{error, math};
 use anchor_lang::prelude::*;
-use tulipv2_sdk_common::math::{
-    common::{TryAdd, TryDiv, TryMul},
-    decimal::Decimal,
-};
+use tulipv2_sdk_common::math::{common::TryAdd, decimal::Decimal};
 
 /// Provide token-related data for `state::Chamber`.
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -79,17 +76,47 @@ impl ChamberVault {
         Ok(())
     }
 
-    /// TODO: Deprecate. Instead, calculate value from internal position.
+    /// Release deposited amounts, which belong to `shares_amount` out of `shares_supply`.
+    pub fn withdraw(&mut self, shares_amount: u64, shares_supply: u64) -> Result<()> {
+        if shares_supply == 0 || shares_amount > shares_supply {
+            return Err(error::ChamberError::MathOverflow.into());
+        }
+
+        let withdraw_base_amount = self
+            .base_amount
+            .checked_mul(shares_amount.into())
+            .ok_or(error::ChamberError::MathOverflow)?
+            / u128::from(shares_supply);
+        let withdraw_quote_amount = self
+            .quote_amount
+            .checked_mul(shares_amount.into())
+            .ok_or(error::ChamberError::MathOverflow)?
+            / u128::from(shares_supply);
+
+        self.base_amount -= withdraw_base_amount;
+        self.quote_amount -= withdraw_quote_amount;
+
+        Ok(())
+    }
+
+    /// Book value of deposited and compounded amounts, used to mint shares,
+    /// check deposit limits and report events `nav`.
+    ///
+    /// Differs from market equity of `views::ChamberNav`, since it excludes lp
+    /// price moves and debt interest, and unlike equity is not moved by
+    /// pending deposits, which have borrowed, but not yet provided lp.
     pub fn get_total_value(&self, base_price: &Decimal, quote_price: &Decimal) -> Result<u64> {
-        let base_amount = Decimal::from(self.base_amount);
-        let quote_amount = Decimal::from(self.quote_amount);
-
-        let base_value = base_price
-            .try_mul(base_amount)?
-            .try_div(self.base_decimals)?;
-        let quote_value = quote_price
-            .try_mul(quote_amount)?
-            .try_div(self.quote_decimals)?;
+        let base_amount: u64 = self
+            .base_amount
+            .try_into()
+            .map_err(|_| error::ChamberError::MathOverflow)?;
+        let quote_amount: u64 = self
+            .quote_amount
+            .try_into()
+            .map_err(|_| error::ChamberError::MathOverflow)?;
+
+        let base_value = math::get_token_value(base_price, base_amount, self.base_decimals)?;
+        let quote_value = math::get_token_value(quote_price, quote_amount, self.quote_decimals)?;
 
         Ok(base_value.try_add(quote_value)?.try_floor_u64()?)
     }
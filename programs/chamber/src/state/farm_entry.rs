@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Represent permission to create `state::Chamber`s over `farm`, owned by `farm_program`.
+#[account]
+#[derive(Debug)]
+pub struct FarmEntry {
+    pub farm: Pubkey,
+    pub farm_program: Pubkey,
+}
+
+impl FarmEntry {
+    pub const LEN: usize = 8 + 32 * 2;
+
+    pub fn init(&mut self, farm: &Pubkey, farm_program: &Pubkey) {
+        self.farm = *farm;
+        self.farm_program = *farm_program;
+    }
+}
@@ -1,15 +1,49 @@
+mod allowlist_entry;
 mod chamber;
+mod chamber_access;
 mod chamber_config;
+mod chamber_debt;
+mod chamber_harvest;
+mod chamber_limits;
 mod chamber_market;
+mod chamber_obligation;
+mod chamber_registry;
+mod chamber_risk;
+mod chamber_risk_bounds;
+mod chamber_roles;
+mod chamber_status;
 mod chamber_strategy;
+mod chamber_timelock;
+mod chamber_v0;
 mod chamber_vault;
+mod farm_entry;
+mod oracle_entry;
+mod protocol_config;
 mod user_account;
 mod user_account_status;
+mod user_account_v0;
 
+pub use allowlist_entry::*;
 pub use chamber::*;
+pub use chamber_access::*;
 pub use chamber_config::*;
+pub use chamber_debt::*;
+pub use chamber_harvest::*;
+pub use chamber_limits::*;
 pub use chamber_market::*;
+pub use chamber_obligation::*;
+pub use chamber_registry::*;
+pub use chamber_risk::*;
+pub use chamber_risk_bounds::*;
+pub use chamber_roles::*;
+pub use chamber_status::*;
 pub use chamber_strategy::*;
+pub use chamber_timelock::*;
+pub use chamber_v0::*;
 pub use chamber_vault::*;
+pub use farm_entry::*;
+pub use oracle_entry::*;
+pub use protocol_config::*;
 pub use user_account::*;
 pub use user_account_status::*;
+pub use user_account_v0::*;
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Represent `Pyth` product and price accounts, allowed as `mint` oracle of `state::Chamber`s.
+#[account]
+#[derive(Debug)]
+pub struct OracleEntry {
+    pub mint: Pubkey,
+    pub product: Pubkey,
+    pub price: Pubkey,
+}
+
+impl OracleEntry {
+    pub const LEN: usize = 8 + 32 * 3;
+
+    pub fn init(&mut self, mint: &Pubkey, product: &Pubkey, price: &Pubkey) {
+        self.mint = *mint;
+        self.product = *product;
+        self.price = *price;
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{error, math, utils};
+use anchor_lang::prelude::*;
+
+/// Singleton protocol wide config, shared by all `state::Chamber`s.
+#[account]
+#[derive(Debug)]
+pub struct ProtocolConfig {
+    /// Protocol admin, managing config and allowed farms.
+    pub admin: Pubkey,
+
+    /// Protocol share of `Chamber` performance fees, paid to `admin`, in basis points.
+    pub protocol_fee_bps: u64,
+
+    /// Farm programs, allowed as `Chamber` markets, unused slots are default pubkeys.
+    pub farm_programs: [Pubkey; utils::MAX_PROTOCOL_PROGRAMS],
+
+    /// Oracle programs, allowed to own `Chamber` price oracles, unused slots are default pubkeys.
+    pub oracle_programs: [Pubkey; utils::MAX_PROTOCOL_PROGRAMS],
+
+    /// Suspends chamber creation, deposits and rebalances of all `Chamber`s.
+    pub is_paused: bool,
+
+    /// Created `state::ChamberRegistry` pages.
+    pub registry_pages: u32,
+
+    /// `Chamber`s, listed by all `state::ChamberRegistry` pages.
+    pub chambers_count: u64,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + 32 + 8 + 32 * utils::MAX_PROTOCOL_PROGRAMS * 2 + 1 + 4 + 8;
+
+    pub fn init(&mut self, admin: &Pubkey) {
+        self.admin = *admin;
+        self.protocol_fee_bps = 0;
+        self.farm_programs = Default::default();
+        self.oracle_programs = Default::default();
+        self.is_paused = false;
+        self.registry_pages = 0;
+        self.chambers_count = 0;
+    }
+
+    pub fn update(
+        &mut self,
+        admin: &Pubkey,
+        protocol_fee_bps: u64,
+        farm_programs: &[Pubkey],
+        oracle_programs: &[Pubkey],
+    ) -> Result<()> {
+        if protocol_fee_bps > math::BPS {
+            return Err(error::ChamberError::InvalidFee.into());
+        }
+
+        self.admin = *admin;
+        self.protocol_fee_bps = protocol_fee_bps;
+        self.farm_programs = to_program_slots(farm_programs)?;
+        self.oracle_programs = to_program_slots(oracle_programs)?;
+
+        Ok(())
+    }
+
+    pub fn assert_not_paused(&self) -> Result<()> {
+        if self.is_paused {
+            return Err(error::ChamberError::ProtocolPaused.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_farm_program(&self, farm_program: &Pubkey) -> Result<()> {
+        if !is_program_allowed(&self.farm_programs, farm_program) {
+            return Err(error::ChamberError::FarmNotAllowed.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn assert_oracle_program(&self, oracle_program: &Pubkey) -> Result<()> {
+        if !is_program_allowed(&self.oracle_programs, oracle_program) {
+            return Err(error::ChamberError::OracleNotAllowed.into());
+        }
+
+        Ok(())
+    }
+
+    /// New `state::ChamberRegistry` page is created, only when all existing pages are full.
+    pub fn is_registry_full(&self) -> bool {
+        self.chambers_count
+            >= u64::from(self.registry_pages) * utils::MAX_CHAMBER_REGISTRY_ENTRIES as u64
+    }
+}
+
+fn to_program_slots(programs: &[Pubkey]) -> Result<[Pubkey; utils::MAX_PROTOCOL_PROGRAMS]> {
+    if programs.len() > utils::MAX_PROTOCOL_PROGRAMS {
+        return Err(error::ChamberError::TooManyPrograms.into());
+    }
+
+    let mut slots: [Pubkey; utils::MAX_PROTOCOL_PROGRAMS] = Default::default();
+    slots[..programs.len()].copy_from_slice(programs);
+
+    Ok(slots)
+}
+
+fn is_program_allowed(programs: &[Pubkey], program: &Pubkey) -> bool {
+    *program != Pubkey::default() && programs.contains(program)
+}
@@ -1,5 +1,5 @@
 use super::UserAccountStatus;
-use crate::error;
+use crate::{error, utils};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -13,16 +13,40 @@ pub struct UserAccount {
     pub locked_base_amount: u64,
     pub locked_quote_amount: u64,
     pub locked_shares_amount: u64,
+
+    /// Account layout version, upgraded by `migrate_user_account`.
+    pub version: u8,
+
+    /// Deposit value of completed deposits, net of withdrawn value, capped by
+    /// `state::ChamberLimits::max_user_deposit`.
+    pub deposited_value: u64,
+
+    /// Deposit value of pending deposit, added to `deposited_value`, once deposit is ended.
+    pub locked_value: u64,
+
+    /// Locked tokens of pending deposit, which are still idle in `Chamber` and
+    /// not moved into position, so may be refunded on cancel.
+    pub idle_base_amount: u64,
+    pub idle_quote_amount: u64,
+
+    /// Reserved for state of future layout versions.
+    pub reserved: [u8; utils::USER_ACCOUNT_RESERVED_LEN],
 }
 
 impl UserAccount {
-    pub const LEN: usize = 8 + 32 * 3 + 1 + 8 * 3;
+    pub const LEN: usize = 8 + 32 * 3 + 1 + 8 * 3 + 1 + 8 * 4 + utils::USER_ACCOUNT_RESERVED_LEN;
 
     pub fn init(&mut self, chamber: &Pubkey, user: &Pubkey, shares: &Pubkey) {
         self.chamber = *chamber;
         self.user = *user;
         self.shares = *shares;
         self.status = UserAccountStatus::Ready;
+        self.version = utils::USER_ACCOUNT_VERSION;
+        self.deposited_value = 0;
+        self.locked_value = 0;
+        self.idle_base_amount = 0;
+        self.idle_quote_amount = 0;
+        self.reserved = [0; utils::USER_ACCOUNT_RESERVED_LEN];
     }
 
     pub fn assert_status(&self, status: UserAccountStatus) -> Result<()> {
@@ -38,21 +62,64 @@ impl UserAccount {
         locked_base_amount: u64,
         locked_quote_amount: u64,
         locked_shares_amount: u64,
+        locked_value: u64,
     ) {
         self.status = UserAccountStatus::BeginDeposit;
         self.locked_base_amount = locked_base_amount;
         self.locked_quote_amount = locked_quote_amount;
         self.locked_shares_amount = locked_shares_amount;
+        self.locked_value = locked_value;
+        self.idle_base_amount = locked_base_amount;
+        self.idle_quote_amount = locked_quote_amount;
+    }
+
+    /// Locked tokens, moved from `Chamber` into position, are no longer refundable.
+    pub fn record_position_deposit(&mut self, base_amount: u64, quote_amount: u64) {
+        self.idle_base_amount = self.idle_base_amount.saturating_sub(base_amount);
+        self.idle_quote_amount = self.idle_quote_amount.saturating_sub(quote_amount);
+    }
+
+    /// Pending deposit may be cancelled, only while none of locked tokens is moved into position.
+    pub fn is_cancellable(&self) -> bool {
+        self.status != UserAccountStatus::Ready
+            && self.idle_base_amount >= self.locked_base_amount
+            && self.idle_quote_amount >= self.locked_quote_amount
     }
 
     pub fn process_deposit(&mut self) {
         self.status = UserAccountStatus::ProcessDeposit;
     }
 
-    pub fn end_deposit(&mut self) {
+    /// Complete pending deposit, recording its value.
+    pub fn end_deposit(&mut self) -> Result<()> {
+        self.record_deposit(self.locked_value)?;
+        self.cancel_deposit();
+
+        Ok(())
+    }
+
+    /// Drop pending deposit without recording its value.
+    pub fn cancel_deposit(&mut self) {
         self.status = UserAccountStatus::Ready;
         self.locked_base_amount = 0;
         self.locked_quote_amount = 0;
         self.locked_shares_amount = 0;
+        self.locked_value = 0;
+        self.idle_base_amount = 0;
+        self.idle_quote_amount = 0;
+    }
+
+    pub fn record_deposit(&mut self, deposit_value: u64) -> Result<()> {
+        self.deposited_value = self
+            .deposited_value
+            .checked_add(deposit_value)
+            .ok_or(error::ChamberError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Withdrawn value may exceed deposited value due to yield or transferred in shares.
+    pub fn record_withdraw(&mut self, withdraw_value: u64) {
+        self.deposited_value = self.deposited_value.saturating_sub(withdraw_value);
     }
 }
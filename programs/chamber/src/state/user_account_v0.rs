@@ -0,0 +1,39 @@
+use super::{UserAccount, UserAccountStatus};
+use crate::utils;
+use anchor_lang::prelude::*;
+
+/// `UserAccount` layout before account versioning, decoded by `migrate_user_account`.
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct UserAccountV0 {
+    pub chamber: Pubkey,
+    pub user: Pubkey,
+    pub shares: Pubkey,
+    pub status: UserAccountStatus,
+
+    pub locked_base_amount: u64,
+    pub locked_quote_amount: u64,
+    pub locked_shares_amount: u64,
+}
+
+impl UserAccountV0 {
+    pub const LEN: usize = UserAccount::LEN - 1 - 8 * 4 - utils::USER_ACCOUNT_RESERVED_LEN;
+
+    /// Upgrade into current `UserAccount` layout with zeroed reserved bytes.
+    pub fn into_user_account(self) -> UserAccount {
+        UserAccount {
+            chamber: self.chamber,
+            user: self.user,
+            shares: self.shares,
+            status: self.status,
+            locked_base_amount: self.locked_base_amount,
+            locked_quote_amount: self.locked_quote_amount,
+            locked_shares_amount: self.locked_shares_amount,
+            version: utils::USER_ACCOUNT_VERSION,
+            deposited_value: 0,
+            locked_value: 0,
+            idle_base_amount: 0,
+            idle_quote_amount: 0,
+            reserved: [0; utils::USER_ACCOUNT_RESERVED_LEN],
+        }
+    }
+}
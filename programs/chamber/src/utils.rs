@@ -4,6 +4,177 @@ pub const SHARES_DECIMALS: u8 = 6;
 pub const CHAMBER_PREFIX: &str = "chamber";
 pub const CHAMBER_AUTHORITY_PREFIX: &str = "chamber_authority";
 pub const USER_ACCOUNT_PREFIX: &str = "user_account";
+pub const NATIVE_TOKEN_PREFIX: &str = "native_token";
+pub const ALLOWLIST_ENTRY_PREFIX: &str = "allowlist_entry";
+pub const PROTOCOL_CONFIG_PREFIX: &str = "protocol_config";
+pub const FARM_ENTRY_PREFIX: &str = "farm_entry";
+pub const ORACLE_ENTRY_PREFIX: &str = "oracle_entry";
+pub const CHAMBER_REGISTRY_PREFIX: &str = "chamber_registry";
+
+/// Current `Chamber` account layout version.
+pub const CHAMBER_VERSION: u8 = 1;
+
+/// Current `UserAccount` account layout version.
+pub const USER_ACCOUNT_VERSION: u8 = 1;
+
+/// `Chamber` bytes, reserved for state of future layout versions.
+pub const CHAMBER_RESERVED_LEN: usize = 24;
+
+/// `UserAccount` bytes, reserved for state of future layout versions.
+pub const USER_ACCOUNT_RESERVED_LEN: usize = 32;
+
+/// Max farm or oracle programs, allowed by `ProtocolConfig`.
+pub const MAX_PROTOCOL_PROGRAMS: usize = 4;
+
+/// Max `Chamber`s, listed by single `ChamberRegistry` page.
+pub const MAX_CHAMBER_REGISTRY_ENTRIES: usize = 32;
+
+/// Min `Chamber` config change timelock delay in seconds, 1 day.
+pub const MIN_CONFIG_CHANGE_DELAY: i64 = 24 * 60 * 60;
+
+/// Max `Chamber` config change timelock delay in seconds, 30 days.
+pub const MAX_CONFIG_CHANGE_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Max `Chamber` leverage, initialized or proposed by config change.
+pub const MAX_LEVERAGE: u64 = 3;
+
+/// Default min `ChamberRisk::safety_margin_bps`, used for zero owner bound.
+pub const DEFAULT_MIN_SAFETY_MARGIN_BPS: u64 = 500;
+
+/// Default max `ChamberRisk::safety_margin_bps`, used for zero owner bound.
+pub const DEFAULT_MAX_SAFETY_MARGIN_BPS: u64 = 10_000;
+
+/// Default min `ChamberRisk::target_ltv_bps`, used for zero owner bound.
+pub const DEFAULT_MIN_TARGET_LTV_BPS: u64 = 0;
+
+/// Default max `ChamberRisk::target_ltv_bps`, used for zero owner bound.
+pub const DEFAULT_MAX_TARGET_LTV_BPS: u64 = 8_000;
+
+/// Default max `ChamberRisk::max_slippage_bps`, used for zero owner bound.
+pub const DEFAULT_MAX_SLIPPAGE_BPS: u64 = 300;
+
+/// Default min `ChamberRisk::rebalance_drift_bps`, used for zero owner bound.
+pub const DEFAULT_MIN_REBALANCE_DRIFT_BPS: u64 = 100;
+
+/// Default max `ChamberRisk::rebalance_drift_bps`, used for zero owner bound.
+pub const DEFAULT_MAX_REBALANCE_DRIFT_BPS: u64 = 2_000;
+
+/// Market obligations, created for each `Chamber`.
+pub const OBLIGATIONS_COUNT: usize = 2;
+
+/// Max addresses, added to lookup table by single `extend_chamber_lookup_table`.
+pub const MAX_LOOKUP_TABLE_EXTEND_ADDRESSES: usize = 20;
+
+pub mod address_lookup_table_program {
+    use anchor_lang::declare_id;
+
+    declare_id!("AddressLookupTab1e1111111111111111111111111");
+}
+
+pub mod raydium_amm_program {
+    use anchor_lang::declare_id;
+
+    declare_id!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+}
+
+/// Grow `account` to `len` bytes, funding its rent exemption from `payer`.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &Rent,
+    len: usize,
+) -> Result<()> {
+    let lamports = rent.minimum_balance(len).saturating_sub(account.lamports());
+
+    if lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account.realloc(len, true)?;
+
+    Ok(())
+}
+
+/// Create `account` PDA, owned by `owner`, funding it from `payer` up to `lamports`.
+///
+/// `account` may be already funded, e.g. by a transfer to its address, in which case plain
+/// `create_account` fails, so missing lamports are transferred and space is allocated separately.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+    space: u64,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            lamports,
+            space,
+            owner,
+        );
+    }
+
+    let missing_lamports = lamports.saturating_sub(current_lamports);
+
+    if missing_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space,
+    )?;
+
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )?;
+
+    Ok(())
+}
 
 pub fn derive_chamber_address(
     farm: &Pubkey,
@@ -40,3 +211,60 @@ pub fn derive_user_account_address(chamber: &Pubkey, user: &Pubkey) -> (Pubkey,
         &crate::id(),
     )
 }
+
+pub fn derive_native_token_address(user_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[NATIVE_TOKEN_PREFIX.as_bytes(), user_account.as_ref()],
+        &crate::id(),
+    )
+}
+
+pub fn derive_allowlist_entry_address(chamber: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ALLOWLIST_ENTRY_PREFIX.as_bytes(),
+            chamber.as_ref(),
+            user.as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+pub fn derive_protocol_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROTOCOL_CONFIG_PREFIX.as_bytes()], &crate::id())
+}
+
+pub fn derive_program_data_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[crate::id().as_ref()],
+        &anchor_lang::solana_program::bpf_loader_upgradeable::id(),
+    )
+}
+
+pub fn derive_farm_entry_address(farm: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FARM_ENTRY_PREFIX.as_bytes(), farm.as_ref()], &crate::id())
+}
+
+pub fn derive_oracle_entry_address(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ORACLE_ENTRY_PREFIX.as_bytes(), mint.as_ref()],
+        &crate::id(),
+    )
+}
+
+pub fn derive_chamber_registry_address(index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CHAMBER_REGISTRY_PREFIX.as_bytes(),
+            index.to_le_bytes().as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+pub fn derive_lookup_table_address(authority: &Pubkey, recent_slot: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[authority.as_ref(), recent_slot.to_le_bytes().as_ref()],
+        &address_lookup_table_program::id(),
+    )
+}
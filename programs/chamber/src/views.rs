@@ -0,0 +1,267 @@
+//! Module provide read-only `Chamber` views, returned by view instructions
+//! via `set_return_data`.
+//!
+//! All prices and values are scaled by `WAD`(1e18).
+//!
+//! Views value position at market prices: lp value and idle tokens, net of
+//! debt with accrued interest. It differs from book value of
+//! `state::ChamberVault::get_total_value`, which prices shares on deposit and
+//! is reported as `nav` by events, since book value excludes lp price moves
+//! and debt interest.
+
+use crate::{error, math, position, state, utils};
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use tulipv2_sdk_common::math::{
+    common::{TryAdd, TryDiv, TryMul, TrySub},
+    decimal::Decimal,
+};
+
+/// Net asset value of `state::Chamber` underlying position.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ChamberNav {
+    pub lp_amount: u64,
+    pub lp_value: u128,
+
+    pub base_debt: u64,
+    pub quote_debt: u64,
+    pub base_debt_value: u128,
+    pub quote_debt_value: u128,
+
+    /// Tokens, held by `Chamber` outside of position.
+    pub idle_base_amount: u64,
+    pub idle_quote_amount: u64,
+
+    pub equity: u128,
+
+    /// Position value to equity ratio.
+    pub leverage: u128,
+
+    pub shares_supply: u64,
+
+    /// Value of one whole(`utils::SHARES_DECIMALS`) share.
+    pub share_price: u128,
+}
+
+/// Current price of `state::Chamber` share.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SharePrice {
+    pub equity: u128,
+    pub shares_supply: u64,
+    pub share_price: u128,
+}
+
+/// User part of `state::Chamber` position.
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UserPosition {
+    pub status: state::UserAccountStatus,
+    pub shares_amount: u64,
+    pub share_price: u128,
+    pub equity: u128,
+    pub lp_value: u128,
+    pub base_debt: u64,
+    pub quote_debt: u64,
+}
+
+impl ChamberNav {
+    #[allow(clippy::too_many_arguments)]
+    pub fn load(
+        chamber: &state::Chamber,
+        chamber_base_token: &token::TokenAccount,
+        chamber_quote_token: &token::TokenAccount,
+        chamber_shares_mint: &token::Mint,
+        chamber_base_oracle: &AccountInfo,
+        chamber_quote_oracle: &AccountInfo,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<Self> {
+        let base_price =
+            tulipv2_sdk_common::pyth::load_pyth_price(&chamber_base_oracle.data.as_ref().borrow())?;
+        let quote_price = tulipv2_sdk_common::pyth::load_pyth_price(
+            &chamber_quote_oracle.data.as_ref().borrow(),
+        )?;
+
+        let (lp_price, lp_decimals, lp_amount, base_debt, quote_debt) =
+            match chamber.strategy.market {
+                state::ChamberMarket::Tulip => {
+                    let leveraged_farm = &remaining_accounts[0];
+                    let user_farm = &remaining_accounts[1];
+                    let user_farm_obligation = &remaining_accounts[2];
+                    let user_farm_obligation_1 = &remaining_accounts[3];
+                    let lp_pyth_price_account = &remaining_accounts[4];
+                    let lp_mint = &remaining_accounts[5];
+
+                    // Ensure, that obligations and lp accounts belong to `Chamber`
+                    if leveraged_farm.key() != chamber.strategy.farm {
+                        return Err(error::ChamberError::InvalidFarm.into());
+                    }
+
+                    position::tulip::assert_lp_accounts(
+                        &position::tulip::load_leveraged_farm(
+                            leveraged_farm,
+                            &chamber.strategy.farm_program,
+                        )?,
+                        lp_pyth_price_account,
+                        lp_mint,
+                    )?;
+                    position::tulip::assert_user_farm_obligations(
+                        user_farm,
+                        &[user_farm_obligation, user_farm_obligation_1],
+                        &chamber.config.authority,
+                        &chamber.strategy.farm,
+                        &chamber.strategy.farm_program,
+                    )?;
+
+                    let lp_price = tulipv2_sdk_common::pyth::load_pyth_price(
+                        &lp_pyth_price_account.data.as_ref().borrow(),
+                    )?;
+                    let lp_decimals = Account::<token::Mint>::try_from(lp_mint)?.decimals;
+
+                    let mut lp_amount: u64 = 0;
+                    let mut base_debt: u64 = 0;
+                    let mut quote_debt: u64 = 0;
+
+                    for user_farm_obligation in [user_farm_obligation, user_farm_obligation_1] {
+                        let obligation = position::tulip::load_obligation(
+                            user_farm_obligation,
+                            &chamber.strategy.farm_program,
+                        )?;
+                        let (obligation_base_debt, obligation_quote_debt) =
+                            position::tulip::get_obligation_debt(&obligation)?;
+
+                        lp_amount = lp_amount
+                            .checked_add(obligation.lp_tokens)
+                            .ok_or(error::ChamberError::MathOverflow)?;
+                        base_debt = base_debt
+                            .checked_add(obligation_base_debt)
+                            .ok_or(error::ChamberError::MathOverflow)?;
+                        quote_debt = quote_debt
+                            .checked_add(obligation_quote_debt)
+                            .ok_or(error::ChamberError::MathOverflow)?;
+                    }
+
+                    (lp_price, lp_decimals, lp_amount, base_debt, quote_debt)
+                }
+            };
+
+        Self::new(
+            &chamber.vault,
+            &base_price,
+            &quote_price,
+            &lp_price,
+            lp_decimals.into(),
+            lp_amount,
+            base_debt,
+            quote_debt,
+            chamber_base_token.amount,
+            chamber_quote_token.amount,
+            chamber_shares_mint.supply,
+        )
+    }
+
+    /// Value position of `vault` at market prices.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vault: &state::ChamberVault,
+        base_price: &Decimal,
+        quote_price: &Decimal,
+        lp_price: &Decimal,
+        lp_decimals: u64,
+        lp_amount: u64,
+        base_debt: u64,
+        quote_debt: u64,
+        idle_base_amount: u64,
+        idle_quote_amount: u64,
+        shares_supply: u64,
+    ) -> Result<Self> {
+        let lp_value = math::get_token_value(lp_price, lp_amount, lp_decimals)?;
+
+        let base_debt_value = math::get_token_value(base_price, base_debt, vault.base_decimals)?;
+        let quote_debt_value =
+            math::get_token_value(quote_price, quote_debt, vault.quote_decimals)?;
+
+        let idle_base_value =
+            math::get_token_value(base_price, idle_base_amount, vault.base_decimals)?;
+        let idle_quote_value =
+            math::get_token_value(quote_price, idle_quote_amount, vault.quote_decimals)?;
+
+        let assets_value = lp_value
+            .try_add(idle_base_value)?
+            .try_add(idle_quote_value)?;
+        let debt_value = base_debt_value.try_add(quote_debt_value)?;
+
+        let equity = if assets_value > debt_value {
+            assets_value.try_sub(debt_value)?
+        } else {
+            Decimal::zero()
+        };
+
+        let leverage = if equity == Decimal::zero() {
+            Decimal::zero()
+        } else {
+            lp_value.try_div(equity)?
+        };
+
+        let share_price = get_share_price(&equity, shares_supply)?;
+
+        Ok(ChamberNav {
+            lp_amount,
+            lp_value: lp_value.to_scaled_val()?,
+            base_debt,
+            quote_debt,
+            base_debt_value: base_debt_value.to_scaled_val()?,
+            quote_debt_value: quote_debt_value.to_scaled_val()?,
+            idle_base_amount,
+            idle_quote_amount,
+            equity: equity.to_scaled_val()?,
+            leverage: leverage.to_scaled_val()?,
+            shares_supply,
+            share_price: share_price.to_scaled_val()?,
+        })
+    }
+}
+
+impl UserPosition {
+    pub fn new(
+        nav: &ChamberNav,
+        user_account: &state::UserAccount,
+        shares_amount: u64,
+    ) -> Result<Self> {
+        let user_part = |value: u128| -> Result<u128> {
+            if nav.shares_supply == 0 {
+                return Ok(0);
+            }
+
+            value
+                .checked_mul(shares_amount.into())
+                .and_then(|value| value.checked_div(nav.shares_supply.into()))
+                .ok_or_else(|| error::ChamberError::MathOverflow.into())
+        };
+
+        Ok(UserPosition {
+            status: user_account.status.clone(),
+            shares_amount,
+            share_price: nav.share_price,
+            equity: user_part(nav.equity)?,
+            lp_value: user_part(nav.lp_value)?,
+            base_debt: user_part(nav.base_debt.into())?
+                .try_into()
+                .map_err(|_| error::ChamberError::MathOverflow)?,
+            quote_debt: user_part(nav.quote_debt.into())?
+                .try_into()
+                .map_err(|_| error::ChamberError::MathOverflow)?,
+        })
+    }
+}
+
+/// Calculate value of one whole share, given `Chamber` equity and shares supply.
+pub fn get_share_price(equity: &Decimal, shares_supply: u64) -> Result<Decimal> {
+    if shares_supply == 0 {
+        return Ok(Decimal::zero());
+    }
+
+    let precision = 10u64
+        .checked_pow(utils::SHARES_DECIMALS.into())
+        .ok_or(error::ChamberError::MathOverflow)?;
+
+    Ok(equity.try_mul(precision)?.try_div(shares_supply)?)
+}
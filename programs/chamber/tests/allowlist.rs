@@ -0,0 +1,336 @@
+mod state;
+mod utils;
+
+use anchor_lang::prelude::AccountMeta;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+const USER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Enable `Chamber` allowlist
+    test_chamber
+        .update_chamber_access(&mut test_context, &payer, true, &Pubkey::default())
+        .await
+        .expect("Unable to enable chamber allowlist!");
+
+    // 4. Build user, which associated with `Chamber`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    // 5. Ensure, that not allowed user can't create `UserAccount`
+    assert!(test_user
+        .create_user_account(&mut test_context)
+        .await
+        .is_err());
+
+    // 6. Allow user to use `Chamber`
+    test_chamber
+        .add_allowlist_entry(&mut test_context, &payer, &test_user.get_pubkey())
+        .await
+        .expect("Unable to add allowlist entry!");
+
+    // 7. Create `UserAccount` with allowlist entry
+    test_user
+        .create_user_account_with_remaining_accounts(
+            &mut test_context,
+            vec![AccountMeta::new_readonly(
+                test_chamber.derive_allowlist_entry(&test_user.get_pubkey()),
+                false,
+            )],
+        )
+        .await
+        .expect("Unable to create user account!");
+
+    let test_chain_user_account = test_user
+        .fetch_user_account(&mut test_context)
+        .await
+        .expect("Unable to fetch user account!");
+
+    assert_eq!(test_chain_user_account.user, test_user.get_pubkey());
+}
+
+fn is_user_not_allowed(error: &transport::TransportError) -> bool {
+    let user_not_allowed: u32 = cetra_chamber::error::ChamberError::UserNotAllowed.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == user_not_allowed
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success_gatekeeper() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let gatekeeper = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Enable `Chamber` allowlist with gatekeeper
+    test_chamber
+        .update_chamber_access(&mut test_context, &payer, true, &gatekeeper.pubkey())
+        .await
+        .expect("Unable to enable chamber allowlist!");
+
+    // 3. Build user, which associated with `Chamber`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    // 4. Ensure, that other signer can't permit `UserAccount`
+    assert!(test_user
+        .create_user_account_with_gatekeeper(&mut test_context, &Keypair::new())
+        .await
+        .is_err());
+
+    // 5. Create `UserAccount` with gatekeeper signature
+    test_user
+        .create_user_account_with_gatekeeper(&mut test_context, &gatekeeper)
+        .await
+        .expect("Unable to create user account!");
+
+    // 6. Ensure, that deposit requires gatekeeper signature
+    let error = test_chamber
+        .begin_deposit_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            None,
+            0,
+            0,
+        )
+        .await
+        .expect_err("Deposit is begun without gatekeeper!");
+
+    assert!(is_user_not_allowed(&error));
+
+    // 7. Ensure, that gatekeeper signed deposit passes access check,
+    // market remaining accounts are omitted, so it fails later
+    let error = test_chamber
+        .begin_deposit_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            Some(&gatekeeper),
+            0,
+            0,
+        )
+        .await
+        .expect_err("Deposit is begun without market accounts!");
+
+    assert!(!is_user_not_allowed(&error));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success_revoke() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Enable `Chamber` allowlist
+    test_chamber
+        .update_chamber_access(&mut test_context, &payer, true, &Pubkey::default())
+        .await
+        .expect("Unable to enable chamber allowlist!");
+
+    // 3. Build allowed user with `UserAccount`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_chamber
+        .add_allowlist_entry(&mut test_context, &payer, &test_user.get_pubkey())
+        .await
+        .expect("Unable to add allowlist entry!");
+
+    test_user
+        .create_user_account_with_remaining_accounts(
+            &mut test_context,
+            vec![AccountMeta::new_readonly(
+                test_chamber.derive_allowlist_entry(&test_user.get_pubkey()),
+                false,
+            )],
+        )
+        .await
+        .expect("Unable to create user account!");
+
+    // 4. Ensure, that allowed deposit passes access check,
+    // market remaining accounts are omitted, so it fails later
+    let error = test_chamber
+        .begin_deposit_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            None,
+            0,
+            0,
+        )
+        .await
+        .expect_err("Deposit is begun without market accounts!");
+
+    assert!(!is_user_not_allowed(&error));
+
+    // 5. Revoke user allowlist entry
+    test_chamber
+        .remove_allowlist_entry(&mut test_context, &payer, &test_user.get_pubkey())
+        .await
+        .expect("Unable to remove allowlist entry!");
+
+    // 6. Ensure, that revoked user can't deposit
+    let error = test_chamber
+        .begin_deposit_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            None,
+            1,
+            0,
+        )
+        .await
+        .expect_err("Deposit is begun by revoked user!");
+
+    assert!(is_user_not_allowed(&error));
+}
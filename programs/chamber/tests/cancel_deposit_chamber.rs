@@ -0,0 +1,348 @@
+mod state;
+mod utils;
+
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+use anchor_spl::token::spl_token;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    account::AccountSharedData, instruction::InstructionError, program_pack::Pack, pubkey::Pubkey,
+    signature::Keypair, signer::Signer, transaction::TransactionError, transport,
+};
+
+const USER_FUND_LAMPORTS: u64 = 1000000000;
+const LOCKED_BASE_AMOUNT: u64 = 1000;
+const LOCKED_QUOTE_AMOUNT: u64 = 500;
+
+/// Leave `user` deposit pending in `BeginDeposit` stage, optionally moved into position.
+async fn set_pending_deposit(
+    test_context: &mut TestContext,
+    user: &state::User,
+    is_in_position: bool,
+) {
+    let mut account = test_context
+        .context
+        .banks_client
+        .get_account(user.get_user_account_pubkey())
+        .await
+        .expect("Unable to fetch user account!")
+        .expect("User account is not found!");
+
+    let mut user_account =
+        cetra_chamber::state::UserAccount::try_deserialize(&mut account.data.as_slice())
+            .expect("Unexpected invalid UserAccount layout!");
+
+    user_account.begin_deposit(LOCKED_BASE_AMOUNT, LOCKED_QUOTE_AMOUNT, 1, 0);
+
+    if is_in_position {
+        user_account.record_position_deposit(LOCKED_BASE_AMOUNT, LOCKED_QUOTE_AMOUNT);
+    }
+
+    let mut data = vec![];
+    user_account
+        .try_serialize(&mut data)
+        .expect("Unable to serialize user account!");
+    account.data[..data.len()].copy_from_slice(&data);
+
+    test_context.context.set_account(
+        &user.get_user_account_pubkey(),
+        &AccountSharedData::from(account),
+    );
+}
+
+/// Set token `amount` of `token_account`, keeping native account lamports in sync.
+async fn set_token_amount(test_context: &mut TestContext, token_account: &Pubkey, amount: u64) {
+    let mut account = test_context
+        .context
+        .banks_client
+        .get_account(*token_account)
+        .await
+        .expect("Unable to fetch token account!")
+        .expect("Token account is not found!");
+
+    let mut state =
+        spl_token::state::Account::unpack(&account.data).expect("Invalid token account!");
+
+    if state.is_native() {
+        account.lamports += amount - state.amount;
+    }
+
+    state.amount = amount;
+    spl_token::state::Account::pack(state, &mut account.data).expect("Invalid token account!");
+
+    test_context
+        .context
+        .set_account(token_account, &AccountSharedData::from(account));
+}
+
+async fn get_token_amount(test_context: &mut TestContext, token_account: &Pubkey) -> u64 {
+    let account = test_context
+        .context
+        .banks_client
+        .get_account(*token_account)
+        .await
+        .expect("Unable to fetch token account!")
+        .expect("Token account is not found!");
+
+    spl_token::state::Account::unpack(&account.data)
+        .expect("Invalid token account!")
+        .amount
+}
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build users with pending deposits and fund `Chamber` idle tokens
+    let mut test_users = Vec::new();
+
+    for _ in 0..2 {
+        let test_user = state::User::build_with_chamber(
+            &mut test_context,
+            &payer,
+            &test_chamber,
+            USER_FUND_LAMPORTS,
+            0,
+            0,
+        )
+        .await
+        .expect("Unable to build user with chamber!");
+
+        test_user
+            .create_user_account(&mut test_context)
+            .await
+            .expect("Unable to create user account!");
+
+        set_pending_deposit(&mut test_context, &test_user, false).await;
+
+        test_users.push(test_user);
+    }
+
+    set_token_amount(
+        &mut test_context,
+        &test_chamber.get_base_ata(),
+        LOCKED_BASE_AMOUNT * 2,
+    )
+    .await;
+    set_token_amount(
+        &mut test_context,
+        &test_chamber.get_quote_ata(),
+        LOCKED_QUOTE_AMOUNT * 2,
+    )
+    .await;
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        USER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 3. Cancel pending deposits by user and by guardian
+    let (test_user, other_user) = (&test_users[0], &test_users[1]);
+
+    test_chamber
+        .cancel_deposit_chamber(&mut test_context, &test_user.get_keypair(), test_user)
+        .await
+        .expect("Unable to cancel deposit by user!");
+
+    test_chamber
+        .cancel_deposit_chamber(&mut test_context, &owner, other_user)
+        .await
+        .expect("Unable to cancel deposit by guardian!");
+
+    // 4. Ensure, that locked tokens are refunded and `UserAccount`s are ready
+    for test_user in &test_users {
+        assert_eq!(
+            get_token_amount(&mut test_context, &test_user.get_base_ata()).await,
+            LOCKED_BASE_AMOUNT
+        );
+        assert_eq!(
+            get_token_amount(&mut test_context, &test_user.get_quote_ata()).await,
+            LOCKED_QUOTE_AMOUNT
+        );
+
+        let test_chain_user_account = test_user
+            .fetch_user_account(&mut test_context)
+            .await
+            .expect("Unable to fetch user account!");
+
+        assert_eq!(
+            test_chain_user_account.status,
+            cetra_chamber::state::UserAccountStatus::Ready
+        );
+        assert_eq!(test_chain_user_account.locked_base_amount, 0);
+        assert_eq!(test_chain_user_account.locked_quote_amount, 0);
+        assert_eq!(test_chain_user_account.locked_shares_amount, 0);
+    }
+
+    assert_eq!(
+        get_token_amount(&mut test_context, &test_chamber.get_base_ata()).await,
+        0
+    );
+    assert_eq!(
+        get_token_amount(&mut test_context, &test_chamber.get_quote_ata()).await,
+        0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let stranger = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build user with ready `UserAccount`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create user account!");
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &stranger.pubkey(),
+        USER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund stranger!");
+
+    // 3. Ready deposit can not be cancelled
+    let error = test_chamber
+        .cancel_deposit_chamber(&mut test_context, &test_user.get_keypair(), &test_user)
+        .await
+        .expect_err("Ready deposit is cancelled!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidUserAccountStatus
+    ));
+
+    // 4. Pending deposit can not be cancelled by stranger
+    set_pending_deposit(&mut test_context, &test_user, false).await;
+
+    let error = test_chamber
+        .cancel_deposit_chamber(&mut test_context, &stranger, &test_user)
+        .await
+        .expect_err("Deposit is cancelled by stranger!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::Unauthorized
+    ));
+
+    // 5. Deposit, moved into position, can not be cancelled
+    set_pending_deposit(&mut test_context, &test_user, true).await;
+    set_token_amount(
+        &mut test_context,
+        &test_chamber.get_base_ata(),
+        LOCKED_BASE_AMOUNT,
+    )
+    .await;
+    set_token_amount(
+        &mut test_context,
+        &test_chamber.get_quote_ata(),
+        LOCKED_QUOTE_AMOUNT,
+    )
+    .await;
+
+    let error = test_chamber
+        .cancel_deposit_chamber(&mut test_context, &test_user.get_keypair(), &test_user)
+        .await
+        .expect_err("Deposit in position is cancelled!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::DepositInPosition
+    ));
+}
@@ -0,0 +1,44 @@
+use cetra_chamber::state;
+
+#[test]
+fn success() {
+    let mut debt = state::ChamberDebt::default();
+
+    // 1. Borrow adds principal
+    debt.record_borrow(1000, 500)
+        .expect("Unable to record borrow!");
+    debt.reconcile(1000, 500);
+
+    assert_eq!(debt.base_principal, 1000);
+    assert_eq!(debt.base_interest, 0);
+
+    // 2. Market debt above principal is accrued interest
+    debt.reconcile(1050, 520);
+
+    assert_eq!(debt.base_principal, 1000);
+    assert_eq!(debt.base_interest, 50);
+    assert_eq!(debt.quote_interest, 20);
+
+    // 3. Repay covers interest first, then principal
+    debt.record_repay(150, 20);
+    debt.reconcile(900, 500);
+
+    assert_eq!(debt.base_principal, 900);
+    assert_eq!(debt.base_interest, 0);
+    assert_eq!(debt.quote_principal, 500);
+    assert_eq!(debt.get_total_debt(), (900, 500));
+}
+
+#[test]
+fn success_reconcile_liquidated() {
+    let mut debt = state::ChamberDebt::default();
+
+    debt.record_borrow(1000, 500)
+        .expect("Unable to record borrow!");
+
+    // Market debt below principal lowers principal
+    debt.reconcile(400, 500);
+
+    assert_eq!(debt.base_principal, 400);
+    assert_eq!(debt.base_interest, 0);
+}
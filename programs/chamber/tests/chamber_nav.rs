@@ -0,0 +1,136 @@
+use cetra_chamber::{state, views};
+use solana_sdk::pubkey::Pubkey;
+use tulipv2_sdk_common::math::decimal::Decimal;
+
+fn build_vault() -> state::ChamberVault {
+    state::ChamberVault::new(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        9,
+        6,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn get_equity(
+    vault: &state::ChamberVault,
+    base_price: &Decimal,
+    quote_price: &Decimal,
+    lp_amount: u64,
+    base_debt: u64,
+    quote_debt: u64,
+    idle_base_amount: u64,
+    idle_quote_amount: u64,
+) -> u128 {
+    views::ChamberNav::new(
+        vault,
+        base_price,
+        quote_price,
+        &Decimal::one(),
+        6,
+        lp_amount,
+        base_debt,
+        quote_debt,
+        idle_base_amount,
+        idle_quote_amount,
+        200,
+    )
+    .expect("Unable to value position!")
+    .equity
+}
+
+#[test]
+fn success() {
+    let base_price = Decimal::from(100u64);
+    let quote_price = Decimal::one();
+
+    // 1. Deposit 1 base and 100 quote, worth 200
+    let mut vault = build_vault();
+    vault
+        .deposit(1_000_000_000, 100_000_000)
+        .expect("Unable to deposit!");
+
+    let book_value = vault
+        .get_total_value(&base_price, &quote_price)
+        .expect("Unable to get book value!");
+    assert_eq!(book_value, 200);
+
+    // 2. Freshly opened 3x position: 600 lp, 2 base and 200 quote debt
+    let equity = get_equity(
+        &vault,
+        &base_price,
+        &quote_price,
+        600_000_000,
+        2_000_000_000,
+        200_000_000,
+        0,
+        0,
+    );
+    assert_eq!(equity, Decimal::from(book_value).to_scaled_val().unwrap());
+
+    // 3. Accrued debt interest lowers equity, but not book value
+    let equity = get_equity(
+        &vault,
+        &base_price,
+        &quote_price,
+        600_000_000,
+        2_000_000_000,
+        220_000_000,
+        0,
+        0,
+    );
+    assert_eq!(equity, Decimal::from(180u64).to_scaled_val().unwrap());
+    assert_eq!(
+        vault
+            .get_total_value(&base_price, &quote_price)
+            .expect("Unable to get book value!"),
+        200
+    );
+
+    // 4. Idle tokens are part of equity
+    let equity = get_equity(
+        &vault,
+        &base_price,
+        &quote_price,
+        600_000_000,
+        2_000_000_000,
+        220_000_000,
+        0,
+        20_000_000,
+    );
+    assert_eq!(equity, Decimal::from(200u64).to_scaled_val().unwrap());
+}
+
+#[test]
+fn success_compounded() {
+    let base_price = Decimal::from(100u64);
+    let quote_price = Decimal::one();
+
+    let mut vault = build_vault();
+    vault
+        .deposit(1_000_000_000, 100_000_000)
+        .expect("Unable to deposit!");
+
+    let book_value = vault
+        .get_total_value(&base_price, &quote_price)
+        .expect("Unable to get book value!");
+    assert_eq!(
+        cetra_chamber::math::get_shares_value(100, 200, book_value).unwrap(),
+        100
+    );
+
+    // Compounded harvest output is booked as deposit and raises share value
+    vault.deposit(0, 20_000_000).expect("Unable to compound!");
+
+    let book_value = vault
+        .get_total_value(&base_price, &quote_price)
+        .expect("Unable to get book value!");
+    assert_eq!(
+        cetra_chamber::math::get_shares_value(100, 200, book_value).unwrap(),
+        110
+    );
+}
@@ -0,0 +1,120 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build and initialize two `Chamber`s over same farm
+    let mut test_chambers = vec![];
+    for nonce in 0..2 {
+        let test_chamber = state::Chamber::build_raydium_sol_usdc(
+            &mut test_context,
+            &payer,
+            &owner,
+            &fee_manager.pubkey(),
+            nonce,
+        )
+        .await
+        .expect("Unable to build raydium SOL/USDC chamber!");
+
+        test_chamber
+            .initialize_chamber(&mut test_context, &payer)
+            .await
+            .expect("Unable to initialize chamber!");
+
+        test_chambers.push(test_chamber);
+    }
+
+    // 2. Ensure, that `Chamber`s are listed in creation order
+    let test_protocol = state::Protocol::new(&payer);
+
+    let protocol_config = test_protocol
+        .fetch_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to fetch protocol config!");
+
+    assert_eq!(protocol_config.registry_pages, 1);
+    assert_eq!(protocol_config.chambers_count, 2);
+
+    let chamber_registry = test_protocol
+        .fetch_chamber_registry(&mut test_context, 0)
+        .await
+        .expect("Unable to fetch chamber registry!");
+
+    assert_eq!(chamber_registry.entries.len(), 2);
+    for (nonce, (entry, test_chamber)) in chamber_registry
+        .entries
+        .iter()
+        .zip(test_chambers.iter())
+        .enumerate()
+    {
+        assert_eq!(entry.chamber, test_chamber.get_pubkey());
+        assert_eq!(entry.farm, test_chamber.get_farm());
+        assert_eq!(entry.base_mint, test_chamber.get_base_mint());
+        assert_eq!(entry.quote_mint, test_chamber.get_quote_mint());
+        assert_eq!(usize::from(entry.nonce), nonce);
+        assert_eq!(entry.status, cetra_chamber::state::ChamberStatus::Active);
+    }
+
+    // 3. Ensure, that next page can't be created, while last page has free entries
+    assert!(test_protocol
+        .create_chamber_registry_page(&mut test_context, 1)
+        .await
+        .is_err());
+
+    // 4. Pause second `Chamber` and sync its registry status
+    test_chambers[1]
+        .set_chamber_pause(&mut test_context, &payer, &owner, true)
+        .await
+        .expect("Unable to pause chamber!");
+
+    test_protocol
+        .sync_chamber_registry(&mut test_context, 0, &test_chambers[1].get_pubkey())
+        .await
+        .expect("Unable to sync chamber registry!");
+
+    let chamber_registry = test_protocol
+        .fetch_chamber_registry(&mut test_context, 0)
+        .await
+        .expect("Unable to fetch chamber registry!");
+
+    assert_eq!(
+        chamber_registry.entries[0].status,
+        cetra_chamber::state::ChamberStatus::Active
+    );
+    assert_eq!(
+        chamber_registry.entries[1].status,
+        cetra_chamber::state::ChamberStatus::Paused
+    );
+}
@@ -0,0 +1,118 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+const USER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_accounts() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build user, which token accounts receive leftover tokens
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    // 3. Close with obligations in wrong order
+    test_chamber
+        .close_chamber(
+            &mut test_context,
+            &payer,
+            &owner,
+            &test_user.get_base_ata(),
+            &test_user.get_quote_ata(),
+            0,
+            [1, 0],
+        )
+        .await
+        .expect_err("Chamber is closed with swapped obligations!");
+
+    // 4. Close with same obligation passed twice
+    test_chamber
+        .close_chamber(
+            &mut test_context,
+            &payer,
+            &owner,
+            &test_user.get_base_ata(),
+            &test_user.get_quote_ata(),
+            0,
+            [0, 0],
+        )
+        .await
+        .expect_err("Chamber is closed with duplicated obligation!");
+
+    // 5. Close with user farm, not created by `Chamber`
+    test_chamber
+        .close_chamber(
+            &mut test_context,
+            &payer,
+            &owner,
+            &test_user.get_base_ata(),
+            &test_user.get_quote_ata(),
+            1,
+            [0, 1],
+        )
+        .await
+        .expect_err("Chamber is closed with foreign user farm!");
+
+    // 6. Close by not owner
+    test_chamber
+        .close_chamber(
+            &mut test_context,
+            &payer,
+            &test_user.get_keypair(),
+            &test_user.get_base_ata(),
+            &test_user.get_quote_ata(),
+            0,
+            [0, 1],
+        )
+        .await
+        .expect_err("Chamber is closed by not owner!");
+
+    // 7. Ensure, that `Chamber` is still open
+    test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+}
@@ -0,0 +1,282 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+const DELAY: i64 = 24 * 60 * 60;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let guardian = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &owner,
+            &owner.pubkey(),
+            &guardian.pubkey(),
+        )
+        .await
+        .expect("Unable to update chamber roles!");
+
+    // 3. Ensure, that timelock is enabled with min delay
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(
+        chamber.timelock.delay,
+        cetra_chamber::utils::MIN_CONFIG_CHANGE_DELAY
+    );
+
+    // 4. Propose change past timelock delay and execute it, once effective
+    let now = utils::get_unix_timestamp(&mut test_context).await;
+
+    test_chamber
+        .propose_config_change(&mut test_context, &payer, 2, 1000, DELAY, now + DELAY)
+        .await
+        .expect("Unable to propose config change!");
+
+    utils::set_unix_timestamp(&mut test_context, now + DELAY).await;
+
+    test_chamber
+        .execute_config_change(&mut test_context, &payer)
+        .await
+        .expect("Unable to execute config change!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.strategy.leverage, 2);
+    assert_eq!(chamber.harvest.performance_fee_bps, 1000);
+    assert_eq!(chamber.timelock.delay, DELAY);
+    assert!(!chamber.timelock.pending.is_pending());
+
+    // 5. Propose next change past timelock delay
+    let now = now + DELAY;
+
+    test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 500, DELAY, now + DELAY)
+        .await
+        .expect("Unable to propose config change!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(chamber.timelock.pending.is_pending());
+    assert_eq!(chamber.timelock.pending.leverage, 3);
+    assert_eq!(chamber.timelock.pending.effective_timestamp, now + DELAY);
+    assert_eq!(chamber.strategy.leverage, 2);
+
+    // 6. Cancel pending change as guardian
+    test_chamber
+        .cancel_config_change(&mut test_context, &payer, &guardian)
+        .await
+        .expect("Unable to cancel config change!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(!chamber.timelock.pending.is_pending());
+    assert_eq!(chamber.strategy.leverage, 2);
+    assert_eq!(chamber.harvest.performance_fee_bps, 1000);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_timelocked() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let strategist = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &owner,
+            &strategist.pubkey(),
+            &owner.pubkey(),
+        )
+        .await
+        .expect("Unable to update chamber roles!");
+
+    let initial_chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    let now = utils::get_unix_timestamp(&mut test_context).await;
+
+    // 3. Propose change before timelock delay
+    assert!(test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 1000, DELAY, now + DELAY - 1)
+        .await
+        .is_err());
+
+    // 4. Propose change, disabling timelock
+    assert!(test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 1000, 0, now + DELAY)
+        .await
+        .is_err());
+
+    // 5. Propose leverage above max leverage
+    assert!(test_chamber
+        .propose_config_change(
+            &mut test_context,
+            &payer,
+            cetra_chamber::utils::MAX_LEVERAGE + 1,
+            1000,
+            DELAY,
+            now + DELAY,
+        )
+        .await
+        .is_err());
+
+    // 6. Propose performance fee above 100%
+    assert!(test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 10001, DELAY, now + DELAY)
+        .await
+        .is_err());
+
+    // 7. Execute change before its effective timestamp
+    test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 1000, DELAY, now + DELAY)
+        .await
+        .expect("Unable to propose config change!");
+
+    assert!(test_chamber
+        .execute_config_change(&mut test_context, &payer)
+        .await
+        .is_err());
+
+    // 8. Propose second change, while first is pending
+    assert!(test_chamber
+        .propose_config_change(&mut test_context, &payer, 3, 2000, DELAY, now + DELAY)
+        .await
+        .is_err());
+
+    // 9. Cancel change as strategist
+    assert!(test_chamber
+        .cancel_config_change(&mut test_context, &payer, &strategist)
+        .await
+        .is_err());
+
+    // 10. Ensure, that harvest config update keeps timelocked performance fee
+    test_chamber
+        .update_chamber_harvest(
+            &mut test_context,
+            &payer,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            true,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber harvest!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.strategy.leverage, initial_chamber.strategy.leverage);
+    assert_eq!(
+        chamber.harvest.performance_fee_bps,
+        initial_chamber.harvest.performance_fee_bps
+    );
+    assert_eq!(chamber.timelock.pending.leverage, 3);
+}
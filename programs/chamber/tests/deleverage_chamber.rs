@@ -0,0 +1,150 @@
+mod state;
+mod utils;
+
+use cetra_chamber::math;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+use tulipv2_sdk_common::math::decimal::Decimal;
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
+
+#[test]
+fn success_repay_swap_amount() {
+    // Short token half of released value is topped up by swap
+    assert_eq!(
+        math::get_repay_swap_amount(1000, 50, &Decimal::from(600u64), &Decimal::one(), 0).unwrap(),
+        200
+    );
+    assert_eq!(
+        math::get_repay_swap_amount(1000, 50, &Decimal::from(600u64), &Decimal::from(2u64), 0)
+            .unwrap(),
+        350
+    );
+
+    // Long token is not swapped in
+    assert_eq!(
+        math::get_repay_swap_amount(1000, 50, &Decimal::from(2000u64), &Decimal::one(), 0).unwrap(),
+        0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. `Chamber` without obligation health config can not be deleveraged
+    let error = test_chamber
+        .deleverage_chamber(&mut test_context, &payer, &payer, 0, 0)
+        .await
+        .expect_err("Chamber is deleveraged without risk config!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::RiskNotConfigured
+    ));
+
+    // 3. Missing obligation can not be deleveraged
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            500,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber risk!");
+
+    let error = test_chamber
+        .deleverage_chamber(&mut test_context, &payer, &payer, 2, 0)
+        .await
+        .expect_err("Missing obligation is deleveraged!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidObligation
+    ));
+
+    // 4. Withdraw percent is forced only by guardian and only up to whole position
+    let error = test_chamber
+        .deleverage_chamber(&mut test_context, &payer, &payer, 0, 50)
+        .await
+        .expect_err("Withdraw percent is forced by cranker!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::Unauthorized
+    ));
+
+    let error = test_chamber
+        .deleverage_chamber(&mut test_context, &payer, &owner, 0, 101)
+        .await
+        .expect_err("Withdraw percent above whole position is forced!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidWithdrawPercent
+    ));
+}
@@ -1,8 +1,13 @@
 mod state;
 mod utils;
 
+use cetra_chamber::math;
 use cetra_program_test::{solana_program_test::*, *};
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+use tulipv2_sdk_common::math::decimal::Decimal;
 
 const USER_FUND_LAMPORTS: u64 = 1000000000;
 const USER_BASE_AMOUNT: u64 = 0;
@@ -76,3 +81,301 @@ async fn success() {
 
     // TODO: Add more asserts
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success_prefunded_native_token() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build user with `UserAccount`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        USER_BASE_AMOUNT,
+        USER_QUOTE_AMOUNT,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create user account!");
+
+    // 3. Fund temporary native token account before deposit
+    let (native_token, _) =
+        cetra_chamber::utils::derive_native_token_address(&test_user.get_user_account_pubkey());
+
+    utils::transfer(&mut test_context, &payer, &native_token, 1000)
+        .await
+        .expect("Unable to fund native token account!");
+
+    // 4. Deposit native lamports, market remaining accounts are omitted,
+    // so only native wrapping is processed
+    let logs = test_chamber
+        .deposit_chamber_native(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            USER_FUND_LAMPORTS / 10,
+        )
+        .await
+        .expect("Unable to process native deposit!");
+
+    // 5. Ensure, that funded account is wrapped into `Chamber`
+    assert!(!logs.iter().any(|log| log.contains("already in use")));
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("Instruction: InitializeAccount")));
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("Instruction: CloseAccount")));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_foreign_shares() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build users with `UserAccount`
+    let mut test_users = Vec::new();
+
+    for _ in 0..2 {
+        let test_user = state::User::build_with_chamber(
+            &mut test_context,
+            &payer,
+            &test_chamber,
+            USER_FUND_LAMPORTS,
+            USER_BASE_AMOUNT,
+            USER_QUOTE_AMOUNT,
+        )
+        .await
+        .expect("Unable to build user with chamber!");
+
+        test_user
+            .create_user_account(&mut test_context)
+            .await
+            .expect("Unable to create user account!");
+
+        test_users.push(test_user);
+    }
+
+    let (test_user, other_user) = (&test_users[0], &test_users[1]);
+
+    // 3. Begin deposit with shares account of other `User`
+    let error = test_chamber
+        .begin_deposit_chamber(
+            &mut test_context,
+            test_user,
+            &other_user.get_shares(),
+            None,
+            USER_BASE_AMOUNT,
+            USER_QUOTE_AMOUNT,
+        )
+        .await
+        .expect_err("Deposit is begun with shares of other user!");
+
+    assert!(matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(2003)
+        ))
+    ));
+
+    // 4. Deposit with shares account of other `User`
+    let logs = test_chamber
+        .deposit_chamber_native(
+            &mut test_context,
+            test_user,
+            &other_user.get_shares(),
+            USER_FUND_LAMPORTS / 10,
+        )
+        .await
+        .expect("Unable to process native deposit!");
+
+    assert!(logs
+        .iter()
+        .any(|log| log.contains("user_shares") && log.contains("ConstraintRaw")));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_finish_ready_deposit_by_keeper() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let keeper = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build user with `UserAccount` and fund keeper
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        USER_BASE_AMOUNT,
+        USER_QUOTE_AMOUNT,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create user account!");
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &keeper.pubkey(),
+        USER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund keeper!");
+
+    // 3. Keeper signature is accepted for deposit stages, so only
+    // `UserAccount` status rejects finishing of not pending deposit
+    let invalid_status: u32 = cetra_chamber::error::ChamberError::InvalidUserAccountStatus.into();
+
+    let error = test_chamber
+        .process_deposit_chamber(&mut test_context, &keeper, &test_user)
+        .await
+        .expect_err("Ready deposit is processed!");
+
+    assert!(matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if code == invalid_status
+    ));
+
+    let error = test_chamber
+        .end_deposit_chamber(&mut test_context, &keeper, &test_user)
+        .await
+        .expect_err("Ready deposit is ended!");
+
+    assert!(matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if code == invalid_status
+    ));
+}
+
+#[test]
+fn success_shares_amount() {
+    // Empty `Chamber` mints shares 1:1 to deposit value
+    assert_eq!(
+        math::get_shares_amount(&Decimal::from(1000u64), 0, 0).unwrap(),
+        1000
+    );
+    assert_eq!(
+        math::get_shares_amount(&Decimal::from(1000u64), 0, 5000).unwrap(),
+        1000
+    );
+
+    // Non empty `Chamber` mints shares pro-rata to total value
+    assert_eq!(
+        math::get_shares_amount(&Decimal::from(1000u64), 500, 1000).unwrap(),
+        500
+    );
+    assert_eq!(
+        math::get_shares_amount(&Decimal::from(1000u64), 2000, 1000).unwrap(),
+        2000
+    );
+}
@@ -2,7 +2,25 @@ mod state;
 mod utils;
 
 use cetra_program_test::{solana_program_test::*, *};
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
 
 #[tokio::test(flavor = "multi_thread")]
 async fn success() {
@@ -42,10 +60,84 @@ async fn success() {
         .expect("Unable to initialize chamber!");
 
     // 3. Fetch on-chain `Chamber`
-    let _test_chain_chamber = test_chamber
+    let test_chain_chamber = test_chamber
         .fetch_chamber(&mut test_context)
         .await
         .expect("Unable to fetch chamber!");
 
+    // 4. Ensure, that position starts in first empty obligation
+    assert_eq!(test_chain_chamber.active_obligation, 0);
+    assert!(test_chain_chamber
+        .obligations
+        .iter()
+        .all(|obligation| obligation.is_empty()));
+    assert_eq!(test_chain_chamber.debt.get_total_debt(), (0, 0));
+
     // TODO: Add more asserts
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_leverage() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build `Chamber`
+    let mut test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber` without leverage
+    test_chamber.set_leverage(0);
+
+    let error = test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect_err("Chamber is initialized without leverage!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidLeverage
+    ));
+
+    // 3. Initialize `Chamber` above max leverage
+    test_chamber.set_leverage(cetra_chamber::utils::MAX_LEVERAGE + 1);
+
+    let error = test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect_err("Chamber is initialized above max leverage!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidLeverage
+    ));
+
+    // 4. Initialize `Chamber` with max leverage
+    test_chamber.set_leverage(cetra_chamber::utils::MAX_LEVERAGE);
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+}
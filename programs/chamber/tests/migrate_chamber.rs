@@ -0,0 +1,206 @@
+mod utils;
+
+use anchor_lang::{AccountDeserialize, Discriminator, InstructionData, ToAccountMetas};
+use cetra_chamber::state;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    account::Account, instruction::Instruction, pubkey::Pubkey, rent::Rent, signature::Keypair,
+    signer::Signer, system_program, sysvar, transaction::Transaction,
+};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+/// Build `Chamber` account in layout, deployed before account versioning:
+/// discriminator, strategy, vault and config without lookup table.
+fn build_legacy_chamber(owner: &Pubkey) -> Account {
+    let mut data = state::Chamber::discriminator().to_vec();
+
+    // Strategy: market, farm, farm program, leverage and volatile base flag
+    data.push(0);
+    data.extend(Pubkey::new_unique().to_bytes());
+    data.extend(Pubkey::new_unique().to_bytes());
+    data.extend(3u64.to_le_bytes());
+    data.push(1);
+
+    // Vault: token accounts, mints and oracles, decimals and deposited amounts
+    for _ in 0..6 {
+        data.extend(Pubkey::new_unique().to_bytes());
+    }
+    data.extend(9u64.to_le_bytes());
+    data.extend(6u64.to_le_bytes());
+    data.extend(1000u128.to_le_bytes());
+    data.extend(2000u128.to_le_bytes());
+
+    // Config: authority, owner, fee manager, shares mint, authority bump and nonce
+    data.extend(Pubkey::new_unique().to_bytes());
+    data.extend(owner.to_bytes());
+    data.extend(Pubkey::new_unique().to_bytes());
+    data.extend(Pubkey::new_unique().to_bytes());
+    data.push(255);
+    data.push(0);
+
+    assert_eq!(data.len(), 452);
+
+    build_account(data)
+}
+
+fn build_account(data: Vec<u8>) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: cetra_chamber::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn build_migrate_chamber(chamber: &Pubkey, owner: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: cetra_chamber::id(),
+        data: cetra_chamber::instruction::MigrateChamber {}.data(),
+        accounts: cetra_chamber::accounts::MigrateChamber {
+            chamber: *chamber,
+            owner: *owner,
+            rent_sysvar: sysvar::rent::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    let owner = Keypair::new();
+    let chamber = Pubkey::new_unique();
+
+    // 1. Load `Chamber` fixture in legacy layout
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .program_test
+        .add_account(chamber, build_legacy_chamber(&owner.pubkey()));
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 2. Migrate `Chamber`
+    let tx = Transaction::new_signed_with_payer(
+        &[build_migrate_chamber(&chamber, &owner.pubkey())],
+        Some(&payer.pubkey()),
+        &[&payer, &owner],
+        test_context.context.last_blockhash,
+    );
+
+    test_context
+        .process_transaction(tx)
+        .await
+        .expect("Unable to migrate chamber!");
+
+    // 3. Ensure, that `Chamber` is upgraded with state preserved
+    let account = test_context
+        .context
+        .banks_client
+        .get_account(chamber)
+        .await
+        .expect("Unable to fetch chamber!")
+        .expect("Chamber is not found!");
+
+    assert_eq!(account.data.len(), state::Chamber::LEN);
+    assert!(Rent::default().is_exempt(account.lamports, account.data.len()));
+
+    let migrated_chamber = state::Chamber::try_deserialize(&mut account.data.as_slice())
+        .expect("Unexpected invalid Chamber layout!");
+
+    assert_eq!(
+        migrated_chamber.version,
+        cetra_chamber::utils::CHAMBER_VERSION
+    );
+    assert_eq!(migrated_chamber.config.owner, owner.pubkey());
+    assert_eq!(migrated_chamber.strategy.leverage, 3);
+    assert_eq!(migrated_chamber.vault.base_decimals, 9);
+    assert_eq!(migrated_chamber.vault.quote_decimals, 6);
+    assert_eq!(migrated_chamber.vault.base_amount, 1000);
+    assert_eq!(migrated_chamber.vault.quote_amount, 2000);
+    assert_eq!(migrated_chamber.config.authority_bump, 255);
+    assert!(!migrated_chamber.config.has_lookup_table());
+    assert!(!migrated_chamber.harvest.is_enabled());
+    assert!(!migrated_chamber.risk.is_enabled());
+    assert_eq!(migrated_chamber.active_obligation, 0);
+    assert!(migrated_chamber.roles.is_strategist(&owner.pubkey()));
+    assert!(migrated_chamber.roles.is_guardian(&owner.pubkey()));
+    assert_eq!(
+        migrated_chamber.timelock.delay,
+        cetra_chamber::utils::MIN_CONFIG_CHANGE_DELAY
+    );
+    assert!(migrated_chamber.reserved.iter().all(|byte| *byte == 0));
+
+    // 4. Migrating up to date `Chamber` fails
+    let tx = Transaction::new_signed_with_payer(
+        &[build_migrate_chamber(&chamber, &owner.pubkey())],
+        Some(&payer.pubkey()),
+        &[&payer, &owner],
+        test_context.context.last_blockhash,
+    );
+
+    test_context
+        .process_transaction(tx)
+        .await
+        .expect_err("Up to date chamber is migrated!");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_not_owner() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    let owner = Keypair::new();
+    let chamber = Pubkey::new_unique();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .program_test
+        .add_account(chamber, build_legacy_chamber(&owner.pubkey()));
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+
+    // Payer is not `Chamber` owner
+    let tx = Transaction::new_signed_with_payer(
+        &[build_migrate_chamber(&chamber, &payer.pubkey())],
+        Some(&payer.pubkey()),
+        &[&payer],
+        test_context.context.last_blockhash,
+    );
+
+    test_context
+        .process_transaction(tx)
+        .await
+        .expect_err("Chamber is migrated by not owner!");
+}
@@ -0,0 +1,127 @@
+mod utils;
+
+use anchor_lang::{
+    AccountDeserialize, AnchorSerialize, Discriminator, InstructionData, ToAccountMetas,
+};
+use cetra_chamber::state;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    account::Account, instruction::Instruction, pubkey::Pubkey, rent::Rent, signature::Keypair,
+    signer::Signer, system_program, sysvar, transaction::Transaction,
+};
+
+const USER_FUND_LAMPORTS: u64 = 1000000000;
+
+/// Build `UserAccount` account in layout, deployed before account versioning.
+fn build_legacy_user_account(user: &Pubkey) -> Account {
+    let user_account = state::UserAccountV0 {
+        chamber: Pubkey::new_unique(),
+        user: *user,
+        shares: Pubkey::new_unique(),
+        status: state::UserAccountStatus::ProcessDeposit,
+        locked_base_amount: 100,
+        locked_quote_amount: 200,
+        locked_shares_amount: 300,
+    };
+
+    let mut data = state::UserAccount::discriminator().to_vec();
+    data.extend(
+        user_account
+            .try_to_vec()
+            .expect("Unable to serialize user account!"),
+    );
+
+    assert_eq!(data.len(), state::UserAccountV0::LEN);
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: cetra_chamber::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    let user = Keypair::new();
+    let user_account = Pubkey::new_unique();
+
+    // 1. Load `UserAccount` fixture in legacy layout
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .program_test
+        .add_account(user_account, build_legacy_user_account(&user.pubkey()));
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &user.pubkey(),
+        USER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund user!");
+
+    // 2. Migrate `UserAccount`
+    let tx = Transaction::new_signed_with_payer(
+        &[Instruction {
+            program_id: cetra_chamber::id(),
+            data: cetra_chamber::instruction::MigrateUserAccount {}.data(),
+            accounts: cetra_chamber::accounts::MigrateUserAccount {
+                user_account,
+                user: user.pubkey(),
+                rent_sysvar: sysvar::rent::id(),
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+        }],
+        Some(&payer.pubkey()),
+        &[&payer, &user],
+        test_context.context.last_blockhash,
+    );
+
+    test_context
+        .process_transaction(tx)
+        .await
+        .expect("Unable to migrate user account!");
+
+    // 3. Ensure, that `UserAccount` is upgraded with state preserved
+    let account = test_context
+        .context
+        .banks_client
+        .get_account(user_account)
+        .await
+        .expect("Unable to fetch user account!")
+        .expect("User account is not found!");
+
+    assert_eq!(account.data.len(), state::UserAccount::LEN);
+
+    let migrated_user_account = state::UserAccount::try_deserialize(&mut account.data.as_slice())
+        .expect("Unexpected invalid UserAccount layout!");
+
+    assert_eq!(
+        migrated_user_account.version,
+        cetra_chamber::utils::USER_ACCOUNT_VERSION
+    );
+    assert_eq!(
+        migrated_user_account.status,
+        state::UserAccountStatus::ProcessDeposit
+    );
+    assert_eq!(migrated_user_account.locked_base_amount, 100);
+    assert_eq!(migrated_user_account.locked_quote_amount, 200);
+    assert_eq!(migrated_user_account.locked_shares_amount, 300);
+}
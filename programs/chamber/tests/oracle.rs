@@ -0,0 +1,88 @@
+use anchor_lang::prelude::{AccountInfo, Pubkey};
+use cetra_chamber::oracle;
+
+fn build_pyth_account(account_type: u32, linked_offset: usize, linked: &Pubkey) -> Vec<u8> {
+    let mut data = vec![0; 240];
+
+    data[0..4].copy_from_slice(&oracle::PYTH_MAGIC.to_le_bytes());
+    data[4..8].copy_from_slice(&oracle::PYTH_VERSION.to_le_bytes());
+    data[8..12].copy_from_slice(&account_type.to_le_bytes());
+    data[linked_offset..linked_offset + 32].copy_from_slice(linked.as_ref());
+
+    data
+}
+
+#[test]
+fn success() {
+    let pyth_program = Pubkey::new_unique();
+    let product_key = Pubkey::new_unique();
+    let price_key = Pubkey::new_unique();
+
+    let mut product_lamports = 0;
+    let mut product_data = build_pyth_account(oracle::PYTH_PRODUCT_TYPE, 16, &price_key);
+    let product = AccountInfo::new(
+        &product_key,
+        false,
+        false,
+        &mut product_lamports,
+        &mut product_data,
+        &pyth_program,
+        false,
+        0,
+    );
+
+    let mut price_lamports = 0;
+    let mut price_data = build_pyth_account(oracle::PYTH_PRICE_TYPE, 112, &product_key);
+    let price = AccountInfo::new(
+        &price_key,
+        false,
+        false,
+        &mut price_lamports,
+        &mut price_data,
+        &pyth_program,
+        false,
+        0,
+    );
+
+    // 1. Linked product and price accounts are valid
+    oracle::assert_pyth_accounts(&product, &price).expect("Unable to validate pyth accounts!");
+
+    // 2. Swapped accounts have unexpected types
+    assert!(oracle::assert_pyth_accounts(&price, &product).is_err());
+}
+
+#[test]
+fn failure_not_linked() {
+    let pyth_program = Pubkey::new_unique();
+    let product_key = Pubkey::new_unique();
+    let price_key = Pubkey::new_unique();
+
+    // 1. Product account links to another price account
+    let mut product_lamports = 0;
+    let mut product_data = build_pyth_account(oracle::PYTH_PRODUCT_TYPE, 16, &Pubkey::new_unique());
+    let product = AccountInfo::new(
+        &product_key,
+        false,
+        false,
+        &mut product_lamports,
+        &mut product_data,
+        &pyth_program,
+        false,
+        0,
+    );
+
+    let mut price_lamports = 0;
+    let mut price_data = build_pyth_account(oracle::PYTH_PRICE_TYPE, 112, &product_key);
+    let price = AccountInfo::new(
+        &price_key,
+        false,
+        false,
+        &mut price_lamports,
+        &mut price_data,
+        &pyth_program,
+        false,
+        0,
+    );
+
+    assert!(oracle::assert_pyth_accounts(&product, &price).is_err());
+}
@@ -0,0 +1,221 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const PROTOCOL_FEE_BPS: u64 = 1000;
+
+async fn start_test_context() -> TestContext {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let mut test_context = start_test_context().await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    let oracle_program = test_chamber
+        .fetch_oracle_program(&mut test_context)
+        .await
+        .expect("Unable to fetch oracle program!");
+
+    // 2. Initialize `ProtocolConfig` by program upgrade authority
+    let test_protocol = state::Protocol::new(&payer);
+
+    utils::set_upgrade_authority(&mut test_context, &payer.pubkey())
+        .await
+        .expect("Unable to set upgrade authority!");
+
+    test_protocol
+        .initialize_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to initialize protocol config!");
+
+    // 3. Allow `Tulip` farm program and `Chamber` oracle program
+    test_protocol
+        .update_protocol_config(
+            &mut test_context,
+            PROTOCOL_FEE_BPS,
+            vec![tulipv2_sdk_levfarm::ID],
+            vec![oracle_program],
+        )
+        .await
+        .expect("Unable to update protocol config!");
+
+    let protocol_config = test_protocol
+        .fetch_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to fetch protocol config!");
+
+    assert_eq!(protocol_config.admin, payer.pubkey());
+    assert_eq!(protocol_config.protocol_fee_bps, PROTOCOL_FEE_BPS);
+    assert_eq!(protocol_config.farm_programs[0], tulipv2_sdk_levfarm::ID);
+    assert_eq!(protocol_config.farm_programs[1], Pubkey::default());
+    assert_eq!(protocol_config.oracle_programs[0], oracle_program);
+    assert!(!protocol_config.is_paused);
+
+    // 4. Create first `ChamberRegistry` page
+    test_protocol
+        .create_chamber_registry_page(&mut test_context, 0)
+        .await
+        .expect("Unable to create chamber registry page!");
+
+    // 5. Ensure, that `Chamber` can't be initialized over not allowed farm
+    assert!(test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .is_err());
+
+    // 6. Allow `Chamber` farm and mint oracles
+    test_protocol
+        .add_farm_entry(
+            &mut test_context,
+            &test_chamber.get_farm(),
+            &tulipv2_sdk_levfarm::ID,
+        )
+        .await
+        .expect("Unable to add farm entry!");
+
+    for (mint, oracle) in [
+        (test_chamber.get_base_mint(), test_chamber.get_base_oracle()),
+        (
+            test_chamber.get_quote_mint(),
+            test_chamber.get_quote_oracle(),
+        ),
+    ] {
+        let product = test_chamber
+            .fetch_oracle_product(&mut test_context, &oracle)
+            .await
+            .expect("Unable to fetch oracle product!");
+
+        test_protocol
+            .add_oracle_entry(&mut test_context, &mint, &product, &oracle)
+            .await
+            .expect("Unable to add oracle entry!");
+    }
+
+    // 7. Ensure, that `Chamber` can't be initialized, while protocol is paused
+    test_protocol
+        .set_protocol_pause(&mut test_context, true)
+        .await
+        .expect("Unable to pause protocol!");
+
+    assert!(test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .is_err());
+
+    // 8. Resume protocol and initialize `Chamber`
+    test_protocol
+        .set_protocol_pause(&mut test_context, false)
+        .await
+        .expect("Unable to resume protocol!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_not_admin() {
+    let mut test_context = start_test_context().await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let not_admin = Keypair::new();
+
+    utils::transfer(&mut test_context, &payer, &not_admin.pubkey(), 1000000000)
+        .await
+        .expect("Unable to fund not admin!");
+
+    // 1. Initialize `ProtocolConfig`
+    utils::set_upgrade_authority(&mut test_context, &payer.pubkey())
+        .await
+        .expect("Unable to set upgrade authority!");
+
+    state::Protocol::new(&payer)
+        .initialize_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to initialize protocol config!");
+
+    // 2. Ensure, that not admin can't update `ProtocolConfig`
+    let test_protocol = state::Protocol::new(&not_admin);
+
+    assert!(test_protocol
+        .update_protocol_config(&mut test_context, 0, vec![], vec![])
+        .await
+        .is_err());
+    assert!(test_protocol
+        .set_protocol_pause(&mut test_context, true)
+        .await
+        .is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_not_upgrade_authority() {
+    let mut test_context = start_test_context().await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let not_authority = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &not_authority.pubkey(),
+        1000000000,
+    )
+    .await
+    .expect("Unable to fund not authority!");
+
+    utils::set_upgrade_authority(&mut test_context, &payer.pubkey())
+        .await
+        .expect("Unable to set upgrade authority!");
+
+    // 1. Ensure, that not upgrade authority can't initialize `ProtocolConfig`
+    assert!(state::Protocol::new(&not_authority)
+        .initialize_protocol_config(&mut test_context)
+        .await
+        .is_err());
+
+    // 2. Ensure, that upgrade authority initializes `ProtocolConfig`
+    let test_protocol = state::Protocol::new(&payer);
+
+    test_protocol
+        .initialize_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to initialize protocol config!");
+
+    let protocol_config = test_protocol
+        .fetch_protocol_config(&mut test_context)
+        .await
+        .expect("Unable to fetch protocol config!");
+
+    assert_eq!(protocol_config.admin, payer.pubkey());
+}
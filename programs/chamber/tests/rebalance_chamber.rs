@@ -0,0 +1,118 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let stranger = Keypair::new();
+
+    for keypair in [&owner, &stranger] {
+        utils::transfer(
+            &mut test_context,
+            &payer,
+            &keypair.pubkey(),
+            OWNER_FUND_LAMPORTS,
+        )
+        .await
+        .expect("Unable to fund keypair!");
+    }
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. `Chamber` can not be rebalanced by stranger
+    let error = test_chamber
+        .rebalance_chamber(&mut test_context, &payer, &stranger, 1, 100)
+        .await
+        .expect_err("Chamber is rebalanced by stranger!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::Unauthorized
+    ));
+
+    // 3. Position can not be moved into missing obligation
+    let error = test_chamber
+        .rebalance_chamber(&mut test_context, &payer, &owner, 2, 100)
+        .await
+        .expect_err("Chamber is rebalanced into missing obligation!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidObligation
+    ));
+
+    // 4. Empty position part can not be withdrawn
+    let error = test_chamber
+        .rebalance_chamber(&mut test_context, &payer, &owner, 0, 0)
+        .await
+        .expect_err("Chamber is rebalanced with empty withdraw!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidWithdrawPercent
+    ));
+
+    // 5. Position part can not be left in inactive obligation on obligation switch
+    let error = test_chamber
+        .rebalance_chamber(&mut test_context, &payer, &owner, 1, 50)
+        .await
+        .expect_err("Chamber position part is left in inactive obligation!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidWithdrawPercent
+    ));
+}
@@ -0,0 +1,139 @@
+mod state;
+mod utils;
+
+use cetra_chamber::math;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
+
+#[test]
+fn success_leverage_ltv() {
+    assert_eq!(math::get_leverage_ltv_bps(1).unwrap(), 0);
+    assert_eq!(math::get_leverage_ltv_bps(2).unwrap(), 5000);
+    assert_eq!(math::get_leverage_ltv_bps(3).unwrap(), 6666);
+
+    assert!(math::get_leverage_ltv_bps(0).is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let strategist = Keypair::new();
+    let stranger = Keypair::new();
+
+    for keypair in [&owner, &strategist, &stranger] {
+        utils::transfer(
+            &mut test_context,
+            &payer,
+            &keypair.pubkey(),
+            OWNER_FUND_LAMPORTS,
+        )
+        .await
+        .expect("Unable to fund keypair!");
+    }
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. `Chamber` can not be releveraged by stranger
+    let error = test_chamber
+        .releverage_chamber(&mut test_context, &payer, &stranger)
+        .await
+        .expect_err("Chamber is releveraged by stranger!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::Unauthorized
+    ));
+
+    // 3. Paused `Chamber` can not be releveraged
+    test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &owner, true)
+        .await
+        .expect("Unable to pause chamber!");
+
+    let error = test_chamber
+        .releverage_chamber(&mut test_context, &payer, &owner)
+        .await
+        .expect_err("Paused chamber is releveraged!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::ChamberPaused
+    ));
+
+    test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &owner, false)
+        .await
+        .expect("Unable to resume chamber!");
+
+    // 4. `Chamber` without obligation health config can not be releveraged
+    test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &owner,
+            &strategist.pubkey(),
+            &owner.pubkey(),
+        )
+        .await
+        .expect("Unable to update chamber roles!");
+
+    let error = test_chamber
+        .releverage_chamber(&mut test_context, &payer, &strategist)
+        .await
+        .expect_err("Chamber is releveraged without risk config!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::RiskNotConfigured
+    ));
+}
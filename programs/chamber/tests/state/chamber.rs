@@ -1,4 +1,4 @@
-use super::User;
+use super::{Protocol, User};
 use crate::utils;
 use anchor_lang::{prelude::AccountMeta, AnchorDeserialize, InstructionData, ToAccountMetas};
 use anchor_spl::{associated_token, token};
@@ -110,8 +110,21 @@ impl Chamber {
         test_context: &mut TestContext,
         payer: &Keypair,
     ) -> transport::Result<()> {
+        self.allow_chamber(test_context, payer).await?;
+
+        let registry_pages = Protocol::new(payer)
+            .fetch_protocol_config(test_context)
+            .await?
+            .registry_pages;
+
         let mut accounts = cetra_chamber::accounts::InitializeChamber {
             chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            chamber_registry: cetra_chamber::utils::derive_chamber_registry_address(
+                registry_pages.saturating_sub(1),
+            )
+            .0,
+            farm_entry: cetra_chamber::utils::derive_farm_entry_address(&self.farm).0,
             farm: self.farm,
             base_token: self.base_ata,
             quote_token: self.quote_ata,
@@ -120,6 +133,9 @@ impl Chamber {
             shares_mint: self.shares_mint,
             base_oracle: self.base_oracle,
             quote_oracle: self.quote_oracle,
+            base_oracle_entry: cetra_chamber::utils::derive_oracle_entry_address(&self.base_mint).0,
+            quote_oracle_entry: cetra_chamber::utils::derive_oracle_entry_address(&self.quote_mint)
+                .0,
             authority: self.authority,
             fee_manager: self.fee_manager,
             farm_program: tulipv2_sdk_levfarm::ID,
@@ -174,57 +190,1208 @@ impl Chamber {
         test_context.process_transaction(tx).await
     }
 
-    /// TODO: Implement with address lookup table support.
+    /// Initialize `ProtocolConfig` with `payer` upgrade authority admin, allowing `Chamber` farm, oracle program
+    /// and mint oracles, and first `ChamberRegistry` page, unless protocol is already configured by test.
+    async fn allow_chamber(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+    ) -> transport::Result<()> {
+        let protocol = Protocol::new(payer);
+
+        if protocol.is_initialized(test_context).await? {
+            return Ok(());
+        }
+
+        let oracle_program = self.fetch_oracle_program(test_context).await?;
+
+        utils::set_upgrade_authority(test_context, &payer.pubkey()).await?;
+        protocol.initialize_protocol_config(test_context).await?;
+        protocol
+            .update_protocol_config(
+                test_context,
+                0,
+                vec![tulipv2_sdk_levfarm::ID],
+                vec![oracle_program],
+            )
+            .await?;
+        protocol
+            .add_farm_entry(test_context, &self.farm, &tulipv2_sdk_levfarm::ID)
+            .await?;
+
+        for (mint, oracle) in [
+            (self.base_mint, self.base_oracle),
+            (self.quote_mint, self.quote_oracle),
+        ] {
+            let product = self.fetch_oracle_product(test_context, &oracle).await?;
+
+            protocol
+                .add_oracle_entry(test_context, &mint, &product, &oracle)
+                .await?;
+        }
+
+        protocol.create_chamber_registry_page(test_context, 0).await
+    }
+
+    /// Program, owning `Chamber` price oracles.
+    pub async fn fetch_oracle_program(
+        &self,
+        test_context: &mut TestContext,
+    ) -> transport::Result<Pubkey> {
+        let Some(account) = test_context
+            .context
+            .banks_client
+            .get_account(self.base_oracle)
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "Oracle is not found!".to_string(),
+            ));
+        };
+
+        Ok(account.owner)
+    }
+
+    /// `Pyth` product account, linked with `oracle` price account.
+    pub async fn fetch_oracle_product(
+        &self,
+        test_context: &mut TestContext,
+        oracle: &Pubkey,
+    ) -> transport::Result<Pubkey> {
+        let Some(account) = test_context
+            .context
+            .banks_client
+            .get_account(*oracle)
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "Oracle is not found!".to_string(),
+            ));
+        };
+
+        Ok(Pubkey::new(&account.data[112..144]))
+    }
+
     #[allow(unused)]
-    pub async fn deposit_chamber(
+    pub fn get_base_oracle(&self) -> Pubkey {
+        self.base_oracle
+    }
+
+    #[allow(unused)]
+    pub fn get_quote_oracle(&self) -> Pubkey {
+        self.quote_oracle
+    }
+
+    #[allow(unused)]
+    pub fn get_farm(&self) -> Pubkey {
+        self.farm
+    }
+
+    #[allow(unused)]
+    pub fn get_farm_config(&self) -> &tulip_levfarm_config::LevFarmConfig {
+        &self.farm_config
+    }
+
+    #[allow(unused)]
+    pub fn set_leverage(&mut self, leverage: u64) {
+        self.leverage = leverage;
+    }
+
+    #[allow(unused)]
+    pub async fn update_chamber_limits(
         &self,
-        _test_context: &mut TestContext,
-        user: &User,
-        base_amount: u64,
-        quote_amount: u64,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        tvl_cap: u64,
+        max_user_deposit: u64,
+        min_deposit: u64,
     ) -> transport::Result<()> {
-        let _user_keypair = user.get_keypair();
+        let accounts = cetra_chamber::accounts::UpdateChamberLimits {
+            chamber: self.chamber,
+            owner: self.owner.pubkey(),
+        }
+        .to_account_metas(None);
 
-        let _accounts = cetra_chamber::accounts::DepositChamber {
+        let data = cetra_chamber::instruction::UpdateChamberLimits {
+            tvl_cap,
+            max_user_deposit,
+            min_deposit,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn update_chamber_access(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        is_allowlist_enabled: bool,
+        gatekeeper: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateChamberAccess {
             chamber: self.chamber,
-            user_account: user.get_user_account_pubkey(),
-            user_shares: user.get_shares(),
-            user_base_token: user.get_base_ata(),
-            user_quote_token: user.get_quote_ata(),
-            chamber_shares_mint: self.shares_mint,
-            chamber_base_token: self.base_ata,
-            chamber_quote_token: self.quote_ata,
-            chamber_base_oracle: self.base_oracle,
-            chamber_quote_oracle: self.quote_oracle,
-            chamber_authority: self.authority,
-            chamber_farm_program: tulipv2_sdk_levfarm::ID,
-            user: user.get_pubkey(),
-            clock_sysvar: clock::id(),
-            rent_sysvar: rent::id(),
-            token_program: token::ID,
-            system_program: system_program::id(),
+            owner: self.owner.pubkey(),
         }
         .to_account_metas(None);
 
-        let _data = cetra_chamber::instruction::DepositChamber {
-            base_amount,
-            quote_amount,
+        let data = cetra_chamber::instruction::UpdateChamberAccess {
+            is_allowlist_enabled,
+            gatekeeper: *gatekeeper,
         }
         .data();
 
-        Ok(())
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
     }
 
-    /// TODO: Implement with address lookup table support.
     #[allow(unused)]
-    pub async fn withdraw_chamber(
+    pub async fn set_chamber_pause(
         &self,
-        _test_context: &mut TestContext,
-        _user: &User,
-        _base_amount: u64,
-        _quote_amount: u64,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        signer: &Keypair,
+        is_paused: bool,
     ) -> transport::Result<()> {
-        Ok(())
+        let accounts = cetra_chamber::accounts::SetChamberPause {
+            chamber: self.chamber,
+            signer: signer.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::SetChamberPause { is_paused }.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, signer],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_chamber_harvest(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        reward_mint: &Pubkey,
+        swap_amm: &Pubkey,
+        reward_farm: &Pubkey,
+        is_reward_to_base: bool,
+        max_slippage_bps: u64,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateChamberHarvest {
+            chamber: self.chamber,
+            owner: self.owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::UpdateChamberHarvest {
+            reward_mint: *reward_mint,
+            swap_amm: *swap_amm,
+            reward_farm: *reward_farm,
+            is_reward_to_base,
+            max_slippage_bps,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_chamber_risk(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        signer: &Keypair,
+        liquidation_threshold_bps: u64,
+        safety_margin_bps: u64,
+        target_ltv_bps: u64,
+        rebalance_drift_bps: u64,
+        max_slippage_bps: u64,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateChamberRisk {
+            chamber: self.chamber,
+            signer: signer.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::UpdateChamberRisk {
+            liquidation_threshold_bps,
+            safety_margin_bps,
+            target_ltv_bps,
+            rebalance_drift_bps,
+            max_slippage_bps,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, signer],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_chamber_risk_bounds(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        owner: &Keypair,
+        min_safety_margin_bps: u64,
+        max_safety_margin_bps: u64,
+        min_target_ltv_bps: u64,
+        max_target_ltv_bps: u64,
+        max_slippage_bps: u64,
+        min_rebalance_drift_bps: u64,
+        max_rebalance_drift_bps: u64,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateChamberRiskBounds {
+            chamber: self.chamber,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::UpdateChamberRiskBounds {
+            min_safety_margin_bps,
+            max_safety_margin_bps,
+            min_target_ltv_bps,
+            max_target_ltv_bps,
+            max_slippage_bps,
+            min_rebalance_drift_bps,
+            max_rebalance_drift_bps,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn update_chamber_roles(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        owner: &Keypair,
+        strategist: &Pubkey,
+        guardian: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateChamberRoles {
+            chamber: self.chamber,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::UpdateChamberRoles {
+            strategist: *strategist,
+            guardian: *guardian,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn propose_config_change(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        leverage: u64,
+        performance_fee_bps: u64,
+        delay: i64,
+        effective_timestamp: i64,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::ProposeConfigChange {
+            chamber: self.chamber,
+            owner: self.owner.pubkey(),
+            clock_sysvar: clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::ProposeConfigChange {
+            leverage,
+            performance_fee_bps,
+            delay,
+            effective_timestamp,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn execute_config_change(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::ExecuteConfigChange {
+            chamber: self.chamber,
+            cranker: payer.pubkey(),
+            clock_sysvar: clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::ExecuteConfigChange {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn cancel_config_change(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        signer: &Keypair,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::CancelConfigChange {
+            chamber: self.chamber,
+            signer: signer.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::CancelConfigChange {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, signer],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn add_allowlist_entry(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        user: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::AddAllowlistEntry {
+            chamber: self.chamber,
+            allowlist_entry: self.derive_allowlist_entry(user),
+            owner: self.owner.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::AddAllowlistEntry { user: *user }.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn remove_allowlist_entry(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        user: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::RemoveAllowlistEntry {
+            chamber: self.chamber,
+            allowlist_entry: self.derive_allowlist_entry(user),
+            owner: self.owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::RemoveAllowlistEntry {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, &self.owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub fn derive_allowlist_entry(&self, user: &Pubkey) -> Pubkey {
+        cetra_chamber::utils::derive_allowlist_entry_address(&self.chamber, user).0
+    }
+
+    #[allow(unused)]
+    pub fn get_owner(&self) -> Pubkey {
+        self.owner.pubkey()
+    }
+
+    /// TODO: Implement with address lookup table support.
+    #[allow(unused)]
+    pub async fn deposit_chamber(
+        &self,
+        _test_context: &mut TestContext,
+        user: &User,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> transport::Result<()> {
+        let _user_keypair = user.get_keypair();
+
+        let _accounts = cetra_chamber::accounts::DepositChamber {
+            chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            user_account: user.get_user_account_pubkey(),
+            user_shares: user.get_shares(),
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: cetra_chamber::utils::derive_native_token_address(
+                &user.get_user_account_pubkey(),
+            )
+            .0,
+            native_mint: token::spl_token::native_mint::ID,
+            permit: self.derive_allowlist_entry(&user.get_pubkey()),
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let _data = cetra_chamber::instruction::DepositChamber {
+            base_amount,
+            quote_amount,
+            native_amount: 0,
+        }
+        .data();
+
+        Ok(())
+    }
+
+    /// TODO: Implement market remaining accounts with address lookup table support.
+    #[allow(unused)]
+    pub async fn begin_deposit_chamber(
+        &self,
+        test_context: &mut TestContext,
+        user: &User,
+        user_shares: &Pubkey,
+        gatekeeper: Option<&Keypair>,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> transport::Result<()> {
+        let user_keypair = user.get_keypair();
+
+        let permit = gatekeeper
+            .map(|gatekeeper| gatekeeper.pubkey())
+            .unwrap_or_else(|| self.derive_allowlist_entry(&user.get_pubkey()));
+
+        let mut accounts = cetra_chamber::accounts::BeginDepositChamber {
+            chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            user_account: user.get_user_account_pubkey(),
+            user_shares: *user_shares,
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            permit,
+            user: user.get_pubkey(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let mut signers = vec![&user_keypair];
+
+        if let Some(gatekeeper) = gatekeeper {
+            accounts
+                .iter_mut()
+                .filter(|account| account.pubkey == permit)
+                .for_each(|account| account.is_signer = true);
+
+            signers.push(gatekeeper);
+        }
+
+        let data = cetra_chamber::instruction::BeginDepositChamber {
+            base_amount,
+            quote_amount,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&user_keypair.pubkey()),
+            &signers,
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Finish `BeginDeposit` stage of `user` deposit, signed only by `keeper`.
+    ///
+    /// TODO: Implement market remaining accounts with address lookup table support.
+    #[allow(unused)]
+    pub async fn process_deposit_chamber(
+        &self,
+        test_context: &mut TestContext,
+        keeper: &Keypair,
+        user: &User,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::ProcessDepositChamber {
+            chamber: self.chamber,
+            user_account: user.get_user_account_pubkey(),
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::ProcessDepositChamber {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&keeper.pubkey()),
+            &[keeper],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Finish `ProcessDeposit` stage of `user` deposit, signed only by `keeper`.
+    ///
+    /// TODO: Implement market remaining accounts with address lookup table support.
+    #[allow(unused)]
+    pub async fn end_deposit_chamber(
+        &self,
+        test_context: &mut TestContext,
+        keeper: &Keypair,
+        user: &User,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::EndDepositChamber {
+            chamber: self.chamber,
+            user_account: user.get_user_account_pubkey(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            user_shares: user.get_shares(),
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::EndDepositChamber {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&keeper.pubkey()),
+            &[keeper],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn cancel_deposit_chamber(
+        &self,
+        test_context: &mut TestContext,
+        signer: &Keypair,
+        user: &User,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::CancelDepositChamber {
+            chamber: self.chamber,
+            user_account: user.get_user_account_pubkey(),
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_authority: self.authority,
+            signer: signer.pubkey(),
+            token_program: token::ID,
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::CancelDepositChamber {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&signer.pubkey()),
+            &[signer],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub fn get_base_ata(&self) -> Pubkey {
+        self.base_ata
+    }
+
+    #[allow(unused)]
+    pub fn get_quote_ata(&self) -> Pubkey {
+        self.quote_ata
+    }
+
+    /// Wrap `native_amount` lamports into `Chamber`, returning program logs.
+    ///
+    /// TODO: Implement market remaining accounts with address lookup table support.
+    #[allow(unused)]
+    pub async fn deposit_chamber_native(
+        &self,
+        test_context: &mut TestContext,
+        user: &User,
+        user_shares: &Pubkey,
+        native_amount: u64,
+    ) -> transport::Result<Vec<String>> {
+        let user_keypair = user.get_keypair();
+
+        let accounts = cetra_chamber::accounts::DepositChamber {
+            chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            user_account: user.get_user_account_pubkey(),
+            user_shares: *user_shares,
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: cetra_chamber::utils::derive_native_token_address(
+                &user.get_user_account_pubkey(),
+            )
+            .0,
+            native_mint: token::spl_token::native_mint::ID,
+            permit: self.derive_allowlist_entry(&user.get_pubkey()),
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::DepositChamber {
+            base_amount: 0,
+            quote_amount: 0,
+            native_amount,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&user_keypair.pubkey()),
+            &[&user_keypair],
+            test_context.context.last_blockhash,
+        );
+
+        let result = test_context
+            .context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+
+        Ok(result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
+
+    /// TODO: Implement market remaining accounts with address lookup table support.
+    #[allow(unused)]
+    pub async fn withdraw_chamber(
+        &self,
+        test_context: &mut TestContext,
+        user: &User,
+        user_shares: &Pubkey,
+        shares_amount: u64,
+        min_base_out: u64,
+        min_quote_out: u64,
+    ) -> transport::Result<()> {
+        let user_keypair = user.get_keypair();
+
+        let accounts = cetra_chamber::accounts::WithdrawChamber {
+            chamber: self.chamber,
+            user_account: user.get_user_account_pubkey(),
+            user_shares: *user_shares,
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: cetra_chamber::utils::derive_native_token_address(
+                &user.get_user_account_pubkey(),
+            )
+            .0,
+            native_mint: token::spl_token::native_mint::ID,
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::WithdrawChamber {
+            shares_amount,
+            output_mint: None,
+            min_base_out,
+            min_quote_out,
+            unwrap_native: false,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&user_keypair.pubkey()),
+            &[&user_keypair],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Withdraw from `Chamber` with `liquidity_program` and `amm_id` as swap route, other market
+    /// accounts are placeholders, so only market account checks pass.
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn withdraw_chamber_with_swap_route(
+        &self,
+        test_context: &mut TestContext,
+        user: &User,
+        shares_amount: u64,
+        output_mint: &Pubkey,
+        liquidity_program: &Pubkey,
+        amm_id: &Pubkey,
+    ) -> transport::Result<()> {
+        let user_keypair = user.get_keypair();
+
+        let mut accounts = cetra_chamber::accounts::WithdrawChamber {
+            chamber: self.chamber,
+            user_account: user.get_user_account_pubkey(),
+            user_shares: user.get_shares(),
+            user_base_token: user.get_base_ata(),
+            user_quote_token: user.get_quote_ata(),
+            chamber_shares_mint: self.shares_mint,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            native_token: cetra_chamber::utils::derive_native_token_address(
+                &user.get_user_account_pubkey(),
+            )
+            .0,
+            native_mint: token::spl_token::native_mint::ID,
+            user: user.get_pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let mut remaining_accounts: Vec<AccountMeta> = (0..64)
+            .map(|_| AccountMeta::new(Pubkey::new_unique(), false))
+            .collect();
+
+        for (index, pubkey) in [
+            (0, self.derive_user_farm(0).0),
+            (2, self.farm),
+            (20, self.derive_user_farm_obligation(0, 0).0),
+            (24, *liquidity_program),
+            (25, *amm_id),
+            (27, self.farm_config.raydium_amm_open_orders),
+            (28, self.farm_config.raydium_amm_quantities_or_target),
+            (30, self.farm_config.raydium_coin_account),
+            (31, self.farm_config.raydium_pc_account),
+            (35, self.farm_config.serum_market),
+            (36, self.farm_config.serum_coin_vault_account),
+            (37, self.farm_config.serum_pc_vault_account),
+        ] {
+            remaining_accounts[index] = AccountMeta::new(pubkey, false);
+        }
+
+        accounts.extend(remaining_accounts);
+
+        let data = cetra_chamber::instruction::WithdrawChamber {
+            shares_amount,
+            output_mint: Some(*output_mint),
+            min_base_out: 0,
+            min_quote_out: 0,
+            unwrap_native: false,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&user_keypair.pubkey()),
+            &[&user_keypair],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Deleverage `Chamber` without market accounts, so only checks preceding market logic pass.
+    #[allow(unused)]
+    pub async fn deleverage_chamber(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        cranker: &Keypair,
+        obligation_index: u8,
+        withdraw_percent: u8,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::DeleverageChamber {
+            chamber: self.chamber,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            cranker: cranker.pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::DeleverageChamber {
+            obligation_index,
+            min_coin_swap: 0,
+            min_pc_swap: 0,
+            withdraw_percent,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, cranker],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Rebalance `Chamber` without market accounts, so only checks preceding market logic pass.
+    #[allow(unused)]
+    pub async fn rebalance_chamber(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        strategist: &Keypair,
+        target_obligation: u8,
+        withdraw_percent: u8,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::RebalanceChamber {
+            chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            strategist: strategist.pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::RebalanceChamber {
+            target_obligation,
+            withdraw_percent,
+        }
+        .data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, strategist],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    /// Releverage `Chamber` without market accounts, so only checks preceding market logic pass.
+    #[allow(unused)]
+    pub async fn releverage_chamber(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        strategist: &Keypair,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::ReleverageChamber {
+            chamber: self.chamber,
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_base_oracle: self.base_oracle,
+            chamber_quote_oracle: self.quote_oracle,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            strategist: strategist.pubkey(),
+            clock_sysvar: clock::id(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::ReleverageChamber {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, strategist],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_chamber(
+        &self,
+        test_context: &mut TestContext,
+        payer: &Keypair,
+        owner: &Keypair,
+        owner_base_token: &Pubkey,
+        owner_quote_token: &Pubkey,
+        user_farm_index: u64,
+        obligation_indexes: [u64; 2],
+    ) -> transport::Result<()> {
+        let mut accounts = cetra_chamber::accounts::CloseChamber {
+            chamber: self.chamber,
+            chamber_base_token: self.base_ata,
+            chamber_quote_token: self.quote_ata,
+            chamber_shares_mint: self.shares_mint,
+            owner_base_token: *owner_base_token,
+            owner_quote_token: *owner_quote_token,
+            chamber_authority: self.authority,
+            chamber_farm_program: tulipv2_sdk_levfarm::ID,
+            owner: owner.pubkey(),
+            token_program: token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let (user_farm, _) = self.derive_user_farm(user_farm_index);
+        let (user_farm_obligation, _) =
+            self.derive_user_farm_obligation(user_farm_index, obligation_indexes[0]);
+        let (user_farm_obligation_1, _) =
+            self.derive_user_farm_obligation(user_farm_index, obligation_indexes[1]);
+        let (position_info_account, _) =
+            tulip_levfarm_derivations::derive_user_position_info_address(
+                user_farm,
+                tulipv2_sdk_levfarm::ID,
+                0,
+            );
+
+        accounts.extend(vec![
+            AccountMeta::new(user_farm, false),
+            AccountMeta::new(user_farm_obligation, false),
+            AccountMeta::new(user_farm_obligation_1, false),
+            AccountMeta::new(position_info_account, false),
+        ]);
+
+        let data = cetra_chamber::instruction::CloseChamber {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
     }
 
     #[allow(unused)]
@@ -236,9 +1403,12 @@ impl Chamber {
             .context
             .banks_client
             .get_account(self.chamber)
-            .await? else {
-                return Err(transport::TransportError::Custom("Chamber is not found!".to_string()));
-            };
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "Chamber is not found!".to_string(),
+            ));
+        };
 
         let mut account_data = &account.data[8..];
 
@@ -1,5 +1,7 @@
 mod chamber;
+mod protocol;
 mod user;
 
 pub use chamber::*;
+pub use protocol::*;
 pub use user::*;
@@ -0,0 +1,257 @@
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use cetra_program_test::{
+    solana_sdk::{
+        instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        system_program, transaction::Transaction, transport,
+    },
+    TestContext,
+};
+
+pub struct Protocol {
+    admin: Keypair,
+    protocol_config: Pubkey,
+}
+
+impl Protocol {
+    pub fn new(admin: &Keypair) -> Self {
+        Protocol {
+            admin: crate::utils::clone_keypair(admin),
+            protocol_config: cetra_chamber::utils::derive_protocol_config_address().0,
+        }
+    }
+
+    pub async fn initialize_protocol_config(
+        &self,
+        test_context: &mut TestContext,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::InitializeProtocolConfig {
+            protocol_config: self.protocol_config,
+            program: cetra_chamber::id(),
+            program_data: cetra_chamber::utils::derive_program_data_address().0,
+            admin: self.admin.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::InitializeProtocolConfig {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    pub async fn update_protocol_config(
+        &self,
+        test_context: &mut TestContext,
+        protocol_fee_bps: u64,
+        farm_programs: Vec<Pubkey>,
+        oracle_programs: Vec<Pubkey>,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::UpdateProtocolConfig {
+            protocol_config: self.protocol_config,
+            signer: self.admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::UpdateProtocolConfig {
+            admin: self.admin.pubkey(),
+            protocol_fee_bps,
+            farm_programs,
+            oracle_programs,
+        }
+        .data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    #[allow(unused)]
+    pub async fn set_protocol_pause(
+        &self,
+        test_context: &mut TestContext,
+        is_paused: bool,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::SetProtocolPause {
+            protocol_config: self.protocol_config,
+            admin: self.admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::SetProtocolPause { is_paused }.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    pub async fn add_farm_entry(
+        &self,
+        test_context: &mut TestContext,
+        farm: &Pubkey,
+        farm_program: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::AddFarmEntry {
+            protocol_config: self.protocol_config,
+            farm_entry: cetra_chamber::utils::derive_farm_entry_address(farm).0,
+            farm: *farm,
+            farm_program: *farm_program,
+            admin: self.admin.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::AddFarmEntry {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    pub async fn add_oracle_entry(
+        &self,
+        test_context: &mut TestContext,
+        mint: &Pubkey,
+        product: &Pubkey,
+        price: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::AddOracleEntry {
+            protocol_config: self.protocol_config,
+            oracle_entry: cetra_chamber::utils::derive_oracle_entry_address(mint).0,
+            mint: *mint,
+            product: *product,
+            price: *price,
+            admin: self.admin.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::AddOracleEntry {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    #[allow(unused)]
+    pub async fn remove_farm_entry(
+        &self,
+        test_context: &mut TestContext,
+        farm: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::RemoveFarmEntry {
+            protocol_config: self.protocol_config,
+            farm_entry: cetra_chamber::utils::derive_farm_entry_address(farm).0,
+            admin: self.admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::RemoveFarmEntry {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    pub async fn create_chamber_registry_page(
+        &self,
+        test_context: &mut TestContext,
+        index: u32,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::CreateChamberRegistryPage {
+            protocol_config: self.protocol_config,
+            chamber_registry: cetra_chamber::utils::derive_chamber_registry_address(index).0,
+            payer: self.admin.pubkey(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::CreateChamberRegistryPage {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    #[allow(unused)]
+    pub async fn sync_chamber_registry(
+        &self,
+        test_context: &mut TestContext,
+        index: u32,
+        chamber: &Pubkey,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::SyncChamberRegistry {
+            chamber_registry: cetra_chamber::utils::derive_chamber_registry_address(index).0,
+            chamber: *chamber,
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::SyncChamberRegistry {}.data();
+
+        self.process_instruction(test_context, accounts, data).await
+    }
+
+    #[allow(unused)]
+    pub async fn fetch_chamber_registry(
+        &self,
+        test_context: &mut TestContext,
+        index: u32,
+    ) -> transport::Result<cetra_chamber::state::ChamberRegistry> {
+        let Some(account) = test_context
+            .context
+            .banks_client
+            .get_account(cetra_chamber::utils::derive_chamber_registry_address(index).0)
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "ChamberRegistry is not found!".to_string(),
+            ));
+        };
+
+        let mut account_data = &account.data[8..];
+
+        let chamber_registry =
+            cetra_chamber::state::ChamberRegistry::deserialize(&mut account_data)
+                .expect("Unexpected invalid ChamberRegistry layout!");
+
+        Ok(chamber_registry)
+    }
+
+    pub async fn fetch_protocol_config(
+        &self,
+        test_context: &mut TestContext,
+    ) -> transport::Result<cetra_chamber::state::ProtocolConfig> {
+        let Some(account) = test_context
+            .context
+            .banks_client
+            .get_account(self.protocol_config)
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "ProtocolConfig is not found!".to_string(),
+            ));
+        };
+
+        let mut account_data = &account.data[8..];
+
+        let protocol_config = cetra_chamber::state::ProtocolConfig::deserialize(&mut account_data)
+            .expect("Unexpected invalid ProtocolConfig layout!");
+
+        Ok(protocol_config)
+    }
+
+    pub async fn is_initialized(&self, test_context: &mut TestContext) -> transport::Result<bool> {
+        Ok(test_context
+            .context
+            .banks_client
+            .get_account(self.protocol_config)
+            .await?
+            .is_some())
+    }
+
+    async fn process_instruction(
+        &self,
+        test_context: &mut TestContext,
+        accounts: Vec<cetra_program_test::solana_sdk::instruction::AccountMeta>,
+        data: Vec<u8>,
+    ) -> transport::Result<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&self.admin.pubkey()),
+            &[&self.admin],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+}
@@ -1,6 +1,6 @@
 use super::Chamber;
 use crate::utils;
-use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use anchor_lang::{prelude::AccountMeta, AnchorDeserialize, InstructionData, ToAccountMetas};
 use anchor_spl::{associated_token, token};
 use cetra_program_test::{
     solana_sdk::{
@@ -73,7 +73,54 @@ impl User {
         &self,
         test_context: &mut TestContext,
     ) -> transport::Result<()> {
-        let accounts = cetra_chamber::accounts::CreateUserAccount {
+        self.create_user_account_with_remaining_accounts(test_context, vec![])
+            .await
+    }
+
+    #[allow(unused)]
+    pub async fn create_user_account_with_remaining_accounts(
+        &self,
+        test_context: &mut TestContext,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> transport::Result<()> {
+        let mut accounts = cetra_chamber::accounts::CreateUserAccount {
+            chamber: self.chamber,
+            user_account: self.user_account,
+            user_shares: self.user_shares,
+            shares_mint: self.shares_mint,
+            user: self.keypair.pubkey(),
+            rent_sysvar: rent::id(),
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        accounts.extend(remaining_accounts);
+
+        let data = cetra_chamber::instruction::CreateUserAccount {}.data();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn create_user_account_with_gatekeeper(
+        &self,
+        test_context: &mut TestContext,
+        gatekeeper: &Keypair,
+    ) -> transport::Result<()> {
+        let mut accounts = cetra_chamber::accounts::CreateUserAccount {
             chamber: self.chamber,
             user_account: self.user_account,
             user_shares: self.user_shares,
@@ -86,8 +133,41 @@ impl User {
         }
         .to_account_metas(None);
 
+        accounts.push(AccountMeta::new_readonly(gatekeeper.pubkey(), true));
+
         let data = cetra_chamber::instruction::CreateUserAccount {}.data();
 
+        let tx = Transaction::new_signed_with_payer(
+            &[Instruction {
+                program_id: cetra_chamber::id(),
+                data,
+                accounts,
+            }],
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair, gatekeeper],
+            test_context.context.last_blockhash,
+        );
+
+        test_context.process_transaction(tx).await
+    }
+
+    #[allow(unused)]
+    pub async fn close_user_account(
+        &self,
+        test_context: &mut TestContext,
+        close_shares: bool,
+    ) -> transport::Result<()> {
+        let accounts = cetra_chamber::accounts::CloseUserAccount {
+            chamber: self.chamber,
+            user_account: self.user_account,
+            user_shares: self.user_shares,
+            user: self.keypair.pubkey(),
+            token_program: token::ID,
+        }
+        .to_account_metas(None);
+
+        let data = cetra_chamber::instruction::CloseUserAccount { close_shares }.data();
+
         let tx = Transaction::new_signed_with_payer(
             &[Instruction {
                 program_id: cetra_chamber::id(),
@@ -136,9 +216,12 @@ impl User {
             .context
             .banks_client
             .get_account(self.user_account)
-            .await? else {
-                return Err(transport::TransportError::Custom("UserAccount is not found!".to_string()));
-            };
+            .await?
+        else {
+            return Err(transport::TransportError::Custom(
+                "UserAccount is not found!".to_string(),
+            ));
+        };
 
         let mut account_data = &account.data[8..];
 
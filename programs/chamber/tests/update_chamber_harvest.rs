@@ -0,0 +1,146 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Configure `Chamber` harvesting
+    let reward_mint = Pubkey::new_unique();
+    let swap_amm = Pubkey::new_unique();
+    let reward_farm = Pubkey::new_unique();
+
+    test_chamber
+        .update_chamber_harvest(
+            &mut test_context,
+            &payer,
+            &reward_mint,
+            &swap_amm,
+            &reward_farm,
+            true,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber harvest!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.harvest.reward_mint, reward_mint);
+    assert_eq!(chamber.harvest.swap_amm, swap_amm);
+    assert_eq!(chamber.harvest.reward_farm, reward_farm);
+    assert!(chamber.harvest.is_reward_to_base);
+    // Performance fee is changed by config change only
+    assert_eq!(chamber.harvest.performance_fee_bps, 0);
+    assert_eq!(chamber.harvest.max_slippage_bps, 100);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_slippage() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Configure `Chamber` harvesting with slippage above 100%
+    test_chamber
+        .update_chamber_harvest(
+            &mut test_context,
+            &payer,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            true,
+            10001,
+        )
+        .await
+        .expect_err("Chamber harvest slippage must not exceed 100%!");
+}
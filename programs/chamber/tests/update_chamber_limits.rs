@@ -0,0 +1,148 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Update limits
+    test_chamber
+        .update_chamber_limits(&mut test_context, &payer, 1000000, 10000, 100)
+        .await
+        .expect("Unable to update chamber limits!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.limits.tvl_cap, 1000000);
+    assert_eq!(chamber.limits.max_user_deposit, 10000);
+    assert_eq!(chamber.limits.min_deposit, 100);
+
+    // 3. Minimum deposit above maximum user deposit is rejected
+    test_chamber
+        .update_chamber_limits(&mut test_context, &payer, 1000000, 100, 10000)
+        .await
+        .expect_err("Minimum deposit above maximum user deposit is accepted!");
+
+    // 4. Maximum user deposit above tvl cap is rejected
+    test_chamber
+        .update_chamber_limits(&mut test_context, &payer, 1000, 10000, 100)
+        .await
+        .expect_err("Maximum user deposit above tvl cap is accepted!");
+
+    // 5. Ensure, that limits are unchanged
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.limits.max_user_deposit, 10000);
+    assert_eq!(chamber.limits.min_deposit, 100);
+}
+
+#[test]
+fn success_limits() {
+    // Zero limits are disabled
+    let limits =
+        cetra_chamber::state::ChamberLimits::new(0, 0, 100).expect("Unable to build limits!");
+
+    limits
+        .assert_deposit(u64::MAX / 2, u64::MAX / 2, 100)
+        .expect("Disabled limits are applied!");
+    assert!(limits.assert_deposit(0, 0, 99).is_err());
+
+    let limits =
+        cetra_chamber::state::ChamberLimits::new(1000, 500, 100).expect("Unable to build limits!");
+
+    assert!(limits.assert_deposit(900, 0, 200).is_err());
+    assert!(limits.assert_deposit(0, 400, 200).is_err());
+    limits
+        .assert_deposit(0, 300, 200)
+        .expect("Deposit within limits is rejected!");
+
+    assert!(cetra_chamber::state::ChamberLimits::new(0, 100, 101).is_err());
+    assert!(cetra_chamber::state::ChamberLimits::new(100, 0, 101).is_err());
+    assert!(cetra_chamber::state::ChamberLimits::new(100, 101, 0).is_err());
+}
+
+#[test]
+fn success_user_deposited_value() {
+    let limits =
+        cetra_chamber::state::ChamberLimits::new(0, 500, 0).expect("Unable to build limits!");
+
+    let mut user_account = cetra_chamber::state::UserAccount {
+        chamber: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        shares: Pubkey::new_unique(),
+        status: cetra_chamber::state::UserAccountStatus::Ready,
+        locked_base_amount: 0,
+        locked_quote_amount: 0,
+        locked_shares_amount: 0,
+        version: cetra_chamber::utils::USER_ACCOUNT_VERSION,
+        deposited_value: 0,
+        locked_value: 0,
+        idle_base_amount: 0,
+        idle_quote_amount: 0,
+        reserved: [0; cetra_chamber::utils::USER_ACCOUNT_RESERVED_LEN],
+    };
+
+    // Cancelled deposit is not recorded, ended one is
+    user_account.begin_deposit(100, 100, 100, 300);
+    user_account.cancel_deposit();
+    assert_eq!(user_account.deposited_value, 0);
+
+    user_account.begin_deposit(100, 100, 100, 300);
+    user_account.end_deposit().expect("Unable to end deposit!");
+    assert_eq!(user_account.deposited_value, 300);
+
+    // Deposited value caps user regardless of shares balance, e.g. after shares transfer
+    assert!(limits
+        .assert_deposit(0, user_account.deposited_value, 300)
+        .is_err());
+
+    // Withdrawn value releases cap
+    user_account.record_withdraw(200);
+    limits
+        .assert_deposit(0, user_account.deposited_value, 300)
+        .expect("Deposit within limits is rejected!");
+
+    user_account.record_withdraw(1000);
+    assert_eq!(user_account.deposited_value, 0);
+}
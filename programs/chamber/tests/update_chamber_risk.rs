@@ -0,0 +1,224 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Configure `Chamber` obligation health
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            500,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber risk!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.risk.liquidation_threshold_bps, 8500);
+    assert_eq!(chamber.risk.safety_margin_bps, 1000);
+    assert_eq!(chamber.risk.target_ltv_bps, 6000);
+    assert_eq!(chamber.risk.get_trigger_ltv_bps(), 7500);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_target_ltv() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Configure `Chamber` target LTV, which already triggers deleveraging
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            7500,
+            500,
+            100,
+        )
+        .await
+        .expect_err("Chamber target LTV must be below deleveraging trigger!");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_bps() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Configure `Chamber` rebalance drift, which triggers rebalance on any drift
+    test_chamber
+        .update_chamber_risk(&mut test_context, &payer, &owner, 8500, 1000, 6000, 0, 100)
+        .await
+        .expect_err("Chamber rebalance drift must not be zero!");
+
+    // 4. Configure `Chamber` rebalance drift above 100%
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            10001,
+            100,
+        )
+        .await
+        .expect_err("Chamber rebalance drift must not exceed 100%!");
+
+    // 5. Configure `Chamber` deleveraging slippage above 100%
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            500,
+            10001,
+        )
+        .await
+        .expect_err("Chamber deleveraging slippage must not exceed 100%!");
+}
@@ -0,0 +1,350 @@
+mod state;
+mod utils;
+
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+const OWNER_FUND_LAMPORTS: u64 = 1000000000;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn success() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let strategist = Keypair::new();
+    let guardian = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(chamber.roles.is_strategist(&owner.pubkey()));
+    assert!(chamber.roles.is_guardian(&owner.pubkey()));
+
+    // 3. Assign roles
+    test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &owner,
+            &strategist.pubkey(),
+            &guardian.pubkey(),
+        )
+        .await
+        .expect("Unable to update chamber roles!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(chamber.roles.is_strategist(&strategist.pubkey()));
+    assert!(chamber.roles.is_guardian(&guardian.pubkey()));
+
+    // 4. Pause `Chamber` as guardian
+    test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &guardian, true)
+        .await
+        .expect("Unable to pause chamber!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(chamber.access.is_paused);
+
+    // 5. Resume `Chamber` as owner
+    test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &owner, false)
+        .await
+        .expect("Unable to resume chamber!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert!(!chamber.access.is_paused);
+
+    // 6. Bound safety margin, target LTV, slippage and rebalance drift as owner
+    test_chamber
+        .update_chamber_risk_bounds(
+            &mut test_context,
+            &payer,
+            &owner,
+            500,
+            1500,
+            5000,
+            7000,
+            200,
+            200,
+            1000,
+        )
+        .await
+        .expect("Unable to update chamber risk bounds!");
+
+    // 7. Update liquidation threshold as owner
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            500,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber risk!");
+
+    // 8. Update hedge parameters as strategist within owner bounds
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &strategist,
+            8500,
+            500,
+            7000,
+            500,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber risk!");
+
+    let chamber = test_chamber
+        .fetch_chamber(&mut test_context)
+        .await
+        .expect("Unable to fetch chamber!");
+
+    assert_eq!(chamber.risk.liquidation_threshold_bps, 8500);
+    assert_eq!(chamber.risk.safety_margin_bps, 500);
+    assert_eq!(chamber.risk.target_ltv_bps, 7000);
+    assert_eq!(chamber.risk_bounds.max_target_ltv_bps, 7000);
+    assert_eq!(chamber.risk_bounds.max_slippage_bps, 200);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_wrong_role() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+    let strategist = Keypair::new();
+    let guardian = Keypair::new();
+
+    utils::transfer(
+        &mut test_context,
+        &payer,
+        &owner.pubkey(),
+        OWNER_FUND_LAMPORTS,
+    )
+    .await
+    .expect("Unable to fund owner!");
+
+    // 1. Build `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    // 2. Initialize `Chamber`
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 3. Assign roles
+    test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &owner,
+            &strategist.pubkey(),
+            &guardian.pubkey(),
+        )
+        .await
+        .expect("Unable to update chamber roles!");
+
+    // 4. Pause `Chamber` as strategist
+    assert!(test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &strategist, true)
+        .await
+        .is_err());
+
+    // 5. Update liquidation threshold as owner
+    test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &owner,
+            8500,
+            1000,
+            6000,
+            500,
+            100,
+        )
+        .await
+        .expect("Unable to update chamber risk!");
+
+    // 6. Change liquidation threshold as strategist
+    assert!(test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &strategist,
+            9000,
+            1000,
+            6000,
+            500,
+            100
+        )
+        .await
+        .is_err());
+
+    // 7. Update roles as strategist
+    assert!(test_chamber
+        .update_chamber_roles(
+            &mut test_context,
+            &payer,
+            &strategist,
+            &strategist.pubkey(),
+            &strategist.pubkey(),
+        )
+        .await
+        .is_err());
+
+    // 8. Update risk bounds as strategist
+    assert!(test_chamber
+        .update_chamber_risk_bounds(&mut test_context, &payer, &strategist, 0, 0, 0, 0, 0, 0, 0)
+        .await
+        .is_err());
+
+    // 9. Bound target LTV below configured one as owner
+    assert!(test_chamber
+        .update_chamber_risk_bounds(&mut test_context, &payer, &owner, 0, 0, 0, 5000, 0, 0, 0)
+        .await
+        .is_err());
+
+    // 10. Exceed owner target LTV bound as strategist
+    test_chamber
+        .update_chamber_risk_bounds(&mut test_context, &payer, &owner, 0, 0, 0, 6500, 0, 0, 0)
+        .await
+        .expect("Unable to update chamber risk bounds!");
+
+    assert!(test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &strategist,
+            8500,
+            1000,
+            7000,
+            500,
+            100,
+        )
+        .await
+        .is_err());
+
+    // 11. Exceed default slippage bound as strategist
+    assert!(test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &strategist,
+            8500,
+            1000,
+            6000,
+            500,
+            500,
+        )
+        .await
+        .is_err());
+
+    // 12. Exceed default rebalance drift bound as strategist
+    assert!(test_chamber
+        .update_chamber_risk(
+            &mut test_context,
+            &payer,
+            &strategist,
+            8500,
+            1000,
+            6000,
+            3000,
+            100,
+        )
+        .await
+        .is_err());
+
+    // 13. Resume `Chamber`, paused by guardian, as guardian
+    test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &guardian, true)
+        .await
+        .expect("Unable to pause chamber!");
+
+    assert!(test_chamber
+        .set_chamber_pause(&mut test_context, &payer, &guardian, false)
+        .await
+        .is_err());
+}
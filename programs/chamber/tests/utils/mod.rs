@@ -1,8 +1,16 @@
 use anchor_spl::token;
 use cetra_program_test::{
     solana_sdk::{
-        pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
-        transaction::Transaction, transport,
+        account::{Account, AccountSharedData},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+        clock::Clock,
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_instruction,
+        transaction::Transaction,
+        transport,
     },
     TestContext,
 };
@@ -53,3 +61,126 @@ pub async fn transfer(
 pub fn clone_keypair(keypair: &Keypair) -> Keypair {
     Keypair::from_bytes(&keypair.to_bytes()).unwrap()
 }
+
+#[allow(unused)]
+pub async fn get_unix_timestamp(test_context: &mut TestContext) -> i64 {
+    test_context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+#[allow(unused)]
+pub async fn set_unix_timestamp(test_context: &mut TestContext, unix_timestamp: i64) {
+    let mut clock = test_context
+        .context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap();
+
+    clock.unix_timestamp = unix_timestamp;
+    test_context.context.set_sysvar(&clock);
+}
+
+/// Credit `amount` of `mint` tokens to `token_account`, bypassing mint authority.
+#[allow(unused)]
+pub async fn mint_tokens_unchecked(
+    test_context: &mut TestContext,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    amount: u64,
+) -> transport::Result<()> {
+    for (address, is_mint) in [(*mint, true), (*token_account, false)] {
+        let mut account = test_context
+            .context
+            .banks_client
+            .get_account(address)
+            .await?
+            .expect("Token account is not found!");
+
+        if is_mint {
+            let mut mint_state = token::spl_token::state::Mint::unpack(&account.data).unwrap();
+            mint_state.supply += amount;
+            mint_state.pack_into_slice(&mut account.data);
+        } else {
+            let mut token_state = token::spl_token::state::Account::unpack(&account.data).unwrap();
+            token_state.amount += amount;
+            token_state.pack_into_slice(&mut account.data);
+        }
+
+        test_context
+            .context
+            .set_account(&address, &AccountSharedData::from(account));
+    }
+
+    Ok(())
+}
+
+/// Redeploy loaded `Chamber` program as upgradeable one with `upgrade_authority`.
+#[allow(unused)]
+pub async fn set_upgrade_authority(
+    test_context: &mut TestContext,
+    upgrade_authority: &Pubkey,
+) -> transport::Result<()> {
+    let program_data_address = cetra_chamber::utils::derive_program_data_address().0;
+    let program_data_offset = UpgradeableLoaderState::programdata_data_offset().unwrap();
+
+    let program = test_context
+        .context
+        .banks_client
+        .get_account(cetra_chamber::id())
+        .await?
+        .ok_or_else(|| transport::TransportError::Custom("Program is not found!".to_string()))?;
+
+    let elf = if program.owner == bpf_loader_upgradeable::id() {
+        test_context
+            .context
+            .banks_client
+            .get_account(program_data_address)
+            .await?
+            .ok_or_else(|| {
+                transport::TransportError::Custom("Program data is not found!".to_string())
+            })?
+            .data[program_data_offset..]
+            .to_vec()
+    } else {
+        program.data
+    };
+
+    // `ProgramData` state: variant, deployment slot and optional upgrade authority
+    let mut program_data = vec![];
+    program_data.extend_from_slice(&3u32.to_le_bytes());
+    program_data.extend_from_slice(&0u64.to_le_bytes());
+    program_data.push(1);
+    program_data.extend_from_slice(upgrade_authority.as_ref());
+    program_data.extend_from_slice(&elf);
+
+    // `Program` state: variant and program data address
+    let mut program = vec![];
+    program.extend_from_slice(&2u32.to_le_bytes());
+    program.extend_from_slice(program_data_address.as_ref());
+
+    let rent = test_context.get_rent().await;
+
+    for (address, data, executable) in [
+        (program_data_address, program_data, false),
+        (cetra_chamber::id(), program, true),
+    ] {
+        test_context.context.set_account(
+            &address,
+            &AccountSharedData::from(Account {
+                lamports: rent.minimum_balance(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable,
+                rent_epoch: 0,
+            }),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+mod state;
+mod utils;
+
+use cetra_chamber::math;
+use cetra_program_test::{solana_program_test::*, *};
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::TransactionError, transport,
+};
+
+const USER_FUND_LAMPORTS: u64 = 1000000000;
+
+fn is_chamber_error(
+    error: &transport::TransportError,
+    chamber_error: cetra_chamber::error::ChamberError,
+) -> bool {
+    let chamber_error: u32 = chamber_error.into();
+
+    matches!(
+        error,
+        transport::TransportError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )) if *code == chamber_error
+    )
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_shares() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build users with `UserAccount`
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create user account!");
+
+    let other_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build other user with chamber!");
+
+    other_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create other user account!");
+
+    // 3. Withdraw nothing
+    test_chamber
+        .withdraw_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            0,
+            0,
+            0,
+        )
+        .await
+        .expect_err("Zero shares are withdrawn!");
+
+    // 4. Withdraw more shares, than `User` owns
+    test_chamber
+        .withdraw_chamber(
+            &mut test_context,
+            &test_user,
+            &test_user.get_shares(),
+            1,
+            0,
+            0,
+        )
+        .await
+        .expect_err("Not owned shares are withdrawn!");
+
+    // 5. Withdraw with shares account of other `User`
+    test_chamber
+        .withdraw_chamber(
+            &mut test_context,
+            &test_user,
+            &other_user.get_shares(),
+            1,
+            0,
+            0,
+        )
+        .await
+        .expect_err("Shares of other user are withdrawn!");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failure_invalid_swap_route() {
+    let rpc_accounts_loader = RpcAccountsLoader::default();
+    let mut program_test_loader = ProgramTestLoader::default();
+
+    program_test_loader
+        .program_test
+        .add_program("cetra_chamber", cetra_chamber::id(), None);
+    program_test_loader
+        .load()
+        .expect("Unable to load accounts!");
+
+    let mut test_context = program_test_loader
+        .start_with_context(Box::new(rpc_accounts_loader))
+        .await;
+
+    let payer = utils::clone_keypair(&test_context.context.payer);
+    let owner = Keypair::new();
+    let fee_manager = Keypair::new();
+
+    // 1. Build and initialize `Chamber`
+    let test_chamber = state::Chamber::build_raydium_sol_usdc(
+        &mut test_context,
+        &payer,
+        &owner,
+        &fee_manager.pubkey(),
+        0,
+    )
+    .await
+    .expect("Unable to build raydium SOL/USDC chamber!");
+
+    test_chamber
+        .initialize_chamber(&mut test_context, &payer)
+        .await
+        .expect("Unable to initialize chamber!");
+
+    // 2. Build `User` with `UserAccount`, holding shares
+    let test_user = state::User::build_with_chamber(
+        &mut test_context,
+        &payer,
+        &test_chamber,
+        USER_FUND_LAMPORTS,
+        0,
+        0,
+    )
+    .await
+    .expect("Unable to build user with chamber!");
+
+    test_user
+        .create_user_account(&mut test_context)
+        .await
+        .expect("Unable to create user account!");
+
+    utils::mint_tokens_unchecked(
+        &mut test_context,
+        &test_chamber.get_shares_mint(),
+        &test_user.get_shares(),
+        1000,
+    )
+    .await
+    .expect("Unable to mint user shares!");
+
+    let amm_id = test_chamber.get_farm_config().raydium_amm_id;
+
+    // 3. Withdraw with swap through not `Raydium` program
+    let error = test_chamber
+        .withdraw_chamber_with_swap_route(
+            &mut test_context,
+            &test_user,
+            1000,
+            &test_chamber.get_base_mint(),
+            &Pubkey::new_unique(),
+            &amm_id,
+        )
+        .await
+        .expect_err("Withdrawn swap is routed through not raydium program!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidSwapRoute
+    ));
+
+    // 4. Withdraw with swap through AMM of other pool
+    let error = test_chamber
+        .withdraw_chamber_with_swap_route(
+            &mut test_context,
+            &test_user,
+            1000,
+            &test_chamber.get_base_mint(),
+            &cetra_chamber::utils::raydium_amm_program::ID,
+            &Pubkey::new_unique(),
+        )
+        .await
+        .expect_err("Withdrawn swap is routed through other AMM!");
+
+    assert!(is_chamber_error(
+        &error,
+        cetra_chamber::error::ChamberError::InvalidSwapRoute
+    ));
+}
+
+#[test]
+fn success_withdraw_percent() {
+    assert_eq!(math::get_withdraw_percent(1000, 1000).unwrap(), 100);
+    assert_eq!(math::get_withdraw_percent(500, 1000).unwrap(), 50);
+    // Partial percent is rounded up
+    assert_eq!(math::get_withdraw_percent(1, 1000).unwrap(), 1);
+    assert_eq!(math::get_withdraw_percent(501, 1000).unwrap(), 51);
+
+    assert!(math::get_withdraw_percent(0, 1000).is_err());
+    assert!(math::get_withdraw_percent(1001, 1000).is_err());
+}
+
+#[test]
+fn success_redeem_amount() {
+    // Exact percent is fully redeemed
+    assert_eq!(math::get_redeem_amount(5000, 500, 1000, 50).unwrap(), 5000);
+    assert_eq!(
+        math::get_redeem_amount(7000, 1000, 1000, 100).unwrap(),
+        7000
+    );
+
+    // Rounded up percent keeps excess in `Chamber`
+    let withdraw_percent = math::get_withdraw_percent(1, 1000).unwrap();
+
+    assert_eq!(
+        math::get_redeem_amount(1000, 1, 1000, withdraw_percent).unwrap(),
+        100
+    );
+
+    assert!(math::get_redeem_amount(1000, 1, 0, 1).is_err());
+    assert!(math::get_redeem_amount(1000, 1, 1000, 0).is_err());
+}
@@ -0,0 +1,317 @@
+//! Simulated chamber position, replayed with `cetra_chamber::math` used by processors.
+
+use crate::{input, output};
+use anyhow::{anyhow, Result};
+use cetra_chamber::math;
+use std::collections::BTreeMap;
+use tulipv2_sdk_common::math::{common::TryAdd, decimal::Decimal};
+
+/// Seconds in year, used to accrue APRs.
+pub const SECONDS_PER_YEAR: f64 = 31_536_000.0;
+
+/// Decimal scale, used to convert prices.
+const WAD: f64 = 1_000_000_000_000_000_000.0;
+
+/// Token amounts are given in smallest units, so value is not scaled by decimals.
+const VALUE_DECIMALS: u64 = 0;
+
+/// Chamber leveraged LP position.
+///
+/// Position is tracked as a share of exogenous pool reserves, so LP tokens always
+/// follow pool ratio. Deposits and rewards enter the pool at pool ratio without swaps.
+#[derive(Debug, Clone)]
+pub struct SimChamber {
+    pub config: input::SimConfig,
+
+    pub pool_share: f64,
+    pub base_debt: f64,
+    pub quote_debt: f64,
+
+    /// Tokens, provided to LP on last entry, used to track impermanent loss.
+    pub entry_base: f64,
+    pub entry_quote: f64,
+
+    pub shares_supply: u64,
+    pub user_shares: BTreeMap<String, u64>,
+
+    pub rebalances: u64,
+    pub rebalance_cost: f64,
+    pub fees_paid: f64,
+}
+
+impl SimChamber {
+    pub fn new(config: &input::SimConfig) -> Self {
+        Self {
+            config: config.clone(),
+            pool_share: 0.0,
+            base_debt: 0.0,
+            quote_debt: 0.0,
+            entry_base: 0.0,
+            entry_quote: 0.0,
+            shares_supply: 0,
+            user_shares: BTreeMap::new(),
+            rebalances: 0,
+            rebalance_cost: 0.0,
+            fees_paid: 0.0,
+        }
+    }
+
+    pub fn get_lp_amounts(&self, step: &input::SimStep) -> (f64, f64) {
+        (
+            self.pool_share * step.pool_base_reserve,
+            self.pool_share * step.pool_quote_reserve,
+        )
+    }
+
+    pub fn get_lp_value(&self, step: &input::SimStep) -> f64 {
+        let (base_amount, quote_amount) = self.get_lp_amounts(step);
+
+        base_amount * step.base_price + quote_amount * step.quote_price
+    }
+
+    pub fn get_equity(&self, step: &input::SimStep) -> f64 {
+        self.get_lp_value(step)
+            - self.base_debt * step.base_price
+            - self.quote_debt * step.quote_price
+    }
+
+    pub fn get_volatile_price(&self, step: &input::SimStep) -> f64 {
+        if self.config.is_base_volatile {
+            step.base_price
+        } else {
+            step.quote_price
+        }
+    }
+
+    /// Accrue borrow interest and compound farm rewards, net of performance fee.
+    pub fn accrue(&mut self, step: &input::SimStep) -> Result<()> {
+        let years = step.duration as f64 / SECONDS_PER_YEAR;
+
+        self.base_debt *= 1.0 + get_rate(step.base_borrow_apr_bps) * years;
+        self.quote_debt *= 1.0 + get_rate(step.quote_borrow_apr_bps) * years;
+
+        let rewards_value = self.get_lp_value(step) * get_rate(step.farm_apr_bps) * years;
+        let fee_amount =
+            math::get_fee_amount(rewards_value as u64, self.config.performance_fee_bps)? as f64;
+
+        self.fees_paid += fee_amount;
+        self.provide_liquidity(step, rewards_value - fee_amount);
+
+        Ok(())
+    }
+
+    pub fn deposit(
+        &mut self,
+        step: &input::SimStep,
+        user: &str,
+        base_amount: u64,
+        quote_amount: u64,
+    ) -> Result<()> {
+        let base_price = to_decimal(step.base_price);
+        let quote_price = to_decimal(step.quote_price);
+
+        let deposit_value =
+            math::get_token_value(&base_price, base_amount, VALUE_DECIMALS)?.try_add(
+                math::get_token_value(&quote_price, quote_amount, VALUE_DECIMALS)?,
+            )?;
+
+        let shares_amount = math::get_shares_amount(
+            &deposit_value,
+            self.shares_supply,
+            self.get_equity(step).max(0.0) as u64,
+        )?;
+
+        let (base_borrow_amount, quote_borrow_amount) = math::get_borrow_amounts(
+            &deposit_value,
+            &base_price,
+            &quote_price,
+            VALUE_DECIMALS,
+            VALUE_DECIMALS,
+            self.config.leverage,
+            self.config.is_base_volatile,
+        )?;
+
+        self.base_debt += base_borrow_amount as f64;
+        self.quote_debt += quote_borrow_amount as f64;
+        self.provide_liquidity(
+            step,
+            from_decimal(&deposit_value)?
+                + base_borrow_amount as f64 * step.base_price
+                + quote_borrow_amount as f64 * step.quote_price,
+        );
+
+        self.shares_supply += shares_amount;
+        *self.user_shares.entry(user.to_string()).or_default() += shares_amount;
+
+        Ok(())
+    }
+
+    /// Withdraw `shares_amount` of `user`, returns withdrawn value.
+    pub fn withdraw(
+        &mut self,
+        step: &input::SimStep,
+        user: &str,
+        shares_amount: u64,
+    ) -> Result<u64> {
+        let user_shares = self
+            .user_shares
+            .get_mut(user)
+            .filter(|user_shares| **user_shares >= shares_amount)
+            .ok_or_else(|| anyhow!("Insufficient shares of {}", user))?;
+
+        let value = math::get_shares_value(
+            shares_amount,
+            self.shares_supply,
+            self.get_equity(step).max(0.0) as u64,
+        )?;
+
+        let remaining = 1.0 - shares_amount as f64 / self.shares_supply as f64;
+        self.pool_share *= remaining;
+        self.base_debt *= remaining;
+        self.quote_debt *= remaining;
+        self.entry_base *= remaining;
+        self.entry_quote *= remaining;
+
+        *user_shares -= shares_amount;
+        self.shares_supply -= shares_amount;
+
+        Ok(value)
+    }
+
+    /// Max debt drift across legs, in basis points of target debt.
+    pub fn get_drift_bps(&self, step: &input::SimStep) -> Result<u64> {
+        let (base_target_debt, quote_target_debt) = self.get_target_debt(step)?;
+
+        Ok(get_leg_drift_bps(self.base_debt, base_target_debt)
+            .max(get_leg_drift_bps(self.quote_debt, quote_target_debt)))
+    }
+
+    /// Unwind position, repay debt and re-enter with target leverage.
+    ///
+    /// Debt shortfall is swapped from the other leg on unwind, and idle tokens are
+    /// swapped to pool ratio on re-entry, both paying `swap_fee_bps`.
+    pub fn rebalance(&mut self, step: &input::SimStep) -> Result<()> {
+        let (base_amount, quote_amount) = self.get_lp_amounts(step);
+        let idle_base_value = (base_amount - self.base_debt) * step.base_price;
+        let idle_quote_value = (quote_amount - self.quote_debt) * step.quote_price;
+
+        let unwind_swap_value = (-idle_base_value).max(0.0) + (-idle_quote_value).max(0.0);
+        let unwind_cost = self.get_swap_cost(unwind_swap_value)?;
+        let equity = idle_base_value + idle_quote_value - unwind_cost;
+
+        self.pool_share = 0.0;
+        self.base_debt = 0.0;
+        self.quote_debt = 0.0;
+        self.entry_base = 0.0;
+        self.entry_quote = 0.0;
+
+        let (base_borrow_amount, quote_borrow_amount) = math::get_borrow_amounts(
+            &to_decimal(equity.max(0.0)),
+            &to_decimal(step.base_price),
+            &to_decimal(step.quote_price),
+            VALUE_DECIMALS,
+            VALUE_DECIMALS,
+            self.config.leverage,
+            self.config.is_base_volatile,
+        )?;
+
+        let base_value = idle_base_value.max(0.0) + base_borrow_amount as f64 * step.base_price;
+        let quote_value = idle_quote_value.max(0.0) + quote_borrow_amount as f64 * step.quote_price;
+        let entry_swap_value = (base_value - quote_value).abs() / 2.0;
+        let entry_cost = self.get_swap_cost(entry_swap_value)?;
+
+        self.base_debt = base_borrow_amount as f64;
+        self.quote_debt = quote_borrow_amount as f64;
+        self.provide_liquidity(step, base_value + quote_value - unwind_cost - entry_cost);
+
+        self.rebalances += 1;
+        self.rebalance_cost += unwind_cost + entry_cost;
+
+        Ok(())
+    }
+
+    pub fn get_step_result(
+        &self,
+        index: usize,
+        step: &input::SimStep,
+        realized_delta: f64,
+    ) -> output::StepResult {
+        let (base_amount, quote_amount) = self.get_lp_amounts(step);
+        let equity = self.get_equity(step);
+
+        let net_volatile_exposure = if self.config.is_base_volatile {
+            base_amount - self.base_debt
+        } else {
+            quote_amount - self.quote_debt
+        };
+
+        output::StepResult {
+            step: index,
+            equity,
+            lp_value: self.get_lp_value(step),
+            base_debt_value: self.base_debt * step.base_price,
+            quote_debt_value: self.quote_debt * step.quote_price,
+            impermanent_loss: self.entry_base * step.base_price
+                + self.entry_quote * step.quote_price
+                - self.get_lp_value(step),
+            net_volatile_exposure,
+            realized_delta,
+            shares_supply: self.shares_supply,
+            share_price: if self.shares_supply == 0 {
+                1.0
+            } else {
+                equity / self.shares_supply as f64
+            },
+        }
+    }
+
+    fn get_target_debt(&self, step: &input::SimStep) -> Result<(u64, u64)> {
+        Ok(math::get_borrow_amounts(
+            &to_decimal(self.get_equity(step).max(0.0)),
+            &to_decimal(step.base_price),
+            &to_decimal(step.quote_price),
+            VALUE_DECIMALS,
+            VALUE_DECIMALS,
+            self.config.leverage,
+            self.config.is_base_volatile,
+        )?)
+    }
+
+    fn get_swap_cost(&self, swap_value: f64) -> Result<f64> {
+        Ok(math::get_fee_amount(swap_value as u64, self.config.swap_fee_bps)? as f64)
+    }
+
+    /// Provide `value` into pool at pool ratio.
+    fn provide_liquidity(&mut self, step: &input::SimStep, value: f64) {
+        let pool_value =
+            step.pool_base_reserve * step.base_price + step.pool_quote_reserve * step.quote_price;
+        if pool_value <= 0.0 || value <= 0.0 {
+            return;
+        }
+
+        let pool_share = value / pool_value;
+        self.pool_share += pool_share;
+        self.entry_base += pool_share * step.pool_base_reserve;
+        self.entry_quote += pool_share * step.pool_quote_reserve;
+    }
+}
+
+fn get_rate(bps: u64) -> f64 {
+    bps as f64 / math::BPS as f64
+}
+
+fn get_leg_drift_bps(debt: f64, target_debt: u64) -> u64 {
+    if target_debt == 0 {
+        return 0;
+    }
+
+    ((debt - target_debt as f64).abs() * math::BPS as f64 / target_debt as f64) as u64
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_scaled_val((value * WAD) as u128)
+}
+
+fn from_decimal(value: &Decimal) -> Result<f64> {
+    Ok(value.to_scaled_val()? as f64 / WAD)
+}
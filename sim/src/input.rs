@@ -0,0 +1,63 @@
+//! Simulation input.
+//!
+//! Token amounts are expressed in smallest units, prices - in value per smallest unit.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimInput {
+    /// Chamber configurations to replay the same market path against.
+    pub configs: Vec<SimConfig>,
+
+    pub steps: Vec<SimStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimConfig {
+    pub name: String,
+    pub leverage: u64,
+    pub is_base_volatile: bool,
+
+    /// Fee, charged from harvested farm rewards.
+    pub performance_fee_bps: u64,
+
+    /// Debt drift, which triggers automatic rebalance. Zero disables automatic rebalances.
+    pub rebalance_threshold_bps: u64,
+
+    /// AMM swap fee, paid for tokens swapped during rebalance.
+    pub swap_fee_bps: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimStep {
+    /// Step duration in seconds.
+    pub duration: u64,
+
+    pub base_price: f64,
+    pub quote_price: f64,
+
+    pub pool_base_reserve: f64,
+    pub pool_quote_reserve: f64,
+
+    pub base_borrow_apr_bps: u64,
+    pub quote_borrow_apr_bps: u64,
+    pub farm_apr_bps: u64,
+
+    #[serde(default)]
+    pub actions: Vec<SimAction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SimAction {
+    Deposit {
+        user: String,
+        base_amount: u64,
+        quote_amount: u64,
+    },
+    Withdraw {
+        user: String,
+        shares_amount: u64,
+    },
+    Rebalance,
+}
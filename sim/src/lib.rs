@@ -0,0 +1,90 @@
+//! Cetra chamber PnL simulator.
+//!
+//! Replays deposits, rebalances and withdrawals over price path for each chamber
+//! configuration, using the same borrow split, share and fee math as on-chain program.
+
+mod chamber;
+pub mod input;
+pub mod output;
+
+pub use chamber::*;
+
+use anyhow::Result;
+
+/// Replay `input` steps against each configuration.
+pub fn simulate(input: &input::SimInput) -> Result<output::SimOutput> {
+    let results = input
+        .configs
+        .iter()
+        .map(|config| simulate_chamber(config, &input.steps))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(output::SimOutput { results })
+}
+
+/// Replay `steps` against single chamber `config`.
+pub fn simulate_chamber(
+    config: &input::SimConfig,
+    steps: &[input::SimStep],
+) -> Result<output::ChamberResult> {
+    let mut chamber = SimChamber::new(config);
+    let mut curve = Vec::with_capacity(steps.len());
+    let mut withdrawals = Vec::new();
+    let mut previous: Option<(f64, f64)> = None;
+
+    for (index, step) in steps.iter().enumerate() {
+        // 1. Accrue interest and rewards over step
+        chamber.accrue(step)?;
+
+        // 2. Measure equity sensitivity to volatile price before user actions
+        let equity = chamber.get_equity(step);
+        let volatile_price = chamber.get_volatile_price(step);
+        let realized_delta = match previous {
+            Some((previous_equity, previous_price)) if volatile_price != previous_price => {
+                (equity - previous_equity) / (volatile_price - previous_price)
+            }
+            _ => 0.0,
+        };
+
+        // 3. Apply step actions
+        for action in &step.actions {
+            match action {
+                input::SimAction::Deposit {
+                    user,
+                    base_amount,
+                    quote_amount,
+                } => chamber.deposit(step, user, *base_amount, *quote_amount)?,
+                input::SimAction::Withdraw {
+                    user,
+                    shares_amount,
+                } => withdrawals.push(output::WithdrawalResult {
+                    step: index,
+                    user: user.clone(),
+                    shares_amount: *shares_amount,
+                    value: chamber.withdraw(step, user, *shares_amount)?,
+                }),
+                input::SimAction::Rebalance => chamber.rebalance(step)?,
+            }
+        }
+
+        // 4. Rebalance, if debt drifted over threshold
+        if config.rebalance_threshold_bps > 0
+            && chamber.shares_supply > 0
+            && chamber.get_drift_bps(step)? >= config.rebalance_threshold_bps
+        {
+            chamber.rebalance(step)?;
+        }
+
+        curve.push(chamber.get_step_result(index, step, realized_delta));
+        previous = Some((chamber.get_equity(step), volatile_price));
+    }
+
+    Ok(output::ChamberResult {
+        name: config.name.clone(),
+        curve,
+        withdrawals,
+        rebalances: chamber.rebalances,
+        rebalance_cost: chamber.rebalance_cost,
+        fees_paid: chamber.fees_paid,
+    })
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use std::{env, fs, io};
+
+fn main() -> Result<()> {
+    let input: cetra_sim::input::SimInput = match env::args().nth(1) {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+        None => serde_json::from_reader(io::stdin())?,
+    };
+
+    let output = cetra_sim::simulate(&input)?;
+    println!("{}", serde_json::to_string_pretty(&output)?);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+//! Simulation output.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimOutput {
+    pub results: Vec<ChamberResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChamberResult {
+    pub name: String,
+    pub curve: Vec<StepResult>,
+    pub withdrawals: Vec<WithdrawalResult>,
+
+    pub rebalances: u64,
+    pub rebalance_cost: f64,
+    pub fees_paid: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepResult {
+    pub step: usize,
+    pub equity: f64,
+    pub lp_value: f64,
+    pub base_debt_value: f64,
+    pub quote_debt_value: f64,
+
+    /// LP value loss against holding tokens, provided to LP on last entry.
+    pub impermanent_loss: f64,
+
+    /// Volatile token amount in LP less volatile token debt.
+    pub net_volatile_exposure: f64,
+
+    /// Equity change per volatile price change since previous step.
+    pub realized_delta: f64,
+
+    pub shares_supply: u64,
+    pub share_price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalResult {
+    pub step: usize,
+    pub user: String,
+    pub shares_amount: u64,
+    pub value: u64,
+}
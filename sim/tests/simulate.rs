@@ -0,0 +1,130 @@
+use cetra_sim::input::{SimAction, SimConfig, SimInput, SimStep};
+
+const RESERVE: f64 = 1_000_000_000.0;
+
+fn build_config(rebalance_threshold_bps: u64) -> SimConfig {
+    SimConfig {
+        name: "3x".to_string(),
+        leverage: 3,
+        is_base_volatile: true,
+        performance_fee_bps: 1_000,
+        rebalance_threshold_bps,
+        swap_fee_bps: 25,
+    }
+}
+
+/// Build step for constant product pool, moved to `base_price`.
+fn build_step(base_price: f64, farm_apr_bps: u64, actions: Vec<SimAction>) -> SimStep {
+    SimStep {
+        duration: 86_400,
+        base_price,
+        quote_price: 1.0,
+        pool_base_reserve: RESERVE / base_price.sqrt(),
+        pool_quote_reserve: RESERVE * base_price.sqrt(),
+        base_borrow_apr_bps: 0,
+        quote_borrow_apr_bps: 0,
+        farm_apr_bps,
+        actions,
+    }
+}
+
+fn deposit() -> SimAction {
+    SimAction::Deposit {
+        user: "alice".to_string(),
+        base_amount: 1_000_000,
+        quote_amount: 1_000_000,
+    }
+}
+
+#[test]
+fn success_flat() {
+    let output = cetra_sim::simulate(&SimInput {
+        configs: vec![build_config(500)],
+        steps: vec![
+            build_step(1.0, 0, vec![deposit()]),
+            build_step(1.0, 0, vec![]),
+            build_step(
+                1.0,
+                0,
+                vec![SimAction::Withdraw {
+                    user: "alice".to_string(),
+                    shares_amount: 2_000_000,
+                }],
+            ),
+        ],
+    })
+    .unwrap();
+
+    let result = &output.results[0];
+    assert_eq!(result.rebalances, 0);
+    assert_eq!(result.fees_paid, 0.0);
+
+    let step = &result.curve[1];
+    assert!((step.equity - 2_000_000.0).abs() < 1.0);
+    assert!((step.lp_value - 6_000_000.0).abs() < 1.0);
+    assert!(step.impermanent_loss.abs() < 1.0);
+    assert!(step.net_volatile_exposure.abs() < 1.0);
+    assert_eq!(step.shares_supply, 2_000_000);
+
+    assert_eq!(result.withdrawals.len(), 1);
+    assert!(result.withdrawals[0].value >= 1_999_999);
+    assert_eq!(result.curve[2].shares_supply, 0);
+}
+
+#[test]
+fn success_rebalance() {
+    let output = cetra_sim::simulate(&SimInput {
+        configs: vec![build_config(0), build_config(500)],
+        steps: vec![
+            build_step(1.0, 0, vec![deposit()]),
+            build_step(1.21, 0, vec![]),
+        ],
+    })
+    .unwrap();
+
+    let (manual, automatic) = (&output.results[0], &output.results[1]);
+
+    assert_eq!(manual.rebalances, 0);
+    assert!(manual.curve[1].impermanent_loss > 0.0);
+
+    assert_eq!(automatic.rebalances, 1);
+    assert!(automatic.rebalance_cost > 0.0);
+    assert!(automatic.curve[1].impermanent_loss.abs() < 1.0);
+    assert!(automatic.curve[1].equity < manual.curve[1].equity);
+}
+
+#[test]
+fn success_harvest() {
+    let output = cetra_sim::simulate(&SimInput {
+        configs: vec![build_config(0)],
+        steps: vec![
+            build_step(1.0, 0, vec![deposit()]),
+            build_step(1.0, 36_500, vec![]),
+        ],
+    })
+    .unwrap();
+
+    let result = &output.results[0];
+    assert!(result.fees_paid > 0.0);
+    assert!(result.curve[1].equity > result.curve[0].equity);
+    assert!(result.curve[1].share_price > result.curve[0].share_price);
+}
+
+#[test]
+fn failure_withdraw_insufficient_shares() {
+    assert!(cetra_sim::simulate(&SimInput {
+        configs: vec![build_config(0)],
+        steps: vec![build_step(
+            1.0,
+            0,
+            vec![
+                deposit(),
+                SimAction::Withdraw {
+                    user: "bob".to_string(),
+                    shares_amount: 1,
+                },
+            ],
+        )],
+    })
+    .is_err());
+}